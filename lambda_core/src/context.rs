@@ -0,0 +1,104 @@
+use std::rc::Rc;
+
+#[derive(Debug)]
+enum List<T> {
+  Cons(T, Rc<List<T>>),
+  Nil,
+}
+
+// Γ, the types of the variables in scope. Contexts are persistent: `assign`
+// returns an extended context that shares its tail with the original, which
+// is left untouched.
+#[derive(Debug)]
+pub struct TypingContext<T> {
+  type_assignments: Rc<List<(String, T)>>,
+}
+
+impl<T> Clone for TypingContext<T> {
+  fn clone(&self) -> Self {
+    Self {
+      type_assignments: Rc::clone(&self.type_assignments),
+    }
+  }
+}
+
+impl<T> Default for TypingContext<T> {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl<T> TypingContext<T> {
+  pub fn new() -> Self {
+    Self {
+      type_assignments: Rc::new(List::Nil),
+    }
+  }
+
+  pub fn assign(&self, var: String, typ: T) -> TypingContext<T> {
+    TypingContext {
+      type_assignments: Rc::new(List::Cons((var, typ), Rc::clone(&self.type_assignments))),
+    }
+  }
+
+  // The type of the innermost binding of `var`.
+  pub fn get(&self, var: &str) -> Option<&T> {
+    let mut xs = &*self.type_assignments;
+    while let List::Cons((x, typ), tail) = xs {
+      if x == var {
+        return Some(typ);
+      }
+      xs = tail;
+    }
+    None
+  }
+
+  // The bindings, innermost first.
+  pub fn iter(&self) -> Iter<'_, T> {
+    Iter {
+      xs: &self.type_assignments,
+    }
+  }
+}
+
+pub struct Iter<'a, T> {
+  xs: &'a List<(String, T)>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+  type Item = &'a (String, T);
+
+  fn next(&mut self) -> Option<Self::Item> {
+    match self.xs {
+      List::Cons(binding, tail) => {
+        self.xs = tail;
+        Some(binding)
+      }
+      List::Nil => None,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn inner_bindings_shadow_outer_ones() {
+    let outer = TypingContext::new().assign(String::from("x"), 1);
+    let inner = outer.assign(String::from("x"), 2);
+    assert_eq!(inner.get("x"), Some(&2));
+    assert_eq!(outer.get("x"), Some(&1));
+    assert_eq!(outer.get("y"), None);
+  }
+
+  #[test]
+  fn iterates_innermost_first() {
+    let ctx = TypingContext::new()
+      .assign(String::from("x"), 1)
+      .assign(String::from("y"), 2)
+      .assign(String::from("x"), 3);
+    let names: Vec<_> = ctx.iter().map(|(x, n)| format!("{}{}", x, n)).collect();
+    assert_eq!(names, vec!["x3", "y2", "x1"]);
+  }
+}
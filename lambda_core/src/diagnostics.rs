@@ -0,0 +1,148 @@
+use lalrpop_util::ParseError;
+use std::fmt;
+
+// A range of byte offsets into the source text.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Span {
+  pub start: usize,
+  pub end: usize,
+}
+
+impl Span {
+  pub fn new(start: usize, end: usize) -> Self {
+    Span { start, end }
+  }
+}
+
+// An error reported to the user, pointing into the source where it is known.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnostic {
+  pub message: String,
+  pub span: Option<Span>,
+}
+
+impl Diagnostic {
+  // A diagnostic for an error that is not tied to a place in the source, such
+  // as a type error.
+  pub fn new<E: fmt::Display>(err: &E) -> Self {
+    Diagnostic {
+      message: err.to_string(),
+      span: None,
+    }
+  }
+
+  pub fn from_parse_error<T: fmt::Display, E: fmt::Display>(err: &ParseError<usize, T, E>) -> Self {
+    let (message, span) = match err {
+      ParseError::InvalidToken { location } => (
+        String::from("invalid token"),
+        Span::new(*location, *location),
+      ),
+      ParseError::UnrecognizedEOF { location, expected } => (
+        format!("unexpected end of input{}", fmt_expected(expected)),
+        Span::new(*location, *location),
+      ),
+      ParseError::UnrecognizedToken {
+        token: (start, token, end),
+        expected,
+      } => (
+        format!("unexpected token {}{}", token, fmt_expected(expected)),
+        Span::new(*start, *end),
+      ),
+      ParseError::ExtraToken {
+        token: (start, token, end),
+      } => (format!("extra token {}", token), Span::new(*start, *end)),
+      ParseError::User { error } => return Diagnostic::new(error),
+    };
+    Diagnostic {
+      message,
+      span: Some(span),
+    }
+  }
+
+  // Writes the message followed by the line of `source` the span starts on,
+  // with the span underlined.
+  //
+  //   error: unexpected token )
+  //     --> 1:8
+  //     |
+  //   1 | λx: Int) x
+  //     |        ^
+  pub fn render(&self, source: &str) -> String {
+    let span = match self.span {
+      None => return format!("error: {}", self.message),
+      Some(span) => span,
+    };
+    let (line, column) = line_col(source, span.start);
+    let text = source.lines().nth(line - 1).unwrap_or("");
+    let gutter = " ".repeat(line.to_string().len());
+    // The underline covers at least one character and stops at the end of
+    // the line.
+    let line_end = text.chars().count() + 1;
+    let end_column = line_col(source, span.end).1.min(line_end).max(column + 1);
+    format!(
+      "error: {}\n{} --> {}:{}\n{} |\n{} | {}\n{} | {}{}",
+      self.message,
+      gutter,
+      line,
+      column,
+      gutter,
+      line,
+      text,
+      gutter,
+      " ".repeat(column - 1),
+      "^".repeat(end_column - column)
+    )
+  }
+}
+
+impl fmt::Display for Diagnostic {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{}", self.message)
+  }
+}
+
+fn fmt_expected(expected: &[String]) -> String {
+  match expected {
+    [] => String::new(),
+    [one] => format!(", expected {}", one),
+    _ => format!(", expected one of {}", expected.join(", ")),
+  }
+}
+
+// The line and column, both counted from 1 and in characters, of the byte
+// offset `offset` in `source`.
+pub fn line_col(source: &str, offset: usize) -> (usize, usize) {
+  let before = &source[..offset.min(source.len())];
+  let line = before.matches('\n').count() + 1;
+  let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+  (line, before[line_start..].chars().count() + 1)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn line_col_counts_characters() {
+    let source = "λx: Int.\n  x y";
+    assert_eq!(line_col(source, 0), (1, 1));
+    assert_eq!(line_col(source, "λx".len()), (1, 3));
+    assert_eq!(line_col(source, source.len()), (2, 6));
+  }
+
+  #[test]
+  fn render_underlines_the_span() {
+    let diagnostic = Diagnostic {
+      message: String::from("unexpected token )"),
+      span: Some(Span::new("λx: Int".len(), "λx: Int)".len())),
+    };
+    assert_eq!(
+      diagnostic.render("λx: Int) x"),
+      "error: unexpected token )\n  --> 1:8\n  |\n1 | λx: Int) x\n  |        ^"
+    );
+    assert_eq!(
+      Diagnostic::new(&"undefined variable").render(""),
+      "error: undefined variable"
+    );
+  }
+}
@@ -0,0 +1,15 @@
+// Infrastructure shared by the calculi: typing contexts, diagnostics, name
+// handling, pretty printing and reduction traces. The grammars stay in each
+// crate, since lalrpop cannot share rules between grammar files.
+
+pub mod context;
+pub mod diagnostics;
+pub mod names;
+pub mod pretty;
+pub mod trace;
+
+pub use context::TypingContext;
+pub use diagnostics::{Diagnostic, Span};
+pub use names::Hint;
+pub use pretty::Precedence;
+pub use trace::{Redex, Trace};
@@ -0,0 +1,57 @@
+// Helpers for de Bruijn representations, where a context is a list of named
+// bindings with the innermost binding last and index 0 refers to it.
+
+// Binder names are kept only as hints for converting back to named syntax.
+// They never take part in equality.
+#[derive(Debug, Clone)]
+pub struct Hint(pub String);
+
+impl PartialEq for Hint {
+  fn eq(&self, _other: &Hint) -> bool {
+    true
+  }
+}
+
+pub fn shift_index(i: usize, d: isize) -> usize {
+  (i as isize + d) as usize
+}
+
+// The index of the innermost binding of `name`.
+pub fn lookup<T>(bindings: &[(String, T)], name: &str) -> Option<usize> {
+  bindings.iter().rev().position(|(x, _)| x == name)
+}
+
+// The name bound at index `i`, or `#i` for an index that is free in
+// `bindings`.
+pub fn name_of<T>(bindings: &[(String, T)], i: usize) -> String {
+  match bindings.len().checked_sub(i + 1) {
+    Some(position) => bindings[position].0.clone(),
+    None => format!("#{}", i),
+  }
+}
+
+// Picks a name based on `hint` that is not already bound, so converting back
+// to named syntax never captures a variable.
+pub fn fresh_name<T>(bindings: &[(String, T)], hint: &str) -> String {
+  let mut name = String::from(hint);
+  while lookup(bindings, &name).is_some() {
+    name.push('\'');
+  }
+  name
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn indices_count_from_the_innermost_binding() {
+    let bindings = vec![(String::from("x"), ()), (String::from("y"), ())];
+    assert_eq!(lookup(&bindings, "y"), Some(0));
+    assert_eq!(lookup(&bindings, "x"), Some(1));
+    assert_eq!(name_of(&bindings, 1), "x");
+    assert_eq!(name_of(&bindings, 2), "#2");
+    assert_eq!(fresh_name(&bindings, "x"), "x'");
+    assert_eq!(fresh_name(&bindings, "z"), "z");
+  }
+}
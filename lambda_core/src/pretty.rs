@@ -0,0 +1,43 @@
+use std::fmt;
+
+// Pretty printing produces syntax that the grammar parses back into the same
+// tree, with as few parentheses as the grammar needs.
+pub trait Precedence: fmt::Display + Sized {
+  // The precedence level of the grammar the value is parsed at.
+  fn precedence(&self) -> usize;
+
+  // Writes `left op right` for a right associative operator.
+  fn fmt_binary(
+    &self,
+    f: &mut fmt::Formatter<'_>,
+    op: &str,
+    left: &Self,
+    right: &Self,
+  ) -> fmt::Result {
+    if left.precedence() >= self.precedence() {
+      write!(f, "({})", left)?;
+    } else {
+      write!(f, "{}", left)?;
+    }
+    if right.precedence() > self.precedence() {
+      write!(f, " {} ({})", op, right)
+    } else {
+      write!(f, " {} {}", op, right)
+    }
+  }
+}
+
+// Writes `label{separator}value` for every field, separated by commas.
+pub fn fmt_fields<T: fmt::Display>(
+  f: &mut fmt::Formatter<'_>,
+  fields: &[(String, T)],
+  separator: &str,
+) -> fmt::Result {
+  for (i, (label, value)) in fields.iter().enumerate() {
+    if i > 0 {
+      write!(f, ", ")?;
+    }
+    write!(f, "{}{}{}", label, separator, value)?;
+  }
+  Ok(())
+}
@@ -0,0 +1,224 @@
+use crate::diagnostics::Span;
+use crate::names;
+use serde_json::json;
+
+// Reduction traces: every step of an evaluation, with the redex it contracted
+// and the rules of the small-step semantics that justify it.
+
+// The index a de Bruijn variable gets when it stands in for a redex. It is
+// free in every context, so it prints as `#HOLE`, which no source term does.
+pub const HOLE: usize = usize::MAX / 2;
+
+// How a step was derived: for each term from the root down to the redex, the
+// congruence rule that steps into one of its subterms and the index of that
+// subterm, followed by the rule that contracts the redex.
+//
+//   (λx: Int. x) ((λy: Int. y) 1)  ~>  path [1], rules [E-App2, E-AppAbs]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Redex {
+  pub path: Vec<usize>,
+  pub rules: Vec<&'static str>,
+  hole: bool,
+}
+
+impl Redex {
+  // A recorder for a step that leaves a hole where the redex was, instead
+  // of contracting it, to find out where the redex is in the printed term.
+  pub fn hole() -> Self {
+    Redex {
+      hole: true,
+      ..Redex::default()
+    }
+  }
+
+  // Records a congruence rule stepping into the subterm `i`.
+  pub fn enter(&mut self, rule: &'static str, i: usize) -> &mut Self {
+    self.rules.push(rule);
+    self.path.push(i);
+    self
+  }
+
+  // Records the rule that contracts the redex, returning whether the step
+  // should contract it or leave a hole.
+  pub fn contract(&mut self, rule: &'static str) -> bool {
+    self.rules.push(rule);
+    !self.hole
+  }
+}
+
+// One step of a trace: the term before the step, where the redex is in it,
+// and how the step was derived.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Step {
+  pub term: String,
+  pub redex: Span,
+  pub path: Vec<usize>,
+  pub rules: Vec<&'static str>,
+}
+
+impl Step {
+  // `with_hole` is `term` printed with the redex replaced by a `HOLE`
+  // variable. Whatever surrounds the hole is printed the same way around the
+  // redex, parentheses included.
+  pub fn new(term: String, with_hole: &str, redex: Redex) -> Self {
+    let marker = names::name_of::<()>(&[], HOLE);
+    let span = match with_hole.find(&marker) {
+      Some(start) => {
+        let after = with_hole.len() - start - marker.len();
+        Span::new(start, term.len() - after)
+      }
+      None => Span::new(0, term.len()),
+    };
+    Step {
+      term,
+      redex: span,
+      path: redex.path,
+      rules: redex.rules,
+    }
+  }
+
+  // The rule that contracted the redex.
+  pub fn rule(&self) -> &'static str {
+    self.rules.last().copied().unwrap_or_default()
+  }
+
+  pub fn redex_text(&self) -> &str {
+    &self.term[self.redex.start..self.redex.end]
+  }
+}
+
+// A whole evaluation: the steps taken and the term they end in, a value or a
+// stuck term.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Trace {
+  pub steps: Vec<Step>,
+  pub result: String,
+}
+
+impl Trace {
+  // The terms one per line, each followed by its redex underlined and the
+  // rules of the step.
+  //
+  //      (λx: Int. x) ((λy: Int. y) 1)
+  //                   ^^^^^^^^^^^^^^^^ E-App2, E-AppAbs
+  //   |> (λx: Int. x) 1
+  //      ^^^^^^^^^^^^^^ E-AppAbs
+  //   |> 1
+  pub fn render_text(&self) -> String {
+    let mut lines = Vec::new();
+    for (i, step) in self.steps.iter().enumerate() {
+      lines.push(format!("{}{}", arrow(i), step.term));
+      let column = step.term[..step.redex.start].chars().count();
+      let width = step.redex_text().chars().count().max(1);
+      lines.push(format!(
+        "   {}{} {}",
+        " ".repeat(column),
+        "^".repeat(width),
+        step.rules.join(", ")
+      ));
+    }
+    lines.push(format!("{}{}", arrow(self.steps.len()), self.result));
+    lines.join("\n")
+  }
+
+  // The trace as JSON. The redex is given as byte offsets into the term as
+  // well as its text.
+  pub fn render_json(&self) -> String {
+    let steps: Vec<_> = self
+      .steps
+      .iter()
+      .map(|step| {
+        json!({
+          "term": step.term,
+          "redex": {
+            "start": step.redex.start,
+            "end": step.redex.end,
+            "text": step.redex_text(),
+          },
+          "path": step.path,
+          "rules": step.rules,
+          "rule": step.rule(),
+        })
+      })
+      .collect();
+    let trace = json!({ "steps": steps, "result": self.result });
+    serde_json::to_string_pretty(&trace).unwrap()
+  }
+
+  // A standalone HTML page listing the terms, with the redex of each step
+  // highlighted.
+  pub fn render_html(&self) -> String {
+    let mut items = Vec::new();
+    for step in &self.steps {
+      items.push(format!(
+        "<li><code>{}<mark>{}</mark>{}</code> <span class=\"rules\">{}</span></li>",
+        escape_html(&step.term[..step.redex.start]),
+        escape_html(step.redex_text()),
+        escape_html(&step.term[step.redex.end..]),
+        step.rules.join(", ")
+      ));
+    }
+    items.push(format!(
+      "<li><code>{}</code></li>",
+      escape_html(&self.result)
+    ));
+    format!(
+      "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>Reduction trace</title>\n<style>\nmark {{ background: #ffe38a; }}\n.rules {{ color: #666; font-size: smaller; }}\n</style>\n</head>\n<body>\n<ol start=\"0\">\n{}\n</ol>\n</body>\n</html>\n",
+      items.join("\n")
+    )
+  }
+}
+
+fn arrow(i: usize) -> &'static str {
+  if i == 0 {
+    "   "
+  } else {
+    "|> "
+  }
+}
+
+fn escape_html(text: &str) -> String {
+  text
+    .replace('&', "&amp;")
+    .replace('<', "&lt;")
+    .replace('>', "&gt;")
+    .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn trace() -> Trace {
+    let mut redex = Redex::default();
+    redex.enter("E-App2", 1);
+    redex.contract("E-AppAbs");
+    Trace {
+      steps: vec![Step::new(
+        String::from("f ((λx. x) <a = 1>)"),
+        &format!("f {}", names::name_of::<()>(&[], HOLE)),
+        redex,
+      )],
+      result: String::from("f <a = 1>"),
+    }
+  }
+
+  #[test]
+  fn steps_locate_the_redex() {
+    let trace = trace();
+    assert_eq!(trace.steps[0].redex_text(), "((λx. x) <a = 1>)");
+    assert_eq!(trace.steps[0].rule(), "E-AppAbs");
+    assert!(!Redex::hole().contract("E-AppAbs"));
+    assert_eq!(
+      trace.render_text(),
+      "   f ((λx. x) <a = 1>)\n     ^^^^^^^^^^^^^^^^^ E-App2, E-AppAbs\n|> f <a = 1>"
+    );
+    let json: serde_json::Value = serde_json::from_str(&trace.render_json()).unwrap();
+    assert_eq!(json["steps"][0]["path"], json!([1]));
+    assert_eq!(json["steps"][0]["redex"]["start"], 2);
+    assert_eq!(json["result"], "f <a = 1>");
+    assert!(trace
+      .render_html()
+      .contains("<code>f <mark>((λx. x) &lt;a = 1&gt;)</mark></code>"));
+  }
+}
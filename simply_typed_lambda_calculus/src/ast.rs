@@ -1,32 +1,204 @@
+use lambda_core::Precedence;
+use std::fmt;
+
 // Type ::=
 //  | *                   -- base type
+//  | Unit                -- unit type
 //  | Type -> Type        -- function type
+//  | Type × Type         -- product type
+//  | Type + Type         -- sum type
 //  | (Type)              -- grouping
 #[derive(Debug, Clone, PartialEq)]
 pub enum Type {
   // *
   Int,
+  // Unit
+  Unit,
   // Type -> Type
   Arrow(Box<Type>, Box<Type>),
+  // Type × Type
+  Product(Box<Type>, Box<Type>),
+  // Type + Type
+  Sum(Box<Type>, Box<Type>),
 }
 
 // Term ::=
 //  | Int                 -- integer literal
+//  | ()                  -- unit value
 //  | Var                 -- term variable
 //  | Term Term           -- term application
 //  | λ Var : Type . Term -- term abstraction
+//  | λ Var . Term        -- term abstraction, parameter type given by the context
+//  | (Term : Type)       -- type annotation
+//  | let Var = Term in Term
+//  | (Term, Term)        -- pair
+//  | Term.1              -- first projection
+//  | Term.2              -- second projection
+//  | inl Term as Type    -- left injection
+//  | inr Term as Type    -- right injection
+//  | case Term of inl Var => Term | inr Var => Term
+//  | Term; Term          -- sequencing
+//  | fix Term            -- fixed point
+//  | let rec Var : Type = Term in Term   -- sugar for let Var = fix (λVar: Type. Term) in Term
 //  | (Term)              -- grouping
 #[derive(Debug, Clone, PartialEq)]
 pub enum Term {
   Int(i32),
+  // ()
+  Unit,
   // Var
   Var(String),
   // Term Term
   App(Box<Term>, Box<Term>),
   // λ Var: Type. Term
+  // λ Var. Term
   Abs {
     param_name: String,
-    param_type: Type,
+    param_type: Option<Type>,
+    body: Box<Term>,
+  },
+  // (Term : Type)
+  Ann(Box<Term>, Type),
+  // let Var = Term in Term
+  Let {
+    name: String,
+    value: Box<Term>,
     body: Box<Term>,
   },
+  // (Term, Term)
+  Pair(Box<Term>, Box<Term>),
+  // Term.1
+  // Term.2
+  Proj(Box<Term>, usize),
+  // inl Term as Type
+  Inl(Box<Term>, Type),
+  // inr Term as Type
+  Inr(Box<Term>, Type),
+  // case Term of inl Var => Term | inr Var => Term
+  Case {
+    scrutinee: Box<Term>,
+    left_var: String,
+    left: Box<Term>,
+    right_var: String,
+    right: Box<Term>,
+  },
+  // Term; Term
+  Seq(Box<Term>, Box<Term>),
+  // fix Term
+  Fix(Box<Term>),
+}
+
+// Pretty printing produces syntax that the grammar parses back into the same tree.
+impl Precedence for Type {
+  fn precedence(&self) -> usize {
+    match self {
+      Type::Int | Type::Unit => 1,
+      Type::Product(..) => 2,
+      Type::Sum(..) => 3,
+      Type::Arrow(..) => 4,
+    }
+  }
+}
+
+impl fmt::Display for Type {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Type::Int => write!(f, "Int"),
+      Type::Unit => write!(f, "Unit"),
+      Type::Arrow(param_type, return_type) => self.fmt_binary(f, "->", param_type, return_type),
+      Type::Product(left, right) => self.fmt_binary(f, "×", left, right),
+      Type::Sum(left, right) => self.fmt_binary(f, "+", left, right),
+    }
+  }
+}
+
+impl Term {
+  fn is_atom(&self) -> bool {
+    matches!(
+      self,
+      Term::Int(_) | Term::Unit | Term::Var(_) | Term::Ann(..) | Term::Pair(..) | Term::Proj(..)
+    )
+  }
+
+  // Terms that extend as far to the right as possible.
+  fn is_open(&self) -> bool {
+    matches!(
+      self,
+      Term::Abs { .. }
+        | Term::Let { .. }
+        | Term::Inl(..)
+        | Term::Inr(..)
+        | Term::Case { .. }
+        | Term::Seq(..)
+    )
+  }
+}
+
+impl fmt::Display for Term {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Term::Int(n) => write!(f, "{}", n),
+      Term::Unit => write!(f, "()"),
+      Term::Var(x) => write!(f, "{}", x),
+      Term::App(function, arg) => {
+        if function.is_open() {
+          write!(f, "({})", function)?;
+        } else {
+          write!(f, "{}", function)?;
+        }
+        if arg.is_atom() {
+          write!(f, " {}", arg)
+        } else {
+          write!(f, " ({})", arg)
+        }
+      }
+      Term::Abs {
+        param_name,
+        param_type: Some(param_type),
+        body,
+      } => write!(f, "λ{}: {}. {}", param_name, param_type, body),
+      Term::Abs {
+        param_name,
+        param_type: None,
+        body,
+      } => write!(f, "λ{}. {}", param_name, body),
+      Term::Ann(term, typ) => write!(f, "({} : {})", term, typ),
+      Term::Let { name, value, body } => write!(f, "let {} = {} in {}", name, value, body),
+      Term::Pair(first, second) => write!(f, "({}, {})", first, second),
+      Term::Proj(term, i) => {
+        if term.is_atom() {
+          write!(f, "{}.{}", term, i)
+        } else {
+          write!(f, "({}).{}", term, i)
+        }
+      }
+      Term::Inl(term, typ) => write!(f, "inl {} as {}", term, typ),
+      Term::Inr(term, typ) => write!(f, "inr {} as {}", term, typ),
+      Term::Case {
+        scrutinee,
+        left_var,
+        left,
+        right_var,
+        right,
+      } => write!(
+        f,
+        "case {} of inl {} => {} | inr {} => {}",
+        scrutinee, left_var, left, right_var, right
+      ),
+      Term::Seq(first, second) => {
+        if first.is_open() {
+          write!(f, "({}); {}", first, second)
+        } else {
+          write!(f, "{}; {}", first, second)
+        }
+      }
+      Term::Fix(term) => {
+        if term.is_atom() {
+          write!(f, "fix {}", term)
+        } else {
+          write!(f, "fix ({})", term)
+        }
+      }
+    }
+  }
 }
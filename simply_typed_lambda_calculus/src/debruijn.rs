@@ -0,0 +1,962 @@
+use crate::ast::{self, Type};
+use crate::typechecker::{self, TypecheckerError};
+use lambda_core::names::{self, shift_index};
+use lambda_core::trace::{Redex, Step, Trace, HOLE};
+use thiserror::Error;
+
+pub use lambda_core::Hint;
+
+// Locally nameless intermediate representation.
+//
+// Bound variables are replaced by de Bruijn indices: the number of binders
+// between the occurrence and the binder it refers to.
+//
+//   λx: Int. λy: Int. x   ~>   λ: Int. λ: Int. 1
+//
+// Substitution on this representation cannot capture variables, and two
+// terms are alpha-equivalent exactly when their representations are equal.
+// Types have no binders, so they are shared with the named syntax.
+
+// Term ::=
+//  | Int
+//  | ()
+//  | n                -- term variable
+//  | Term Term
+//  | λ : Type . Term
+//  | let = Term in Term
+//  | (Term, Term)
+//  | Term.n
+//  | inl Term as Type
+//  | inr Term as Type
+//  | case Term of inl => Term | inr => Term   -- each branch binds a term variable
+//  | Term; Term
+//  | fix Term
+#[derive(Debug, Clone, PartialEq)]
+pub enum Term {
+  Int(i32),
+  Unit,
+  Var(usize),
+  App(Box<Term>, Box<Term>),
+  Abs {
+    hint: Hint,
+    param_type: Type,
+    body: Box<Term>,
+  },
+  Let {
+    hint: Hint,
+    value: Box<Term>,
+    body: Box<Term>,
+  },
+  Pair(Box<Term>, Box<Term>),
+  Proj(Box<Term>, usize),
+  Inl(Box<Term>, Type),
+  Inr(Box<Term>, Type),
+  Case {
+    scrutinee: Box<Term>,
+    left_hint: Hint,
+    left: Box<Term>,
+    right_hint: Hint,
+    right: Box<Term>,
+  },
+  Seq(Box<Term>, Box<Term>),
+  Fix(Box<Term>),
+}
+
+// With `fix` a term can reduce forever, so evaluation gives up after this
+// many steps.
+pub const STEP_LIMIT: usize = 1_000_000;
+
+#[derive(Debug, PartialEq, Error)]
+pub enum EvalError {
+  #[error(transparent)]
+  Typechecker(#[from] TypecheckerError),
+  #[error("evaluation did not finish within {0} steps")]
+  StepLimitExceeded(usize),
+}
+
+// Γ, innermost binding last. Converting between named syntax and the IR
+// only needs the names, so bindings introduced there carry no type.
+#[derive(Debug, Clone, Default)]
+pub struct Context {
+  terms: Vec<(String, Option<Type>)>,
+}
+
+impl Context {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  pub fn assign(&self, name: String, typ: Type) -> Context {
+    let mut ctx = self.clone();
+    ctx.terms.push((name, Some(typ)));
+    ctx
+  }
+
+  pub fn bind(&self, name: String) -> Context {
+    let mut ctx = self.clone();
+    ctx.terms.push((name, None));
+    ctx
+  }
+
+  pub fn get(&self, i: usize) -> Option<&Type> {
+    let position = self.terms.len().checked_sub(i + 1)?;
+    self.terms[position].1.as_ref()
+  }
+
+  fn lookup(&self, name: &str) -> Option<usize> {
+    names::lookup(&self.terms, name)
+  }
+
+  fn name_of(&self, i: usize) -> String {
+    names::name_of(&self.terms, i)
+  }
+
+  fn fresh_name(&self, hint: &str) -> String {
+    names::fresh_name(&self.terms, hint)
+  }
+}
+
+impl Term {
+  pub fn from_ast(ctx: &Context, term: &ast::Term) -> Result<Term, TypecheckerError> {
+    match term {
+      ast::Term::Int(n) => Ok(Term::Int(*n)),
+      ast::Term::Unit => Ok(Term::Unit),
+      ast::Term::Var(x) => match ctx.lookup(x) {
+        None => Err(TypecheckerError::UndefinedVariable(x.clone())),
+        Some(i) => Ok(Term::Var(i)),
+      },
+      ast::Term::App(f, arg) => Ok(Term::App(
+        Box::new(Term::from_ast(ctx, f)?),
+        Box::new(Term::from_ast(ctx, arg)?),
+      )),
+      ast::Term::Abs {
+        param_name,
+        param_type: Some(param_type),
+        body,
+      } => {
+        let ctx = ctx.bind(param_name.clone());
+        Ok(Term::Abs {
+          hint: Hint(param_name.clone()),
+          param_type: param_type.clone(),
+          body: Box::new(Term::from_ast(&ctx, body)?),
+        })
+      }
+      ast::Term::Abs {
+        param_type: None, ..
+      } => Err(TypecheckerError::MissingAnnotation(term.clone())),
+      // Annotations only guide the typechecker.
+      ast::Term::Ann(term, _) => Term::from_ast(ctx, term),
+      ast::Term::Let { name, value, body } => {
+        let value = Term::from_ast(ctx, value)?;
+        let ctx = ctx.bind(name.clone());
+        Ok(Term::Let {
+          hint: Hint(name.clone()),
+          value: Box::new(value),
+          body: Box::new(Term::from_ast(&ctx, body)?),
+        })
+      }
+      ast::Term::Pair(first, second) => Ok(Term::Pair(
+        Box::new(Term::from_ast(ctx, first)?),
+        Box::new(Term::from_ast(ctx, second)?),
+      )),
+      ast::Term::Proj(term, i) => Ok(Term::Proj(Box::new(Term::from_ast(ctx, term)?), *i)),
+      ast::Term::Inl(term, typ) => Ok(Term::Inl(Box::new(Term::from_ast(ctx, term)?), typ.clone())),
+      ast::Term::Inr(term, typ) => Ok(Term::Inr(Box::new(Term::from_ast(ctx, term)?), typ.clone())),
+      ast::Term::Case {
+        scrutinee,
+        left_var,
+        left,
+        right_var,
+        right,
+      } => Ok(Term::Case {
+        scrutinee: Box::new(Term::from_ast(ctx, scrutinee)?),
+        left_hint: Hint(left_var.clone()),
+        left: Box::new(Term::from_ast(&ctx.bind(left_var.clone()), left)?),
+        right_hint: Hint(right_var.clone()),
+        right: Box::new(Term::from_ast(&ctx.bind(right_var.clone()), right)?),
+      }),
+      ast::Term::Seq(first, second) => Ok(Term::Seq(
+        Box::new(Term::from_ast(ctx, first)?),
+        Box::new(Term::from_ast(ctx, second)?),
+      )),
+      ast::Term::Fix(term) => Ok(Term::Fix(Box::new(Term::from_ast(ctx, term)?))),
+    }
+  }
+
+  pub fn to_ast(&self, ctx: &Context) -> ast::Term {
+    match self {
+      Term::Int(n) => ast::Term::Int(*n),
+      Term::Unit => ast::Term::Unit,
+      Term::Var(i) => ast::Term::Var(ctx.name_of(*i)),
+      Term::App(f, arg) => ast::Term::App(Box::new(f.to_ast(ctx)), Box::new(arg.to_ast(ctx))),
+      Term::Abs {
+        hint,
+        param_type,
+        body,
+      } => {
+        let param_name = ctx.fresh_name(&hint.0);
+        let ctx = ctx.bind(param_name.clone());
+        ast::Term::Abs {
+          param_name,
+          param_type: Some(param_type.clone()),
+          body: Box::new(body.to_ast(&ctx)),
+        }
+      }
+      Term::Let { hint, value, body } => {
+        let name = ctx.fresh_name(&hint.0);
+        let value = value.to_ast(ctx);
+        let ctx = ctx.bind(name.clone());
+        ast::Term::Let {
+          name,
+          value: Box::new(value),
+          body: Box::new(body.to_ast(&ctx)),
+        }
+      }
+      Term::Pair(first, second) => {
+        ast::Term::Pair(Box::new(first.to_ast(ctx)), Box::new(second.to_ast(ctx)))
+      }
+      Term::Proj(term, i) => ast::Term::Proj(Box::new(term.to_ast(ctx)), *i),
+      Term::Inl(term, typ) => ast::Term::Inl(Box::new(term.to_ast(ctx)), typ.clone()),
+      Term::Inr(term, typ) => ast::Term::Inr(Box::new(term.to_ast(ctx)), typ.clone()),
+      Term::Case {
+        scrutinee,
+        left_hint,
+        left,
+        right_hint,
+        right,
+      } => {
+        let left_var = ctx.fresh_name(&left_hint.0);
+        let right_var = ctx.fresh_name(&right_hint.0);
+        ast::Term::Case {
+          scrutinee: Box::new(scrutinee.to_ast(ctx)),
+          left: Box::new(left.to_ast(&ctx.bind(left_var.clone()))),
+          left_var,
+          right: Box::new(right.to_ast(&ctx.bind(right_var.clone()))),
+          right_var,
+        }
+      }
+      Term::Seq(first, second) => {
+        ast::Term::Seq(Box::new(first.to_ast(ctx)), Box::new(second.to_ast(ctx)))
+      }
+      Term::Fix(term) => ast::Term::Fix(Box::new(term.to_ast(ctx))),
+    }
+  }
+
+  // Rebuilds the term, replacing every variable with `on_var(cutoff, index)`,
+  // where `cutoff` is the number of binders crossed so far.
+  fn map_vars(&self, cutoff: usize, on_var: &dyn Fn(usize, usize) -> Term) -> Term {
+    match self {
+      Term::Int(n) => Term::Int(*n),
+      Term::Unit => Term::Unit,
+      Term::Var(i) => on_var(cutoff, *i),
+      Term::App(f, arg) => Term::App(
+        Box::new(f.map_vars(cutoff, on_var)),
+        Box::new(arg.map_vars(cutoff, on_var)),
+      ),
+      Term::Abs {
+        hint,
+        param_type,
+        body,
+      } => Term::Abs {
+        hint: hint.clone(),
+        param_type: param_type.clone(),
+        body: Box::new(body.map_vars(cutoff + 1, on_var)),
+      },
+      Term::Let { hint, value, body } => Term::Let {
+        hint: hint.clone(),
+        value: Box::new(value.map_vars(cutoff, on_var)),
+        body: Box::new(body.map_vars(cutoff + 1, on_var)),
+      },
+      Term::Pair(first, second) => Term::Pair(
+        Box::new(first.map_vars(cutoff, on_var)),
+        Box::new(second.map_vars(cutoff, on_var)),
+      ),
+      Term::Proj(term, i) => Term::Proj(Box::new(term.map_vars(cutoff, on_var)), *i),
+      Term::Inl(term, typ) => Term::Inl(Box::new(term.map_vars(cutoff, on_var)), typ.clone()),
+      Term::Inr(term, typ) => Term::Inr(Box::new(term.map_vars(cutoff, on_var)), typ.clone()),
+      Term::Case {
+        scrutinee,
+        left_hint,
+        left,
+        right_hint,
+        right,
+      } => Term::Case {
+        scrutinee: Box::new(scrutinee.map_vars(cutoff, on_var)),
+        left_hint: left_hint.clone(),
+        left: Box::new(left.map_vars(cutoff + 1, on_var)),
+        right_hint: right_hint.clone(),
+        right: Box::new(right.map_vars(cutoff + 1, on_var)),
+      },
+      Term::Seq(first, second) => Term::Seq(
+        Box::new(first.map_vars(cutoff, on_var)),
+        Box::new(second.map_vars(cutoff, on_var)),
+      ),
+      Term::Fix(term) => Term::Fix(Box::new(term.map_vars(cutoff, on_var))),
+    }
+  }
+
+  // ↑d,c(t): adds `d` to every variable that is free above `cutoff`.
+  pub fn shift(&self, d: isize, cutoff: usize) -> Term {
+    self.map_vars(cutoff, &|c, i| {
+      if i >= c {
+        Term::Var(shift_index(i, d))
+      } else {
+        Term::Var(i)
+      }
+    })
+  }
+
+  // t[s/j]
+  pub fn subst(&self, j: usize, s: &Term) -> Term {
+    self.map_vars(0, &|c, i| {
+      if i == j + c {
+        s.shift(c as isize, 0)
+      } else {
+        Term::Var(i)
+      }
+    })
+  }
+
+  // Given the body t of `λx. t`, computes t[v/x].
+  pub fn instantiate(&self, v: &Term) -> Term {
+    self.subst(0, &v.shift(1, 0)).shift(-1, 0)
+  }
+
+  pub fn type_of(&self, ctx: &Context) -> Result<Type, TypecheckerError> {
+    match self {
+      Term::Int(_) => Ok(Type::Int),
+      Term::Unit => Ok(Type::Unit),
+      // Γ(x) = τ
+      // --------
+      // Γ ⊢ x: τ
+      Term::Var(i) => match ctx.get(*i) {
+        None => Err(TypecheckerError::UndefinedVariable(ctx.name_of(*i))),
+        Some(typ) => Ok(typ.clone()),
+      },
+      // Γ ⊢ t1: σ -> τ    Γ ⊢ t2: σ
+      // ---------------------------
+      //       Γ ⊢ (t1 t2): τ
+      Term::App(f, arg) => match f.type_of(ctx)? {
+        Type::Arrow(param_type, return_type) => {
+          let arg_type = arg.type_of(ctx)?;
+
+          if arg_type != *param_type {
+            return Err(TypecheckerError::TypeMismatch {
+              term: arg.to_ast(ctx),
+              expected: *param_type,
+              got: arg_type,
+            });
+          }
+
+          Ok(*return_type)
+        }
+        _ => Err(TypecheckerError::UnexpectedTerm {
+          expected: String::from("abstraction"),
+          got: f.to_ast(ctx),
+        }),
+      },
+      //    Γ, x: σ ⊢ t: τ
+      // ----------------------
+      // Γ ⊢ (λx: σ. t): σ -> τ
+      Term::Abs {
+        hint,
+        param_type,
+        body,
+      } => {
+        let ctx = ctx.assign(ctx.fresh_name(&hint.0), param_type.clone());
+        let body_typ = body.type_of(&ctx)?;
+        Ok(Type::Arrow(
+          Box::new(param_type.clone()),
+          Box::new(body_typ),
+        ))
+      }
+      // Γ ⊢ t1: σ    Γ, x: σ ⊢ t2: τ
+      // ---------------------------
+      //  Γ ⊢ let x = t1 in t2: τ
+      Term::Let { hint, value, body } => {
+        let value_typ = value.type_of(ctx)?;
+        let ctx = ctx.assign(ctx.fresh_name(&hint.0), value_typ);
+        body.type_of(&ctx)
+      }
+      // Γ ⊢ t1: τ1    Γ ⊢ t2: τ2
+      // ------------------------
+      // Γ ⊢ (t1, t2): τ1 × τ2
+      Term::Pair(first, second) => Ok(Type::Product(
+        Box::new(first.type_of(ctx)?),
+        Box::new(second.type_of(ctx)?),
+      )),
+      // Γ ⊢ t: τ1 × τ2
+      // --------------
+      //  Γ ⊢ t.i: τi
+      Term::Proj(term, i) => match (term.type_of(ctx)?, i) {
+        (Type::Product(first, _), 1) => Ok(*first),
+        (Type::Product(_, second), 2) => Ok(*second),
+        (typ, _) => Err(TypecheckerError::UnexpectedType {
+          expected: format!("a pair type with a component {}", i),
+          got: typ,
+        }),
+      },
+      //          Γ ⊢ t: τ1
+      // ------------------------------
+      // Γ ⊢ inl t as τ1 + τ2: τ1 + τ2
+      //
+      //          Γ ⊢ t: τ2
+      // ------------------------------
+      // Γ ⊢ inr t as τ1 + τ2: τ1 + τ2
+      Term::Inl(term, typ) | Term::Inr(term, typ) => match typ {
+        Type::Sum(left, right) => {
+          let expected = if let Term::Inl(..) = self {
+            left
+          } else {
+            right
+          };
+          let got = term.type_of(ctx)?;
+
+          if got != **expected {
+            return Err(TypecheckerError::TypeMismatch {
+              term: term.to_ast(ctx),
+              expected: *expected.clone(),
+              got,
+            });
+          }
+
+          Ok(typ.clone())
+        }
+        _ => Err(TypecheckerError::UnexpectedType {
+          expected: String::from("sum type"),
+          got: typ.clone(),
+        }),
+      },
+      // Γ ⊢ t: τ1 + τ2    Γ, x1: τ1 ⊢ t1: τ    Γ, x2: τ2 ⊢ t2: τ
+      // -------------------------------------------------------
+      //    Γ ⊢ case t of inl x1 => t1 | inr x2 => t2: τ
+      Term::Case {
+        scrutinee,
+        left_hint,
+        left,
+        right_hint,
+        right,
+      } => match scrutinee.type_of(ctx)? {
+        Type::Sum(left_typ, right_typ) => {
+          let left_ctx = ctx.assign(ctx.fresh_name(&left_hint.0), *left_typ);
+          let right_ctx = ctx.assign(ctx.fresh_name(&right_hint.0), *right_typ);
+          let typ = left.type_of(&left_ctx)?;
+          let right_branch_typ = right.type_of(&right_ctx)?;
+
+          if right_branch_typ != typ {
+            return Err(TypecheckerError::TypeMismatch {
+              term: right.to_ast(&right_ctx),
+              expected: typ,
+              got: right_branch_typ,
+            });
+          }
+
+          Ok(typ)
+        }
+        typ => Err(TypecheckerError::UnexpectedType {
+          expected: String::from("sum type"),
+          got: typ,
+        }),
+      },
+      // Γ ⊢ t1: Unit    Γ ⊢ t2: τ
+      // -------------------------
+      //     Γ ⊢ t1; t2: τ
+      Term::Seq(first, second) => {
+        let first_typ = first.type_of(ctx)?;
+
+        if first_typ != Type::Unit {
+          return Err(TypecheckerError::TypeMismatch {
+            term: first.to_ast(ctx),
+            expected: Type::Unit,
+            got: first_typ,
+          });
+        }
+
+        second.type_of(ctx)
+      }
+      // Γ ⊢ t: τ -> τ
+      // -------------
+      // Γ ⊢ fix t: τ
+      Term::Fix(term) => match term.type_of(ctx)? {
+        Type::Arrow(param_type, return_type) if param_type == return_type => Ok(*return_type),
+        typ => Err(TypecheckerError::UnexpectedType {
+          expected: String::from("function type of the form T -> T"),
+          got: typ,
+        }),
+      },
+    }
+  }
+
+  pub fn is_value(&self) -> bool {
+    match self {
+      Term::Int(_) | Term::Unit | Term::Abs { .. } => true,
+      Term::Pair(first, second) => first.is_value() && second.is_value(),
+      Term::Inl(term, _) | Term::Inr(term, _) => term.is_value(),
+      _ => false,
+    }
+  }
+
+  // Performs a single call-by-value reduction step, or returns `None` when
+  // the term is a value or stuck.
+  pub fn step(&self) -> Option<Term> {
+    self.reduce(&mut Redex::default())
+  }
+
+  // Like `step`, also returning where the redex was and the rules that
+  // reduced it.
+  pub fn step_traced(&self) -> Option<(Term, Redex)> {
+    let mut redex = Redex::default();
+    let next = self.reduce(&mut redex)?;
+    Some((next, redex))
+  }
+
+  fn reduce(&self, redex: &mut Redex) -> Option<Term> {
+    match self {
+      Term::App(f, arg) => {
+        if !f.is_value() {
+          //    t1 |> t1'
+          // ---------------
+          // t1 t2 |> t1' t2
+          Some(Term::App(
+            Box::new(f.reduce(redex.enter("E-App1", 0))?),
+            arg.clone(),
+          ))
+        } else if !arg.is_value() {
+          //   t2 |> t2'
+          // ------------
+          // v t2 |> v t2'
+          Some(Term::App(
+            f.clone(),
+            Box::new(arg.reduce(redex.enter("E-App2", 1))?),
+          ))
+        } else {
+          match &**f {
+            // ------------------------
+            // (λx: τ. t) v |> t[v/x]
+            Term::Abs { body, .. } => Some(contract(redex, "E-AppAbs", || body.instantiate(arg))),
+            _ => None,
+          }
+        }
+      }
+      Term::Let { hint, value, body } => {
+        if !value.is_value() {
+          //              t1 |> t1'
+          // ------------------------------------
+          // let x = t1 in t2 |> let x = t1' in t2
+          Some(Term::Let {
+            hint: hint.clone(),
+            value: Box::new(value.reduce(redex.enter("E-Let", 0))?),
+            body: body.clone(),
+          })
+        } else {
+          // ----------------------------
+          // let x = v in t |> t[v/x]
+          Some(contract(redex, "E-LetV", || body.instantiate(value)))
+        }
+      }
+      Term::Pair(first, second) => {
+        if !first.is_value() {
+          //       t1 |> t1'
+          // ---------------------
+          // (t1, t2) |> (t1', t2)
+          Some(Term::Pair(
+            Box::new(first.reduce(redex.enter("E-Pair1", 0))?),
+            second.clone(),
+          ))
+        } else {
+          //       t2 |> t2'
+          // ---------------------
+          // (v1, t2) |> (v1, t2')
+          Some(Term::Pair(
+            first.clone(),
+            Box::new(second.reduce(redex.enter("E-Pair2", 1))?),
+          ))
+        }
+      }
+      Term::Proj(term, i) => {
+        if !term.is_value() {
+          //    t |> t'
+          // -------------
+          // t.i |> t'.i
+          Some(Term::Proj(
+            Box::new(term.reduce(redex.enter("E-Proj", 0))?),
+            *i,
+          ))
+        } else {
+          match (&**term, i) {
+            // ---------------
+            // (v1, v2).1 |> v1
+            (Term::Pair(first, _), 1) => Some(contract(redex, "E-PairBeta1", || *first.clone())),
+            // ---------------
+            // (v1, v2).2 |> v2
+            (Term::Pair(_, second), 2) => Some(contract(redex, "E-PairBeta2", || *second.clone())),
+            _ => None,
+          }
+        }
+      }
+      //          t |> t'
+      // -----------------------
+      // inl t as τ |> inl t' as τ
+      Term::Inl(term, typ) => Some(Term::Inl(
+        Box::new(term.reduce(redex.enter("E-Inl", 0))?),
+        typ.clone(),
+      )),
+      //          t |> t'
+      // -----------------------
+      // inr t as τ |> inr t' as τ
+      Term::Inr(term, typ) => Some(Term::Inr(
+        Box::new(term.reduce(redex.enter("E-Inr", 0))?),
+        typ.clone(),
+      )),
+      Term::Case {
+        scrutinee,
+        left_hint,
+        left,
+        right_hint,
+        right,
+      } => {
+        if !scrutinee.is_value() {
+          //                              t |> t'
+          // ---------------------------------------------------------------------------
+          // case t of inl x1 => t1 | inr x2 => t2 |> case t' of inl x1 => t1 | inr x2 => t2
+          Some(Term::Case {
+            scrutinee: Box::new(scrutinee.reduce(redex.enter("E-Case", 0))?),
+            left_hint: left_hint.clone(),
+            left: left.clone(),
+            right_hint: right_hint.clone(),
+            right: right.clone(),
+          })
+        } else {
+          match &**scrutinee {
+            // --------------------------------------------------------------
+            // case (inl v as τ) of inl x1 => t1 | inr x2 => t2 |> t1[v/x1]
+            Term::Inl(value, _) => Some(contract(redex, "E-CaseInl", || left.instantiate(value))),
+            // --------------------------------------------------------------
+            // case (inr v as τ) of inl x1 => t1 | inr x2 => t2 |> t2[v/x2]
+            Term::Inr(value, _) => Some(contract(redex, "E-CaseInr", || right.instantiate(value))),
+            _ => None,
+          }
+        }
+      }
+      Term::Seq(first, second) => {
+        if !first.is_value() {
+          //      t1 |> t1'
+          // -------------------
+          // t1; t2 |> t1'; t2
+          Some(Term::Seq(
+            Box::new(first.reduce(redex.enter("E-Seq", 0))?),
+            second.clone(),
+          ))
+        } else {
+          // ----------
+          // (); t |> t
+          Some(contract(redex, "E-SeqNext", || *second.clone()))
+        }
+      }
+      Term::Fix(term) => {
+        if !term.is_value() {
+          //       t |> t'
+          // -----------------
+          // fix t |> fix t'
+          Some(Term::Fix(Box::new(term.reduce(redex.enter("E-Fix", 0))?)))
+        } else {
+          match &**term {
+            // -------------------------------------
+            // fix (λx: τ. t) |> t[fix (λx: τ. t)/x]
+            Term::Abs { body, .. } => Some(contract(redex, "E-FixBeta", || body.instantiate(self))),
+            _ => None,
+          }
+        }
+      }
+      _ => None,
+    }
+  }
+
+  pub fn eval(&self) -> Result<Term, EvalError> {
+    self.eval_with_limit(STEP_LIMIT)
+  }
+
+  // Evaluates the term, failing if it takes more than `limit` steps.
+  pub fn eval_with_limit(&self, limit: usize) -> Result<Term, EvalError> {
+    let mut term = self.clone();
+    let mut steps = 0;
+    while let Some(next) = term.step() {
+      if steps == limit {
+        return Err(EvalError::StepLimitExceeded(limit));
+      }
+      term = next;
+      steps += 1;
+    }
+    Ok(term)
+  }
+
+  // Evaluates the closed term like `eval_with_limit`, recording every step.
+  pub fn trace_with_limit(&self, limit: usize) -> Result<Trace, EvalError> {
+    let ctx = Context::new();
+    let mut term = self.clone();
+    let mut steps = Vec::new();
+    while let Some((next, redex)) = term.step_traced() {
+      if steps.len() == limit {
+        return Err(EvalError::StepLimitExceeded(limit));
+      }
+      let with_hole = term.reduce(&mut Redex::hole()).unwrap();
+      steps.push(Step::new(
+        term.to_ast(&ctx).to_string(),
+        &with_hole.to_ast(&ctx).to_string(),
+        redex,
+      ));
+      term = next;
+    }
+    Ok(Trace {
+      steps,
+      result: term.to_ast(&ctx).to_string(),
+    })
+  }
+}
+
+// The contractum of a redex, or a hole in its place when `redex` is looking
+// for where the redex is.
+fn contract(redex: &mut Redex, rule: &'static str, contractum: impl FnOnce() -> Term) -> Term {
+  if redex.contract(rule) {
+    contractum()
+  } else {
+    Term::Var(HOLE)
+  }
+}
+
+// Typechecks `term` and converts it to a closed term.
+pub fn compile(term: &ast::Term) -> Result<Term, TypecheckerError> {
+  let (term, _) = typechecker::elaborate(term)?;
+  Term::from_ast(&Context::new(), &term)
+}
+
+pub fn eval(term: &ast::Term) -> Result<ast::Term, EvalError> {
+  Ok(compile(term)?.eval()?.to_ast(&Context::new()))
+}
+
+// Like `eval`, returning every step of the evaluation.
+pub fn trace(term: &ast::Term) -> Result<Trace, EvalError> {
+  compile(term)?.trace_with_limit(STEP_LIMIT)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn from_ast(input: &str) -> Term {
+    Term::from_ast(&Context::new(), &crate::parse(input).unwrap()).unwrap()
+  }
+
+  fn eval(input: &str) -> Result<ast::Term, EvalError> {
+    super::eval(&crate::parse(input).unwrap())
+  }
+
+  #[test]
+  fn conversions_round_trip() {
+    for input in [
+      "λx: Int. λy: Int. x",
+      "λf: Int -> Int. let y = f 1 in (y, ())",
+      "λs: Int + Unit. case s of inl x => x | inr u => 0",
+    ] {
+      let term = crate::parse(input).unwrap();
+      assert_eq!(from_ast(input).to_ast(&Context::new()), term);
+    }
+    // Terms that only differ in the names of their binders are equal.
+    assert_eq!(from_ast("λx: Int. x"), from_ast("λy: Int. y"));
+    assert_eq!(
+      Term::from_ast(&Context::new(), &crate::parse("λx: Int. y").unwrap()),
+      Err(TypecheckerError::UndefinedVariable(String::from("y")))
+    );
+  }
+
+  #[test]
+  fn readback_picks_fresh_names() {
+    assert_eq!(
+      from_ast("λx: Int. λx: Unit. x")
+        .to_ast(&Context::new())
+        .to_string(),
+      "λx: Int. λx': Unit. x'"
+    );
+    let ctx = Context::new().bind(String::from("x"));
+    let term = Term::Abs {
+      hint: Hint(String::from("x")),
+      param_type: Type::Int,
+      body: Box::new(Term::Var(1)),
+    };
+    assert_eq!(term.to_ast(&ctx).to_string(), "λx': Int. x");
+  }
+
+  #[test]
+  fn shadowed_binders_are_typed_by_position() {
+    let type_of = |input: &str| from_ast(input).type_of(&Context::new());
+    assert_eq!(
+      type_of("λx: Int. λx: Unit. x"),
+      Ok(crate::parse_type("Int -> Unit -> Unit").unwrap())
+    );
+    assert_eq!(type_of("(λx: Int. λx: Unit. x) 1 ()"), Ok(Type::Unit));
+    assert_eq!(
+      type_of("λx: Int. let x = () in x"),
+      Ok(crate::parse_type("Int -> Unit").unwrap())
+    );
+  }
+
+  #[test]
+  fn substitution_avoids_capture() {
+    // (λy: Int. x)[y/x] keeps the free y pointing outside the abstraction.
+    let ctx = Context::new()
+      .assign(String::from("y"), Type::Int)
+      .assign(String::from("x"), Type::Int);
+    let body = Term::Abs {
+      hint: Hint(String::from("y")),
+      param_type: Type::Int,
+      body: Box::new(Term::Var(1)),
+    };
+    assert_eq!(
+      body.subst(0, &Term::Var(1)).to_ast(&ctx).to_string(),
+      "λy': Int. y"
+    );
+    assert_eq!(
+      from_ast("(λx: Int. λy: Int. x) 1").eval().unwrap(),
+      from_ast("λy: Int. 1")
+    );
+  }
+
+  #[test]
+  fn case_takes_the_branch_of_the_injection() {
+    let either = "λs: Int + (Int -> Int). case s of inl x => x | inr f => f 2";
+    assert_eq!(
+      eval(&format!("({}) (inl 1 as Int + (Int -> Int))", either)),
+      Ok(ast::Term::Int(1))
+    );
+    assert_eq!(
+      eval(&format!(
+        "({}) (inr (λx: Int. x) as Int + (Int -> Int))",
+        either
+      )),
+      Ok(ast::Term::Int(2))
+    );
+    // The scrutinee is evaluated before a branch is taken.
+    assert_eq!(
+      eval("case (λx: Int. inr x as Unit + Int) 3 of inl u => 0 | inr y => y"),
+      Ok(ast::Term::Int(3))
+    );
+  }
+
+  #[test]
+  fn sequences_evaluate_from_left_to_right() {
+    assert_eq!(eval("(); 1"), Ok(ast::Term::Int(1)));
+    assert_eq!(
+      eval("(λf: Int -> Unit. f 1; f 2; 3) (λx. ())"),
+      Ok(ast::Term::Int(3))
+    );
+    let trace = trace(&crate::parse("(λu: Unit. u) (); 1").unwrap()).unwrap();
+    let rules: Vec<_> = trace
+      .steps
+      .iter()
+      .map(|step| step.rules.join(", "))
+      .collect();
+    assert_eq!(rules, vec!["E-Seq, E-AppAbs", "E-SeqNext"]);
+    // The first part runs even when the second is already a value.
+    assert_eq!(
+      compile(&crate::parse("fix (λu: Unit. u); 1").unwrap())
+        .unwrap()
+        .eval_with_limit(100),
+      Err(EvalError::StepLimitExceeded(100))
+    );
+  }
+
+  #[test]
+  fn the_first_part_of_a_sequence_is_unit() {
+    let ctx = Context::new();
+    assert_eq!(from_ast("()").type_of(&ctx), Ok(Type::Unit));
+    assert_eq!(from_ast("(); 1").type_of(&ctx), Ok(Type::Int));
+    let mismatch = || TypecheckerError::TypeMismatch {
+      term: ast::Term::Int(1),
+      expected: Type::Unit,
+      got: Type::Int,
+    };
+    assert_eq!(from_ast("1; 2").type_of(&ctx), Err(mismatch()));
+    assert_eq!(eval("1; 2"), Err(EvalError::Typechecker(mismatch())));
+  }
+
+  #[test]
+  fn recursion_unfolds_fix() {
+    assert_eq!(
+      eval("fix (λf: Int -> Int. λx: Int. x) 3"),
+      Ok(ast::Term::Int(3))
+    );
+    // Calls itself once with inl, where it stops.
+    let f = "let rec f: Int + Unit -> Int = λs. case s of inl x => x | inr u => f (inl 7 as Int + Unit) in f";
+    assert_eq!(
+      eval(&format!("{} (inr () as Int + Unit)", f)),
+      Ok(ast::Term::Int(7))
+    );
+  }
+
+  #[test]
+  fn divergence_is_cut_off() {
+    let diverge = compile(&crate::parse("fix (λx: Int. x)").unwrap()).unwrap();
+    assert_eq!(
+      diverge.eval_with_limit(100),
+      Err(EvalError::StepLimitExceeded(100))
+    );
+    assert_eq!(
+      eval("fix (λx: Int. x)"),
+      Err(EvalError::StepLimitExceeded(STEP_LIMIT))
+    );
+    assert_eq!(
+      EvalError::StepLimitExceeded(100).to_string(),
+      "evaluation did not finish within 100 steps"
+    );
+  }
+
+  #[test]
+  fn traces_record_each_step() {
+    let input = "(λx: Int. x) ((λy: Int. y) 1)";
+    let trace = super::trace(&crate::parse(input).unwrap()).unwrap();
+    let steps: Vec<_> = trace
+      .steps
+      .iter()
+      .map(|step| (step.redex_text(), step.path.clone(), step.rules.join(", ")))
+      .collect();
+    assert_eq!(
+      steps,
+      vec![
+        (
+          "((λy: Int. y) 1)",
+          vec![1],
+          String::from("E-App2, E-AppAbs")
+        ),
+        ("(λx: Int. x) 1", vec![], String::from("E-AppAbs")),
+      ]
+    );
+    assert_eq!(trace.steps[0].term, input);
+    assert_eq!(trace.steps[1].term, "(λx: Int. x) 1");
+    assert_eq!(trace.result, "1");
+    let trace = super::trace(&crate::parse("let p = (1, ()) in p.1").unwrap()).unwrap();
+    let rules: Vec<_> = trace.steps.iter().map(|step| step.rule()).collect();
+    assert_eq!(rules, vec!["E-LetV", "E-PairBeta1"]);
+    // Values take no steps.
+    let trace = super::trace(&crate::parse("λx: Int. x").unwrap()).unwrap();
+    assert!(trace.steps.is_empty());
+    assert_eq!(trace.result, "λx: Int. x");
+  }
+
+  #[test]
+  fn traces_stop_at_the_limit() {
+    let term = compile(&crate::parse("(λx: Int. x) ((λy: Int. y) 1)").unwrap()).unwrap();
+    assert_eq!(
+      term.trace_with_limit(2).map(|trace| trace.steps.len()),
+      Ok(2)
+    );
+    assert_eq!(
+      term.trace_with_limit(1).map(|trace| trace.steps.len()),
+      Err(EvalError::StepLimitExceeded(1))
+    );
+    let diverge = compile(&crate::parse("fix (λx: Int. x)").unwrap()).unwrap();
+    assert_eq!(
+      diverge.trace_with_limit(10).map(|trace| trace.steps.len()),
+      Err(EvalError::StepLimitExceeded(10))
+    );
+  }
+}
@@ -0,0 +1,525 @@
+use crate::ast::{self, Term};
+use crate::typechecker::TypecheckerError;
+use std::fmt;
+
+// Hindley–Milner type inference (Algorithm W).
+//
+// Parameter types may be omitted everywhere. Unknown types are represented by
+// metavariables that are solved by unification, and `let` bound values are
+// generalized over the metavariables that are not mentioned in Γ:
+//
+//   Γ ⊢ t1: τ1    Γ, x: gen(Γ, τ1) ⊢ t2: τ2
+//   ----------------------------------------
+//        Γ ⊢ let x = t1 in t2: τ2
+//
+// where gen(Γ, τ) = ∀α1 ... αn. τ for the αi free in τ but not in Γ.
+
+// Type ::=
+//  | Int
+//  | Unit
+//  | Type -> Type
+//  | Type × Type
+//  | Type + Type
+//  | ?n              -- metavariable
+#[derive(Debug, Clone, PartialEq)]
+pub enum Type {
+  Int,
+  Unit,
+  Arrow(Box<Type>, Box<Type>),
+  Product(Box<Type>, Box<Type>),
+  Sum(Box<Type>, Box<Type>),
+  Meta(usize),
+}
+
+// ∀α1 ... αn. τ
+#[derive(Debug, Clone, PartialEq)]
+pub struct Scheme {
+  pub vars: Vec<usize>,
+  pub typ: Type,
+}
+
+impl From<&ast::Type> for Type {
+  fn from(typ: &ast::Type) -> Type {
+    match typ {
+      ast::Type::Int => Type::Int,
+      ast::Type::Unit => Type::Unit,
+      ast::Type::Arrow(param_type, return_type) => Type::Arrow(
+        Box::new(Type::from(&**param_type)),
+        Box::new(Type::from(&**return_type)),
+      ),
+      ast::Type::Product(left, right) => Type::Product(
+        Box::new(Type::from(&**left)),
+        Box::new(Type::from(&**right)),
+      ),
+      ast::Type::Sum(left, right) => Type::Sum(
+        Box::new(Type::from(&**left)),
+        Box::new(Type::from(&**right)),
+      ),
+    }
+  }
+}
+
+impl Type {
+  // Rebuilds the type, replacing every metavariable with `on_meta(m)`.
+  fn map_metas(&self, on_meta: &dyn Fn(usize) -> Type) -> Type {
+    match self {
+      Type::Int => Type::Int,
+      Type::Unit => Type::Unit,
+      Type::Arrow(param_type, return_type) => Type::Arrow(
+        Box::new(param_type.map_metas(on_meta)),
+        Box::new(return_type.map_metas(on_meta)),
+      ),
+      Type::Product(left, right) => Type::Product(
+        Box::new(left.map_metas(on_meta)),
+        Box::new(right.map_metas(on_meta)),
+      ),
+      Type::Sum(left, right) => Type::Sum(
+        Box::new(left.map_metas(on_meta)),
+        Box::new(right.map_metas(on_meta)),
+      ),
+      Type::Meta(m) => on_meta(*m),
+    }
+  }
+
+  fn metas(&self, metas: &mut Vec<usize>) {
+    match self {
+      Type::Int | Type::Unit => (),
+      Type::Arrow(left, right) | Type::Product(left, right) | Type::Sum(left, right) => {
+        left.metas(metas);
+        right.metas(metas);
+      }
+      Type::Meta(m) => {
+        if !metas.contains(m) {
+          metas.push(*m);
+        }
+      }
+    }
+  }
+
+  // The same precedence levels as the types of the grammar.
+  fn precedence(&self) -> usize {
+    match self {
+      Type::Int | Type::Unit | Type::Meta(_) => 1,
+      Type::Product(..) => 2,
+      Type::Sum(..) => 3,
+      Type::Arrow(..) => 4,
+    }
+  }
+
+  fn fmt_binary(
+    &self,
+    names: &[(usize, String)],
+    f: &mut fmt::Formatter<'_>,
+    op: &str,
+    left: &Type,
+    right: &Type,
+  ) -> fmt::Result {
+    if left.precedence() >= self.precedence() {
+      write!(f, "(")?;
+      left.fmt_with(names, f)?;
+      write!(f, ")")?;
+    } else {
+      left.fmt_with(names, f)?;
+    }
+    write!(f, " {} ", op)?;
+    if right.precedence() > self.precedence() {
+      write!(f, "(")?;
+      right.fmt_with(names, f)?;
+      write!(f, ")")
+    } else {
+      right.fmt_with(names, f)
+    }
+  }
+
+  fn fmt_with(&self, names: &[(usize, String)], f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Type::Int => write!(f, "Int"),
+      Type::Unit => write!(f, "Unit"),
+      Type::Meta(m) => match names.iter().find(|(n, _)| n == m) {
+        Some((_, name)) => write!(f, "{}", name),
+        None => write!(f, "?{}", m),
+      },
+      Type::Arrow(param_type, return_type) => {
+        self.fmt_binary(names, f, "->", param_type, return_type)
+      }
+      Type::Product(left, right) => self.fmt_binary(names, f, "×", left, right),
+      Type::Sum(left, right) => self.fmt_binary(names, f, "+", left, right),
+    }
+  }
+}
+
+impl fmt::Display for Type {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    self.fmt_with(&[], f)
+  }
+}
+
+// Quantified variables are named a, b, c, ... in order of appearance.
+impl fmt::Display for Scheme {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let mut metas = Vec::new();
+    self.typ.metas(&mut metas);
+    let names: Vec<(usize, String)> = metas
+      .into_iter()
+      .filter(|m| self.vars.contains(m))
+      .enumerate()
+      .map(|(i, m)| {
+        let letter = char::from(b'a' + (i % 26) as u8);
+        (m, format!("{}{}", letter, "'".repeat(i / 26)))
+      })
+      .collect();
+    if !names.is_empty() {
+      write!(f, "∀")?;
+      for (_, name) in &names {
+        write!(f, "{}", name)?;
+        if Some(name) != names.last().map(|(_, name)| name) {
+          write!(f, " ")?;
+        }
+      }
+      write!(f, ". ")?;
+    }
+    self.typ.fmt_with(&names, f)
+  }
+}
+
+#[derive(Debug, Default)]
+struct Inferencer {
+  // Solutions for metavariables, indexed by metavariable.
+  solutions: Vec<Option<Type>>,
+}
+
+impl Inferencer {
+  fn fresh(&mut self) -> Type {
+    self.solutions.push(None);
+    Type::Meta(self.solutions.len() - 1)
+  }
+
+  // Replaces every solved metavariable in `typ` by its solution.
+  fn resolve(&self, typ: &Type) -> Type {
+    typ.map_metas(&|m| match &self.solutions[m] {
+      Some(solution) => self.resolve(solution),
+      None => Type::Meta(m),
+    })
+  }
+
+  fn occurs(&self, m: usize, typ: &Type) -> bool {
+    let mut metas = Vec::new();
+    self.resolve(typ).metas(&mut metas);
+    metas.contains(&m)
+  }
+
+  fn unify(&mut self, expected: &Type, got: &Type) -> Result<(), TypecheckerError> {
+    match (self.resolve(expected), self.resolve(got)) {
+      (Type::Int, Type::Int) | (Type::Unit, Type::Unit) => Ok(()),
+      (Type::Meta(a), Type::Meta(b)) if a == b => Ok(()),
+      (Type::Meta(m), typ) | (typ, Type::Meta(m)) => {
+        if self.occurs(m, &typ) {
+          return Err(TypecheckerError::InfiniteType(Type::Meta(m), typ));
+        }
+        self.solutions[m] = Some(typ);
+        Ok(())
+      }
+      (Type::Arrow(left_a, right_a), Type::Arrow(left_b, right_b))
+      | (Type::Product(left_a, right_a), Type::Product(left_b, right_b))
+      | (Type::Sum(left_a, right_a), Type::Sum(left_b, right_b)) => {
+        self.unify(&left_a, &left_b)?;
+        self.unify(&right_a, &right_b)
+      }
+      (expected, got) => Err(TypecheckerError::CannotUnify { expected, got }),
+    }
+  }
+
+  fn instantiate(&mut self, scheme: &Scheme) -> Type {
+    let fresh: Vec<(usize, Type)> = scheme.vars.iter().map(|var| (*var, self.fresh())).collect();
+    substitute(&self.resolve(&scheme.typ), &fresh)
+  }
+
+  fn generalize(&self, env: &[(String, Scheme)], typ: &Type) -> Scheme {
+    let mut env_metas = Vec::new();
+    for (_, scheme) in env {
+      let mut metas = Vec::new();
+      self.resolve(&scheme.typ).metas(&mut metas);
+      env_metas.extend(metas.into_iter().filter(|m| !scheme.vars.contains(m)));
+    }
+    let typ = self.resolve(typ);
+    let mut vars = Vec::new();
+    typ.metas(&mut vars);
+    vars.retain(|m| !env_metas.contains(m));
+    Scheme { vars, typ }
+  }
+
+  fn infer(
+    &mut self,
+    env: &mut Vec<(String, Scheme)>,
+    term: &Term,
+  ) -> Result<Type, TypecheckerError> {
+    match term {
+      Term::Int(_) => Ok(Type::Int),
+      Term::Unit => Ok(Type::Unit),
+      // x: ∀α1 ... αn. τ ∈ Γ
+      // ---------------------------
+      // Γ ⊢ x: τ[β1/α1 ... βn/αn]
+      Term::Var(x) => match env.iter().rev().find(|(y, _)| y == x) {
+        None => Err(TypecheckerError::UndefinedVariable(x.clone())),
+        Some((_, scheme)) => {
+          let scheme = scheme.clone();
+          Ok(self.instantiate(&scheme))
+        }
+      },
+      // Γ ⊢ t1: τ1    Γ ⊢ t2: τ2    τ1 = τ2 -> β
+      // ----------------------------------------
+      //              Γ ⊢ t1 t2: β
+      Term::App(f, arg) => {
+        let f_typ = self.infer(env, f)?;
+        let arg_typ = self.infer(env, arg)?;
+        let return_typ = self.fresh();
+        self.unify(
+          &f_typ,
+          &Type::Arrow(Box::new(arg_typ), Box::new(return_typ.clone())),
+        )?;
+        Ok(return_typ)
+      }
+      //     Γ, x: β ⊢ t: τ
+      // ---------------------
+      // Γ ⊢ (λx. t): β -> τ
+      Term::Abs {
+        param_name,
+        param_type,
+        body,
+      } => {
+        let param_typ = match param_type {
+          Some(param_type) => Type::from(param_type),
+          None => self.fresh(),
+        };
+        env.push((
+          param_name.clone(),
+          Scheme {
+            vars: Vec::new(),
+            typ: param_typ.clone(),
+          },
+        ));
+        let body_typ = self.infer(env, body);
+        env.pop();
+        Ok(Type::Arrow(Box::new(param_typ), Box::new(body_typ?)))
+      }
+      Term::Ann(term, typ) => {
+        let term_typ = self.infer(env, term)?;
+        let typ = Type::from(typ);
+        self.unify(&typ, &term_typ)?;
+        Ok(typ)
+      }
+      Term::Let { name, value, body } => {
+        let value_typ = self.infer(env, value)?;
+        let scheme = self.generalize(env, &value_typ);
+        env.push((name.clone(), scheme));
+        let body_typ = self.infer(env, body);
+        env.pop();
+        body_typ
+      }
+      Term::Pair(first, second) => {
+        let first_typ = self.infer(env, first)?;
+        let second_typ = self.infer(env, second)?;
+        Ok(Type::Product(Box::new(first_typ), Box::new(second_typ)))
+      }
+      // Γ ⊢ t: τ    τ = β1 × β2
+      // -----------------------
+      //      Γ ⊢ t.i: βi
+      Term::Proj(pair, i) => {
+        let (first, second) = (self.fresh(), self.fresh());
+        let pair_typ = self.infer(env, pair)?;
+        self.unify(
+          &Type::Product(Box::new(first.clone()), Box::new(second.clone())),
+          &pair_typ,
+        )?;
+        match i {
+          1 => Ok(first),
+          2 => Ok(second),
+          _ => Err(TypecheckerError::UnexpectedTerm {
+            expected: String::from("projection .1 or .2"),
+            got: term.clone(),
+          }),
+        }
+      }
+      Term::Inl(injected, typ) | Term::Inr(injected, typ) => {
+        let (left, right) = (self.fresh(), self.fresh());
+        let typ = Type::from(typ);
+        self.unify(
+          &typ,
+          &Type::Sum(Box::new(left.clone()), Box::new(right.clone())),
+        )?;
+        let injected_typ = self.infer(env, injected)?;
+        let expected = if let Term::Inl(..) = term {
+          left
+        } else {
+          right
+        };
+        self.unify(&expected, &injected_typ)?;
+        Ok(typ)
+      }
+      // Γ ⊢ t: β1 + β2    Γ, x1: β1 ⊢ t1: τ    Γ, x2: β2 ⊢ t2: τ
+      // -------------------------------------------------------
+      //    Γ ⊢ case t of inl x1 => t1 | inr x2 => t2: τ
+      Term::Case {
+        scrutinee,
+        left_var,
+        left,
+        right_var,
+        right,
+      } => {
+        let (left_typ, right_typ) = (self.fresh(), self.fresh());
+        let scrutinee_typ = self.infer(env, scrutinee)?;
+        self.unify(
+          &Type::Sum(Box::new(left_typ.clone()), Box::new(right_typ.clone())),
+          &scrutinee_typ,
+        )?;
+        let typ = self.infer_branch(env, left_var, left_typ, left)?;
+        let right_branch_typ = self.infer_branch(env, right_var, right_typ, right)?;
+        self.unify(&typ, &right_branch_typ)?;
+        Ok(typ)
+      }
+      // Γ ⊢ t1: Unit    Γ ⊢ t2: τ
+      // -------------------------
+      //     Γ ⊢ t1; t2: τ
+      Term::Seq(first, second) => {
+        let first_typ = self.infer(env, first)?;
+        self.unify(&Type::Unit, &first_typ)?;
+        self.infer(env, second)
+      }
+      // Γ ⊢ t: τ    τ = β -> β
+      // ----------------------
+      //     Γ ⊢ fix t: β
+      Term::Fix(term) => {
+        let typ = self.fresh();
+        let term_typ = self.infer(env, term)?;
+        self.unify(
+          &Type::Arrow(Box::new(typ.clone()), Box::new(typ.clone())),
+          &term_typ,
+        )?;
+        Ok(typ)
+      }
+    }
+  }
+
+  // Infers the type of a case branch that binds `var: typ`.
+  fn infer_branch(
+    &mut self,
+    env: &mut Vec<(String, Scheme)>,
+    var: &str,
+    typ: Type,
+    branch: &Term,
+  ) -> Result<Type, TypecheckerError> {
+    env.push((
+      String::from(var),
+      Scheme {
+        vars: Vec::new(),
+        typ,
+      },
+    ));
+    let branch_typ = self.infer(env, branch);
+    env.pop();
+    branch_typ
+  }
+}
+
+fn substitute(typ: &Type, substitution: &[(usize, Type)]) -> Type {
+  typ.map_metas(&|m| match substitution.iter().find(|(var, _)| *var == m) {
+    Some((_, to)) => to.clone(),
+    None => Type::Meta(m),
+  })
+}
+
+// Infers the most general type of `term`.
+pub fn infer(term: &Term) -> Result<Scheme, TypecheckerError> {
+  let mut inferencer = Inferencer::default();
+  let typ = inferencer.infer(&mut Vec::new(), term)?;
+  Ok(inferencer.generalize(&[], &typ))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn infer(input: &str) -> Result<Scheme, TypecheckerError> {
+    super::infer(&crate::parse(input).unwrap())
+  }
+
+  #[test]
+  fn infers_most_general_types() {
+    let scheme = infer("λf. λx. f (f x)").unwrap();
+    assert_eq!(scheme.vars.len(), 1);
+    assert_eq!(scheme.to_string(), "∀a. (a -> a) -> a -> a");
+    assert_eq!(
+      infer("λf. λg. λx. f (g x)").unwrap().to_string(),
+      "∀a b c. (a -> b) -> (c -> a) -> c -> b"
+    );
+    assert_eq!(infer("λx. (x, 1)").unwrap().to_string(), "∀a. a -> a × Int");
+    assert_eq!(
+      infer("(λx. x) 1"),
+      Ok(Scheme {
+        vars: Vec::new(),
+        typ: Type::Int,
+      })
+    );
+  }
+
+  #[test]
+  fn self_application_is_an_infinite_type() {
+    // x: ?0 is applied to itself, so ?0 = ?0 -> ?1.
+    assert_eq!(
+      infer("λx. x x"),
+      Err(TypecheckerError::InfiniteType(
+        Type::Meta(0),
+        Type::Arrow(Box::new(Type::Meta(0)), Box::new(Type::Meta(1)))
+      ))
+    );
+    assert_eq!(
+      infer("λx. x x").unwrap_err().to_string(),
+      "cannot construct the infinite type ?0 = ?0 -> ?1"
+    );
+  }
+
+  #[test]
+  fn let_bound_values_are_generalized() {
+    assert_eq!(
+      infer("let f = λx. x in (f 1, f ())").unwrap().to_string(),
+      "Int × Unit"
+    );
+    assert_eq!(
+      infer("let k = λx. λy. x in k").unwrap().to_string(),
+      "∀a b. a -> b -> a"
+    );
+    // λ bound variables are not generalized.
+    assert_eq!(
+      infer("(λf. (f 1, f ())) (λx. x)"),
+      Err(TypecheckerError::CannotUnify {
+        expected: Type::Int,
+        got: Type::Unit,
+      })
+    );
+  }
+
+  #[test]
+  fn mismatched_types_do_not_unify() {
+    assert_eq!(
+      infer("1 ()"),
+      Err(TypecheckerError::CannotUnify {
+        expected: Type::Int,
+        got: Type::Arrow(Box::new(Type::Unit), Box::new(Type::Meta(0))),
+      })
+    );
+    assert_eq!(
+      infer("λx: Int. x ()"),
+      Err(TypecheckerError::CannotUnify {
+        expected: Type::Int,
+        got: Type::Arrow(Box::new(Type::Unit), Box::new(Type::Meta(0))),
+      })
+    );
+    assert_eq!(
+      infer("λp. (p.1 1, p.1 ())"),
+      Err(TypecheckerError::CannotUnify {
+        expected: Type::Int,
+        got: Type::Unit,
+      })
+    );
+  }
+}
@@ -0,0 +1,77 @@
+pub mod ast;
+pub mod debruijn;
+mod grammar;
+pub mod inference;
+pub mod typechecker;
+
+pub use ast::{Term, Type};
+pub use typechecker::{infer, infer_in, TypecheckerError, TypingContext};
+
+pub type ParseError<'input> =
+  lalrpop_util::ParseError<usize, lalrpop_util::lexer::Token<'input>, &'static str>;
+
+pub fn parse(input: &str) -> Result<Term, ParseError<'_>> {
+  grammar::TermParser::new().parse(input)
+}
+
+pub fn parse_type(input: &str) -> Result<Type, ParseError<'_>> {
+  grammar::TypeParser::new().parse(input)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn parses_terms_and_types() {
+    assert_eq!(
+      parse("λx: Int. x"),
+      Ok(Term::Abs {
+        param_name: String::from("x"),
+        param_type: Some(Type::Int),
+        body: Box::new(Term::Var(String::from("x"))),
+      })
+    );
+    assert_eq!(
+      parse_type("Int -> Unit"),
+      Ok(Type::Arrow(Box::new(Type::Int), Box::new(Type::Unit)))
+    );
+    assert!(parse("λx: Int.").is_err());
+    assert!(parse_type("Int ->").is_err());
+    assert_eq!(parse("2147483647"), Ok(Term::Int(i32::MAX)));
+    assert_eq!(
+      parse("99999999999"),
+      Err(ParseError::User {
+        error: "integer literal is too large"
+      })
+    );
+  }
+
+  #[test]
+  fn infers_in_a_given_context() {
+    let term = parse("f x").unwrap();
+    assert_eq!(
+      infer(&term),
+      Err(TypecheckerError::UndefinedVariable(String::from("f")))
+    );
+    let ctx = TypingContext::new()
+      .assign(String::from("f"), parse_type("Int -> Unit").unwrap())
+      .assign(String::from("x"), Type::Int);
+    assert_eq!(infer_in(&ctx, &term), Ok(Type::Unit));
+    // Later bindings shadow earlier ones.
+    let ctx = ctx.assign(String::from("x"), Type::Unit);
+    assert_eq!(
+      infer_in(&ctx, &term),
+      Err(TypecheckerError::TypeMismatch {
+        term: Term::Var(String::from("x")),
+        expected: Type::Int,
+        got: Type::Unit,
+      })
+    );
+    // λs take their parameter types from the context they are checked in.
+    assert_eq!(
+      infer_in(&ctx, &parse("(λg. g x : (Unit -> Int) -> Int)").unwrap()),
+      Ok(parse_type("(Unit -> Int) -> Int").unwrap())
+    );
+  }
+}
@@ -1,13 +1,30 @@
-mod ast;
-mod grammar;
-mod typechecker;
-
-use ast::Term;
+use lambda_core::Diagnostic;
+use simply_typed_lambda_calculus::{debruijn, inference, typechecker, Term};
 
 fn parse(input: &str) -> Term {
-  grammar::TermParser::new().parse(input).unwrap()
+  match simply_typed_lambda_calculus::parse(input) {
+    Ok(term) => term,
+    Err(err) => panic!("{}", Diagnostic::from_parse_error(&err).render(input)),
+  }
 }
 
 fn main() {
-  dbg!(typechecker::infer(&parse("(λx: Int. x) 1")));
+  let e = parse("(λf: Int -> Int. λx. f x : (Int -> Int) -> Int -> Int) (λx. x) 1");
+  println!("{}", e);
+  match (typechecker::infer(&e), debruijn::eval(&e)) {
+    (Ok(typ), Ok(value)) => println!("{} : {}", value, typ),
+    (Err(err), _) => println!("{}", err),
+    (_, Err(err)) => println!("{}", err),
+  }
+  match debruijn::trace(&e) {
+    Ok(trace) => println!("{}", trace.render_text()),
+    Err(err) => println!("{}", err),
+  }
+
+  let e = parse("let t = λf. λx. f (f x) in t t");
+  println!("{}", e);
+  match inference::infer(&e) {
+    Ok(scheme) => println!("{}", scheme),
+    Err(err) => println!("{}", err),
+  }
 }
@@ -1,4 +1,6 @@
 use crate::ast::{Term, Type};
+use crate::debruijn;
+use crate::inference;
 use thiserror::Error;
 
 // Γ(x) = τ
@@ -16,10 +18,109 @@ use thiserror::Error;
 // Γ ⊢ (λx: σ. t): σ -> τ
 //
 //
+// Γ ⊢ t ⇒ τ synthesizes τ from t, Γ ⊢ t ⇐ τ checks t against a known τ.
+// Unannotated abstractions can only be checked.
+//
+//    Γ, x: σ ⊢ t ⇐ τ
+// ---------------------
+// Γ ⊢ (λx. t) ⇐ σ -> τ
+//
+//
+//    Γ ⊢ t ⇐ τ
+// ---------------
+// Γ ⊢ (t : τ) ⇒ τ
+//
+//
+// Γ ⊢ t ⇒ σ    σ = τ
+// ------------------
+//     Γ ⊢ t ⇐ τ
+//
+//
+// Γ ⊢ t1 ⇒ σ    Γ, x: σ ⊢ t2 ⇒ τ
+// ------------------------------
+//  Γ ⊢ let x = t1 in t2 ⇒ τ
+//
+//
+// Γ ⊢ t1 ⇒ τ1    Γ ⊢ t2 ⇒ τ2
+// --------------------------
+// Γ ⊢ (t1, t2) ⇒ τ1 × τ2
+//
+//
+// Γ ⊢ t ⇒ τ1 × τ2
+// ---------------
+//  Γ ⊢ t.i ⇒ τi
+//
+//
+//           Γ ⊢ t ⇐ τ1
+// -------------------------------
+// Γ ⊢ inl t as τ1 + τ2 ⇒ τ1 + τ2
+//
+//
+//           Γ ⊢ t ⇐ τ2
+// -------------------------------
+// Γ ⊢ inr t as τ1 + τ2 ⇒ τ1 + τ2
+//
+//
+// Γ ⊢ t ⇒ τ1 + τ2    Γ, x1: τ1 ⊢ t1 ⇒ τ    Γ, x2: τ2 ⊢ t2 ⇐ τ
+// ----------------------------------------------------------
+//    Γ ⊢ case t of inl x1 => t1 | inr x2 => t2 ⇒ τ
+//
+//
+// -------------
+// Γ ⊢ () ⇒ Unit
+//
+//
+// Γ ⊢ t1 ⇐ Unit    Γ ⊢ t2 ⇒ τ
+// ---------------------------
+//      Γ ⊢ t1; t2 ⇒ τ
+//
+//
+// Γ ⊢ t ⇒ τ -> τ
+// --------------
+// Γ ⊢ fix t ⇒ τ
+//
+// `let rec x: τ = t1 in t2` is sugar for `let x = fix (λx: τ. t1) in t2`.
+// With fix, terms no longer have to terminate.
+//
+//
 // ------------------------
 // (λx: τ. t) t' |> t[t'/x]
 //
 //
+// ----------------------------
+// let x = v in t |> t[v/x]
+//
+//
+//              t1 |> t1'
+// ------------------------------------
+// let x = t1 in t2 |> let x = t1' in t2
+//
+//
+// (v1, v2).i |> vi
+//
+//
+// case (inl v as τ) of inl x1 => t1 | inr x2 => t2 |> t1[v/x1]
+//
+//
+// case (inr v as τ) of inl x1 => t1 | inr x2 => t2 |> t2[v/x2]
+//
+//
+//      t1 |> t1'
+// -------------------
+// t1; t2 |> t1'; t2
+//
+//
+// (); t |> t
+//
+//
+//       t |> t'
+// -----------------
+// fix t |> fix t'
+//
+//
+// fix (λx: τ. t) |> t[fix (λx: τ. t)/x]
+//
+//
 // ------
 // t |> t
 //
@@ -41,116 +142,539 @@ use thiserror::Error;
 pub enum TypecheckerError {
   #[error("variable {0} is not defined")]
   UndefinedVariable(String),
-  #[error("expected term {term:?} to have type {expected:?} but it has type {got:?}")]
+  #[error("expected term {term} to have type {expected} but it has type {got}")]
   TypeMismatch {
     term: Term,
     expected: Type,
     got: Type,
   },
-  #[error("expected {expected:?} but got {got:?}")]
+  #[error("expected {expected} but got {got}")]
   UnexpectedTerm { expected: String, got: Term },
+  #[error("expected {expected} but got {got}")]
+  UnexpectedType { expected: String, got: Type },
+  #[error("cannot infer the type of {0}, add a type annotation")]
+  MissingAnnotation(Term),
+  #[error("cannot unify {expected} with {got}")]
+  CannotUnify {
+    expected: inference::Type,
+    got: inference::Type,
+  },
+  #[error("cannot construct the infinite type {0} = {1}")]
+  InfiniteType(inference::Type, inference::Type),
 }
 
-#[derive(Debug, Clone)]
-enum List<T> {
-  Cons(T, Box<List<T>>),
-  Nil,
-}
-
-#[derive(Debug)]
-struct TypingContext {
-  type_assignments: List<(String, Type)>,
-}
-
-impl TypingContext {
-  pub fn new() -> Self {
-    Self {
-      type_assignments: List::Nil,
-    }
-  }
-
-  pub fn assign(&self, var: String, typ: Type) -> TypingContext {
-    TypingContext {
-      type_assignments: List::Cons((var, typ), Box::new(self.type_assignments.clone())),
-    }
-  }
-
-  fn get_var(xs: &List<(String, Type)>, var: &String) -> Option<Type> {
-    match xs {
-      List::Nil => None,
-      List::Cons((x, typ), tail) => {
-        if x == var {
-          Some(typ.clone())
-        } else {
-          Self::get_var(tail, var)
-        }
-      }
-    }
-  }
-
-  pub fn get(&self, var: &String) -> Option<Type> {
-    Self::get_var(&self.type_assignments, var)
-  }
-}
+// Γ, the types of the variables in scope.
+pub type TypingContext = lambda_core::TypingContext<Type>;
 
-fn type_of(ctx: &TypingContext, term: &Term) -> Result<Type, TypecheckerError> {
+// Γ ⊢ t ⇒ τ
+//
+// Synthesizes the type of `term`, returning it together with the elaborated
+// term, where every λ carries the parameter type that was inferred for it.
+fn type_of(ctx: &TypingContext, term: &Term) -> Result<(Term, Type), TypecheckerError> {
   match term {
-    Term::Int(_) => Ok(Type::Int),
+    Term::Int(_) => Ok((term.clone(), Type::Int)),
+    // -------------
+    // Γ ⊢ () ⇒ Unit
+    Term::Unit => Ok((term.clone(), Type::Unit)),
     // Γ(x) = τ
     // --------
     // Γ ⊢ x: τ
     Term::Var(x) => match ctx.get(x) {
       None => Err(TypecheckerError::UndefinedVariable(x.clone())),
-      Some(typ) => Ok(typ.clone()),
+      Some(typ) => Ok((term.clone(), typ.clone())),
     },
-    // Γ ⊢ t1: σ -> τ    Γ ⊢ t2: σ
-    // ---------------------------
-    //       Γ ⊢ (t1 t2): τ
-    Term::App(f, arg) => match &**f {
-      Term::Abs {
-        param_name,
-        param_type,
-        body,
-      } => {
-        let arg_type = type_of(ctx, arg)?;
-
-        if &arg_type != param_type {
-          return Err(TypecheckerError::TypeMismatch {
-            term: *arg.clone(),
-            expected: param_type.clone(),
-            got: arg_type,
-          });
-        }
-
-        let ctx = ctx.assign(param_name.clone(), param_type.clone());
-
-        let body_typ = type_of(&ctx, body)?;
-
-        Ok(body_typ)
+    // Γ ⊢ t1 ⇒ σ -> τ    Γ ⊢ t2 ⇐ σ
+    // -----------------------------
+    //       Γ ⊢ (t1 t2) ⇒ τ
+    Term::App(f, arg) => match type_of(ctx, f)? {
+      (f, Type::Arrow(param_type, return_type)) => {
+        let arg = check(ctx, arg, &param_type)?;
+        Ok((Term::App(Box::new(f), Box::new(arg)), *return_type))
       }
       _ => Err(TypecheckerError::UnexpectedTerm {
         expected: String::from("abstraction"),
         got: *f.clone(),
       }),
     },
-    //    Γ, x: σ ⊢ t: τ
+    //    Γ, x: σ ⊢ t ⇒ τ
     // ----------------------
-    // Γ ⊢ (λx: σ. t): σ -> τ
+    // Γ ⊢ (λx: σ. t) ⇒ σ -> τ
     Term::Abs {
       param_name,
-      param_type,
+      param_type: Some(param_type),
       body,
     } => {
       let ctx = ctx.assign(param_name.clone(), param_type.clone());
-      let body_typ = type_of(&ctx, body)?;
-      Ok(Type::Arrow(
-        Box::new(param_type.clone()),
-        Box::new(body_typ),
+      let (body, body_typ) = type_of(&ctx, body)?;
+      Ok((
+        Term::Abs {
+          param_name: param_name.clone(),
+          param_type: Some(param_type.clone()),
+          body: Box::new(body),
+        },
+        Type::Arrow(Box::new(param_type.clone()), Box::new(body_typ)),
+      ))
+    }
+    Term::Abs {
+      param_type: None, ..
+    } => Err(TypecheckerError::MissingAnnotation(term.clone())),
+    //    Γ ⊢ t ⇐ τ
+    // ---------------
+    // Γ ⊢ (t : τ) ⇒ τ
+    Term::Ann(term, typ) => Ok((check(ctx, term, typ)?, typ.clone())),
+    // Γ ⊢ t1 ⇒ σ    Γ, x: σ ⊢ t2 ⇒ τ
+    // ------------------------------
+    //  Γ ⊢ let x = t1 in t2 ⇒ τ
+    Term::Let { name, value, body } => {
+      let (value, value_typ) = type_of(ctx, value)?;
+      let ctx = ctx.assign(name.clone(), value_typ);
+      let (body, body_typ) = type_of(&ctx, body)?;
+      Ok((
+        Term::Let {
+          name: name.clone(),
+          value: Box::new(value),
+          body: Box::new(body),
+        },
+        body_typ,
+      ))
+    }
+    // Γ ⊢ t1 ⇐ Unit    Γ ⊢ t2 ⇒ τ
+    // ---------------------------
+    //      Γ ⊢ t1; t2 ⇒ τ
+    Term::Seq(first, second) => {
+      let first = check(ctx, first, &Type::Unit)?;
+      let (second, typ) = type_of(ctx, second)?;
+      Ok((Term::Seq(Box::new(first), Box::new(second)), typ))
+    }
+    //    Γ, x: τ ⊢ t ⇐ τ
+    // ----------------------
+    // Γ ⊢ fix (λx: τ. t) ⇒ τ
+    //
+    //  Γ ⊢ t ⇒ τ -> τ
+    // ---------------
+    //  Γ ⊢ fix t ⇒ τ
+    Term::Fix(term) => match &**term {
+      // The parameter type is all that is needed, so the body can be checked.
+      Term::Abs {
+        param_type: Some(param_type),
+        ..
+      } => {
+        let arrow = Type::Arrow(Box::new(param_type.clone()), Box::new(param_type.clone()));
+        Ok((
+          Term::Fix(Box::new(check(ctx, term, &arrow)?)),
+          param_type.clone(),
+        ))
+      }
+      _ => match type_of(ctx, term)? {
+        (term, Type::Arrow(param_type, return_type)) if param_type == return_type => {
+          Ok((Term::Fix(Box::new(term)), *return_type))
+        }
+        (_, typ) => Err(TypecheckerError::UnexpectedType {
+          expected: String::from("function type of the form T -> T"),
+          got: typ,
+        }),
+      },
+    },
+    // Γ ⊢ t1 ⇒ τ1    Γ ⊢ t2 ⇒ τ2
+    // --------------------------
+    // Γ ⊢ (t1, t2) ⇒ τ1 × τ2
+    Term::Pair(first, second) => {
+      let (first, first_typ) = type_of(ctx, first)?;
+      let (second, second_typ) = type_of(ctx, second)?;
+      Ok((
+        Term::Pair(Box::new(first), Box::new(second)),
+        Type::Product(Box::new(first_typ), Box::new(second_typ)),
+      ))
+    }
+    // Γ ⊢ t ⇒ τ1 × τ2
+    // ---------------
+    //  Γ ⊢ t.i ⇒ τi
+    Term::Proj(term, i) => {
+      let (term, typ) = type_of(ctx, term)?;
+      let component_typ = match (typ, i) {
+        (Type::Product(first, _), 1) => *first,
+        (Type::Product(_, second), 2) => *second,
+        (typ, _) => {
+          return Err(TypecheckerError::UnexpectedType {
+            expected: format!("a pair type with a component {}", i),
+            got: typ,
+          })
+        }
+      };
+      Ok((Term::Proj(Box::new(term), *i), component_typ))
+    }
+    //           Γ ⊢ t ⇐ τ1
+    // -------------------------------
+    // Γ ⊢ inl t as τ1 + τ2 ⇒ τ1 + τ2
+    //
+    //           Γ ⊢ t ⇐ τ2
+    // -------------------------------
+    // Γ ⊢ inr t as τ1 + τ2 ⇒ τ1 + τ2
+    Term::Inl(injected, typ) | Term::Inr(injected, typ) => match typ {
+      Type::Sum(left, right) => {
+        let elaborated = if let Term::Inl(..) = term {
+          Term::Inl(Box::new(check(ctx, injected, left)?), typ.clone())
+        } else {
+          Term::Inr(Box::new(check(ctx, injected, right)?), typ.clone())
+        };
+        Ok((elaborated, typ.clone()))
+      }
+      _ => Err(TypecheckerError::UnexpectedType {
+        expected: String::from("sum type"),
+        got: typ.clone(),
+      }),
+    },
+    // Γ ⊢ t ⇒ τ1 + τ2    Γ, x1: τ1 ⊢ t1 ⇒ τ    Γ, x2: τ2 ⊢ t2 ⇐ τ
+    // ----------------------------------------------------------
+    //    Γ ⊢ case t of inl x1 => t1 | inr x2 => t2 ⇒ τ
+    Term::Case {
+      scrutinee,
+      left_var,
+      left,
+      right_var,
+      right,
+    } => {
+      let (scrutinee, left_typ, right_typ) = type_of_scrutinee(ctx, scrutinee)?;
+      let (left, typ) = type_of(&ctx.assign(left_var.clone(), left_typ), left)?;
+      let right = check(&ctx.assign(right_var.clone(), right_typ), right, &typ)?;
+      Ok((
+        Term::Case {
+          scrutinee: Box::new(scrutinee),
+          left_var: left_var.clone(),
+          left: Box::new(left),
+          right_var: right_var.clone(),
+          right: Box::new(right),
+        },
+        typ,
       ))
     }
   }
 }
 
+// Synthesizes the type of the scrutinee of a case, which must be a sum.
+fn type_of_scrutinee(
+  ctx: &TypingContext,
+  scrutinee: &Term,
+) -> Result<(Term, Type, Type), TypecheckerError> {
+  match type_of(ctx, scrutinee)? {
+    (scrutinee, Type::Sum(left, right)) => Ok((scrutinee, *left, *right)),
+    (_, typ) => Err(TypecheckerError::UnexpectedType {
+      expected: String::from("sum type"),
+      got: typ,
+    }),
+  }
+}
+
+// Γ ⊢ t ⇐ τ
+//
+// Checks `term` against the `expected` type, returning the elaborated term.
+fn check(ctx: &TypingContext, term: &Term, expected: &Type) -> Result<Term, TypecheckerError> {
+  match (term, expected) {
+    //      Γ, x: σ ⊢ t ⇐ τ
+    // -------------------------
+    // Γ ⊢ (λx[: σ]. t) ⇐ σ -> τ
+    (
+      Term::Abs {
+        param_name,
+        param_type,
+        body,
+      },
+      Type::Arrow(expected_param_type, return_type),
+    ) => {
+      if let Some(param_type) = param_type {
+        if param_type != &**expected_param_type {
+          return Err(TypecheckerError::TypeMismatch {
+            term: Term::Var(param_name.clone()),
+            expected: *expected_param_type.clone(),
+            got: param_type.clone(),
+          });
+        }
+      }
+      let ctx = ctx.assign(param_name.clone(), *expected_param_type.clone());
+      Ok(Term::Abs {
+        param_name: param_name.clone(),
+        param_type: Some(*expected_param_type.clone()),
+        body: Box::new(check(&ctx, body, return_type)?),
+      })
+    }
+    (Term::Abs { .. }, _) => Err(TypecheckerError::UnexpectedType {
+      expected: String::from("function type"),
+      got: expected.clone(),
+    }),
+    // Γ ⊢ t1 ⇒ σ    Γ, x: σ ⊢ t2 ⇐ τ
+    // ------------------------------
+    //  Γ ⊢ let x = t1 in t2 ⇐ τ
+    (Term::Let { name, value, body }, _) => {
+      let (value, value_typ) = type_of(ctx, value)?;
+      let ctx = ctx.assign(name.clone(), value_typ);
+      Ok(Term::Let {
+        name: name.clone(),
+        value: Box::new(value),
+        body: Box::new(check(&ctx, body, expected)?),
+      })
+    }
+    // Γ ⊢ t1 ⇐ Unit    Γ ⊢ t2 ⇐ τ
+    // ---------------------------
+    //      Γ ⊢ t1; t2 ⇐ τ
+    (Term::Seq(first, second), _) => Ok(Term::Seq(
+      Box::new(check(ctx, first, &Type::Unit)?),
+      Box::new(check(ctx, second, expected)?),
+    )),
+    // Γ ⊢ t ⇐ τ -> τ
+    // --------------
+    // Γ ⊢ fix t ⇐ τ
+    (Term::Fix(term), _) => Ok(Term::Fix(Box::new(check(
+      ctx,
+      term,
+      &Type::Arrow(Box::new(expected.clone()), Box::new(expected.clone())),
+    )?))),
+    // Γ ⊢ t1 ⇐ τ1    Γ ⊢ t2 ⇐ τ2
+    // --------------------------
+    // Γ ⊢ (t1, t2) ⇐ τ1 × τ2
+    (Term::Pair(first, second), Type::Product(first_typ, second_typ)) => Ok(Term::Pair(
+      Box::new(check(ctx, first, first_typ)?),
+      Box::new(check(ctx, second, second_typ)?),
+    )),
+    // Γ ⊢ t ⇒ τ1 + τ2    Γ, x1: τ1 ⊢ t1 ⇐ τ    Γ, x2: τ2 ⊢ t2 ⇐ τ
+    // ----------------------------------------------------------
+    //    Γ ⊢ case t of inl x1 => t1 | inr x2 => t2 ⇐ τ
+    (
+      Term::Case {
+        scrutinee,
+        left_var,
+        left,
+        right_var,
+        right,
+      },
+      _,
+    ) => {
+      let (scrutinee, left_typ, right_typ) = type_of_scrutinee(ctx, scrutinee)?;
+      Ok(Term::Case {
+        scrutinee: Box::new(scrutinee),
+        left_var: left_var.clone(),
+        left: Box::new(check(
+          &ctx.assign(left_var.clone(), left_typ),
+          left,
+          expected,
+        )?),
+        right_var: right_var.clone(),
+        right: Box::new(check(
+          &ctx.assign(right_var.clone(), right_typ),
+          right,
+          expected,
+        )?),
+      })
+    }
+    // Γ ⊢ t ⇒ σ    σ = τ
+    // ------------------
+    //     Γ ⊢ t ⇐ τ
+    _ => {
+      let (term, typ) = type_of(ctx, term)?;
+
+      if &typ != expected {
+        return Err(TypecheckerError::TypeMismatch {
+          term,
+          expected: expected.clone(),
+          got: typ,
+        });
+      }
+
+      Ok(term)
+    }
+  }
+}
+
+// Typechecks `term`, returning it with every λ annotated with its parameter type.
+pub fn elaborate(term: &Term) -> Result<(Term, Type), TypecheckerError> {
+  elaborate_in(&TypingContext::new(), term)
+}
+
+// Like `elaborate`, for a term whose free variables are typed by `ctx`.
+//
+// The elaborated term is checked again on the de Bruijn representation, whose
+// rules do not depend on names, and the type it gets there is the one
+// returned.
+pub fn elaborate_in(ctx: &TypingContext, term: &Term) -> Result<(Term, Type), TypecheckerError> {
+  let (term, _) = type_of(ctx, term)?;
+  let mut bindings: Vec<_> = ctx.iter().collect();
+  bindings.reverse();
+  let ctx = bindings
+    .into_iter()
+    .fold(debruijn::Context::new(), |ctx, (x, typ)| {
+      ctx.assign(x.clone(), typ.clone())
+    });
+  let typ = debruijn::Term::from_ast(&ctx, &term)?.type_of(&ctx)?;
+  Ok((term, typ))
+}
+
 pub fn infer(term: &Term) -> Result<Type, TypecheckerError> {
-  type_of(&TypingContext::new(), term)
+  infer_in(&TypingContext::new(), term)
+}
+
+pub fn infer_in(ctx: &TypingContext, term: &Term) -> Result<Type, TypecheckerError> {
+  let (_, typ) = elaborate_in(ctx, term)?;
+  Ok(typ)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn infer(input: &str) -> Result<Type, TypecheckerError> {
+    super::infer(&crate::parse(input).unwrap())
+  }
+
+  fn parse_type(input: &str) -> Type {
+    crate::parse_type(input).unwrap()
+  }
+
+  #[test]
+  fn unannotated_abstractions_are_only_checked() {
+    assert_eq!(
+      infer("λx. x"),
+      Err(TypecheckerError::MissingAnnotation(
+        crate::parse("λx. x").unwrap()
+      ))
+    );
+    assert_eq!(infer("(λx. x : Int -> Int)"), Ok(parse_type("Int -> Int")));
+    assert_eq!(
+      infer("(λf. λx. f x : (Int -> Unit) -> Int -> Unit)"),
+      Ok(parse_type("(Int -> Unit) -> Int -> Unit"))
+    );
+    // The argument of an application is checked against the parameter type.
+    assert_eq!(infer("(λf: Int -> Int. f 1) (λx. x)"), Ok(Type::Int));
+    assert_eq!(
+      infer("(λx. x : Int)"),
+      Err(TypecheckerError::UnexpectedType {
+        expected: String::from("function type"),
+        got: Type::Int,
+      })
+    );
+    assert_eq!(
+      infer("(λx: Unit. x : Int -> Int)"),
+      Err(TypecheckerError::TypeMismatch {
+        term: Term::Var(String::from("x")),
+        expected: Type::Int,
+        got: Type::Unit,
+      })
+    );
+  }
+
+  #[test]
+  fn annotations_must_match() {
+    assert_eq!(infer("(1 : Int)"), Ok(Type::Int));
+    assert_eq!(
+      infer("(1 : Unit)"),
+      Err(TypecheckerError::TypeMismatch {
+        term: Term::Int(1),
+        expected: Type::Unit,
+        got: Type::Int,
+      })
+    );
+    assert_eq!(
+      infer("(λx: Int. x : Int -> Unit)"),
+      Err(TypecheckerError::TypeMismatch {
+        term: Term::Var(String::from("x")),
+        expected: Type::Unit,
+        got: Type::Int,
+      })
+    );
+  }
+
+  #[test]
+  fn pairs_and_projections() {
+    assert_eq!(infer("(1, ())"), Ok(parse_type("Int × Unit")));
+    assert_eq!(infer("(1, ()).1"), Ok(Type::Int));
+    assert_eq!(infer("(1, ()).2"), Ok(Type::Unit));
+    assert_eq!(
+      infer("λp: Int × (Int -> Unit). p.2 p.1"),
+      Ok(parse_type("Int × (Int -> Unit) -> Unit"))
+    );
+    // A pair checked against a product type checks its components.
+    assert_eq!(
+      infer("((λx. x, 1) : (Int -> Int) × Int)"),
+      Ok(parse_type("(Int -> Int) × Int"))
+    );
+    assert_eq!(
+      infer("((1, 2) : Int × Unit)"),
+      Err(TypecheckerError::TypeMismatch {
+        term: Term::Int(2),
+        expected: Type::Unit,
+        got: Type::Int,
+      })
+    );
+  }
+
+  #[test]
+  fn only_pairs_can_be_projected() {
+    assert_eq!(
+      infer("1.1"),
+      Err(TypecheckerError::UnexpectedType {
+        expected: String::from("a pair type with a component 1"),
+        got: Type::Int,
+      })
+    );
+    assert_eq!(
+      infer("(λx: Int. x).2"),
+      Err(TypecheckerError::UnexpectedType {
+        expected: String::from("a pair type with a component 2"),
+        got: parse_type("Int -> Int"),
+      })
+    );
+  }
+
+  #[test]
+  fn injections_are_checked_against_their_sum_type() {
+    assert_eq!(infer("inl 1 as Int + Unit"), Ok(parse_type("Int + Unit")));
+    assert_eq!(infer("inr () as Int + Unit"), Ok(parse_type("Int + Unit")));
+    assert_eq!(
+      infer("inl (λx. x) as (Int -> Int) + Unit"),
+      Ok(parse_type("(Int -> Int) + Unit"))
+    );
+    assert_eq!(
+      infer("inl () as Int + Unit"),
+      Err(TypecheckerError::TypeMismatch {
+        term: Term::Unit,
+        expected: Type::Int,
+        got: Type::Unit,
+      })
+    );
+    assert_eq!(
+      infer("inr 1 as Int"),
+      Err(TypecheckerError::UnexpectedType {
+        expected: String::from("sum type"),
+        got: Type::Int,
+      })
+    );
+  }
+
+  #[test]
+  fn case_branches_have_the_same_type() {
+    assert_eq!(
+      infer("λs: Int + Unit. case s of inl x => x | inr u => 0"),
+      Ok(parse_type("Int + Unit -> Int"))
+    );
+    // Checked against a type, the first branch need not synthesize one.
+    assert_eq!(
+      infer(
+        "(λs: Int + Unit. case s of inl x => λy. x | inr u => λy. y : Int + Unit -> Int -> Int)"
+      ),
+      Ok(parse_type("Int + Unit -> Int -> Int"))
+    );
+    assert_eq!(
+      infer("λs: Int + Unit. case s of inl x => x | inr u => u"),
+      Err(TypecheckerError::TypeMismatch {
+        term: Term::Var(String::from("u")),
+        expected: Type::Int,
+        got: Type::Unit,
+      })
+    );
+    assert_eq!(
+      infer("case 1 of inl x => x | inr y => y"),
+      Err(TypecheckerError::UnexpectedType {
+        expected: String::from("sum type"),
+        got: Type::Int,
+      })
+    );
+  }
 }
@@ -0,0 +1,60 @@
+// Compares the substitution evaluator with the abstract machines on Church
+// numeral arithmetic. Run with `cargo bench -p system_f`.
+
+use std::time::{Duration, Instant};
+use system_f::debruijn::{compile, EvalError, Term, STEP_LIMIT};
+use system_f::machine::Machine;
+use system_f::strategy::Evaluation;
+
+fn numeral(n: usize) -> String {
+  (0..n).fold(String::from("zero"), |m, _| format!("succ ({})", m))
+}
+
+// The best of a few runs, to leave out warming up.
+fn time(run: impl Fn() -> Result<Term, EvalError>) -> (Duration, Term) {
+  let mut best = Duration::MAX;
+  let mut result = None;
+  for _ in 0..3 {
+    let start = Instant::now();
+    let value = run().unwrap();
+    best = best.min(start.elapsed());
+    result = Some(value);
+  }
+  (best, result.unwrap())
+}
+
+fn main() {
+  let programs = [
+    format!("times ({}) ({}) [Int] (λx. x) 0", numeral(4), numeral(5)),
+    format!(
+      "isZero (minus ({}) ({})) [Int] 1 0",
+      numeral(12),
+      numeral(8)
+    ),
+    format!(
+      "equal (times ({}) ({})) (plus ({}) ({})) [Int] 1 0",
+      numeral(3),
+      numeral(4),
+      numeral(6),
+      numeral(6)
+    ),
+  ];
+  for program in &programs {
+    let term = compile(&system_f::parse(program).unwrap()).unwrap();
+    println!("{}", program);
+    for machine in &[Machine::Cek, Machine::Krivine] {
+      let strategy = machine.strategy();
+      let (substitution, expected) = time(|| Evaluation::new(strategy).eval(&term, STEP_LIMIT));
+      let (abstract_machine, value) = time(|| machine.eval(&term, STEP_LIMIT));
+      assert_eq!(value, expected);
+      println!(
+        "  {:<4} substitution {:>10.2?}  {:<7?} {:>10.2?}  {:>6.1}x",
+        strategy.to_string(),
+        substitution,
+        machine,
+        abstract_machine,
+        substitution.as_secs_f64() / abstract_machine.as_secs_f64()
+      );
+    }
+  }
+}
@@ -1,57 +1,123 @@
+use lambda_core::pretty::{fmt_fields, Precedence};
+use std::fmt;
+
 // Kind ::=
 //  | *
+//  | Kind -> Kind   -- kind of a type alias with parameters
 #[derive(Debug, Clone, PartialEq)]
 pub enum Kind {
   // *
   Star,
+  // Kind -> Kind
+  Arrow(Box<Kind>, Box<Kind>),
 }
 
 // Type ::=
 //  | *                   -- base type
+//  | Unit                -- unit type
 //  | Type -> Type        -- function type
+//  | Type × Type         -- product type
+//  | Type + Type         -- sum type
+//  | {Label: Type, ...}  -- record type
+//  | <Label: Type, ...>  -- variant type
 //  | (Type)              -- grouping
 //  | TypeVar
-//  | ∀ TypeVar : Kind . Type -> Type
+//  | Type Type           -- application of a type alias
+//  | ∀ TypeVar : Kind . Type
+//  | ∃ TypeVar : Kind . Type
+//  | μ TypeVar . Type    -- recursive type
 #[derive(Debug, Clone, PartialEq)]
+#[allow(clippy::enum_variant_names)]
 pub enum Type {
   // *
   Int,
   // *
   Bool,
+  // Unit
+  Unit,
   // Type -> Type
   Arrow(Box<Type>, Box<Type>),
+  // Type × Type
+  Product(Box<Type>, Box<Type>),
+  // Type + Type
+  Sum(Box<Type>, Box<Type>),
+  // {Label: Type, ...}
+  Record(Vec<(String, Type)>),
+  // <Label: Type, ...>
+  Variant(Vec<(String, Type)>),
   // TypeVar
   TypeVar(String),
+  // Type Type
+  App(Box<Type>, Box<Type>),
   // ∀ TypeVar : Kind . Type
   Forall {
     type_var: String,
     kind: Kind,
     typ: Box<Type>,
   },
+  // ∃ TypeVar : Kind . Type
+  Exists {
+    type_var: String,
+    kind: Kind,
+    typ: Box<Type>,
+  },
+  // μ TypeVar . Type
+  Mu {
+    type_var: String,
+    typ: Box<Type>,
+  },
 }
 
 // Term ::=
 //  | Int                     -- integer literal
+//  | ()                      -- unit value
 //  | Var                     -- term variable
 //  | Term Term               -- term application
 //  | λ Var : Type . Term     -- term abstraction
+//  | λ Var . Term            -- term abstraction, parameter type given by the context
+//  | (Term : Type)           -- type annotation
 //  | (Term)                  -- grouping
 //  | Λ TypeVar : Kind . Term -- universal abstraction
 //  | Term Type               -- universal application
+//  | pack [Type, Term] as Type                 -- existential introduction
+//  | unpack Term as [TypeVar, Var] in Term     -- existential elimination
+//  | (Term, Term)                              -- pair
+//  | Term.1                                    -- first projection
+//  | Term.2                                    -- second projection
+//  | inl Term as Type                          -- left injection
+//  | inr Term as Type                          -- right injection
+//  | case Term of inl Var => Term | inr Var => Term
+//  | {Label = Term, ...}                       -- record
+//  | Term.Label                                -- field projection
+//  | <Label = Term> as Type                    -- variant introduction
+//  | case Term of { <Label = Var> => Term | ... }
+//  | Term; Term                                -- sequencing
+//  | let Var = Term in Term                    -- let binding
+//  | type TypeVar TypeVar ... = Type; Term     -- type alias
+//  | let TypeVar = Type in Term                -- sugar for type TypeVar = Type; Term
+//  | fix Term                                  -- fixed point
+//  | let rec Var : Type = Term in Term         -- sugar for let Var = fix (λVar: Type. Term) in Term
+//  | fold [Type] Term                          -- recursive type introduction
+//  | unfold [Type] Term                        -- recursive type elimination
 #[derive(Debug, Clone, PartialEq)]
 pub enum Term {
   // Int
   Int(i32),
+  // ()
+  Unit,
   // Var
   Var(String),
   // Term Term
   App(Box<Term>, Box<Term>),
   // λ Var: Type. Term
+  // λ Var. Term
   Abs {
     param_name: String,
-    param_type: Type,
+    param_type: Option<Type>,
     body: Box<Term>,
   },
+  // (Term : Type)
+  Ann(Box<Term>, Type),
   // Λ TypeVar : Kind . Term
   UniversalAbs {
     type_var: String,
@@ -60,4 +126,352 @@ pub enum Term {
   },
   // Term Type
   UniversalApp(Box<Term>, Type),
+  // pack [Type, Term] as Type
+  Pack {
+    witness: Type,
+    term: Box<Term>,
+    typ: Type,
+  },
+  // unpack Term as [TypeVar, Var] in Term
+  Unpack {
+    type_var: String,
+    var: String,
+    package: Box<Term>,
+    body: Box<Term>,
+  },
+  // (Term, Term)
+  Pair(Box<Term>, Box<Term>),
+  // Term.1
+  // Term.2
+  Proj(Box<Term>, usize),
+  // inl Term as Type
+  Inl(Box<Term>, Type),
+  // inr Term as Type
+  Inr(Box<Term>, Type),
+  // case Term of inl Var => Term | inr Var => Term
+  Case {
+    scrutinee: Box<Term>,
+    left_var: String,
+    left: Box<Term>,
+    right_var: String,
+    right: Box<Term>,
+  },
+  // {Label = Term, ...}
+  Record(Vec<(String, Term)>),
+  // Term.Label
+  Field(Box<Term>, String),
+  // <Label = Term> as Type
+  Tag {
+    label: String,
+    term: Box<Term>,
+    typ: Type,
+  },
+  // case Term of { <Label = Var> => Term | ... }
+  VariantCase {
+    scrutinee: Box<Term>,
+    branches: Vec<Branch>,
+  },
+  // Term; Term
+  Seq(Box<Term>, Box<Term>),
+  // let Var = Term in Term
+  Let {
+    name: String,
+    value: Box<Term>,
+    body: Box<Term>,
+  },
+  // type TypeVar TypeVar ... = Type; Term
+  TypeLet {
+    type_var: String,
+    params: Vec<String>,
+    typ: Type,
+    body: Box<Term>,
+  },
+  // fix Term
+  Fix(Box<Term>),
+  // fold [Type] Term
+  Fold(Type, Box<Term>),
+  // unfold [Type] Term
+  Unfold(Type, Box<Term>),
+}
+
+// <Label = Var> => Term
+#[derive(Debug, Clone, PartialEq)]
+pub struct Branch {
+  pub label: String,
+  pub var: String,
+  pub body: Term,
+}
+
+// Pretty printing produces syntax that the grammar parses back into the same tree.
+
+impl fmt::Display for Kind {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Kind::Star => write!(f, "*"),
+      Kind::Arrow(param, result) => {
+        if let Kind::Arrow(..) = **param {
+          write!(f, "({}) -> {}", param, result)
+        } else {
+          write!(f, "{} -> {}", param, result)
+        }
+      }
+    }
+  }
+}
+
+impl Precedence for Type {
+  fn precedence(&self) -> usize {
+    match self {
+      Type::Int
+      | Type::Bool
+      | Type::Unit
+      | Type::TypeVar(_)
+      | Type::Record(_)
+      | Type::Variant(_) => 1,
+      Type::App(..) => 2,
+      Type::Product(..) => 3,
+      Type::Sum(..) => 4,
+      Type::Arrow(..) => 5,
+      Type::Forall { .. } | Type::Exists { .. } | Type::Mu { .. } => 6,
+    }
+  }
+}
+
+impl fmt::Display for Type {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Type::Int => write!(f, "Int"),
+      Type::Bool => write!(f, "Bool"),
+      Type::Unit => write!(f, "Unit"),
+      Type::TypeVar(x) => write!(f, "{}", x),
+      // Application associates to the left.
+      Type::App(function, arg) => {
+        if function.precedence() > self.precedence() {
+          write!(f, "({})", function)?;
+        } else {
+          write!(f, "{}", function)?;
+        }
+        if arg.precedence() >= self.precedence() {
+          write!(f, " ({})", arg)
+        } else {
+          write!(f, " {}", arg)
+        }
+      }
+      Type::Arrow(param_type, return_type) => self.fmt_binary(f, "->", param_type, return_type),
+      Type::Product(left, right) => self.fmt_binary(f, "×", left, right),
+      Type::Sum(left, right) => self.fmt_binary(f, "+", left, right),
+      Type::Record(fields) => {
+        write!(f, "{{")?;
+        fmt_fields(f, fields, ": ")?;
+        write!(f, "}}")
+      }
+      Type::Variant(fields) => {
+        write!(f, "<")?;
+        fmt_fields(f, fields, ": ")?;
+        write!(f, ">")
+      }
+      Type::Forall {
+        type_var,
+        kind,
+        typ,
+      } => write!(f, "∀{}: {}. {}", type_var, kind, typ),
+      Type::Exists {
+        type_var,
+        kind,
+        typ,
+      } => write!(f, "∃{}: {}. {}", type_var, kind, typ),
+      Type::Mu { type_var, typ } => write!(f, "μ{}. {}", type_var, typ),
+    }
+  }
+}
+
+impl Term {
+  fn is_atom(&self) -> bool {
+    matches!(
+      self,
+      Term::Int(_)
+        | Term::Unit
+        | Term::Var(_)
+        | Term::Ann(..)
+        | Term::Pair(..)
+        | Term::Proj(..)
+        | Term::Record(_)
+        | Term::Field(..)
+        | Term::VariantCase { .. }
+    )
+  }
+
+  // Terms that extend as far to the right as possible.
+  fn is_open(&self) -> bool {
+    matches!(
+      self,
+      Term::Abs { .. }
+        | Term::UniversalAbs { .. }
+        | Term::Pack { .. }
+        | Term::Unpack { .. }
+        | Term::Inl(..)
+        | Term::Inr(..)
+        | Term::Case { .. }
+        | Term::Tag { .. }
+        | Term::Seq(..)
+        | Term::Let { .. }
+        | Term::TypeLet { .. }
+    )
+  }
+}
+
+impl fmt::Display for Term {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Term::Int(n) => write!(f, "{}", n),
+      Term::Unit => write!(f, "()"),
+      Term::Var(x) => write!(f, "{}", x),
+      Term::App(function, arg) => {
+        if function.is_open() {
+          write!(f, "({})", function)?;
+        } else {
+          write!(f, "{}", function)?;
+        }
+        if arg.is_atom() {
+          write!(f, " {}", arg)
+        } else {
+          write!(f, " ({})", arg)
+        }
+      }
+      Term::Abs {
+        param_name,
+        param_type: Some(param_type),
+        body,
+      } => write!(f, "λ{}: {}. {}", param_name, param_type, body),
+      Term::Abs {
+        param_name,
+        param_type: None,
+        body,
+      } => write!(f, "λ{}. {}", param_name, body),
+      Term::Ann(term, typ) => write!(f, "({} : {})", term, typ),
+      Term::UniversalAbs {
+        type_var,
+        kind,
+        body,
+      } => write!(f, "Λ{}: {}. {}", type_var, kind, body),
+      Term::UniversalApp(term, typ) => {
+        if term.is_open() {
+          write!(f, "({})", term)?;
+        } else {
+          write!(f, "{}", term)?;
+        }
+        write!(f, " [{}]", typ)
+      }
+      Term::Pack { witness, term, typ } => write!(f, "pack [{}, {}] as {}", witness, term, typ),
+      Term::Unpack {
+        type_var,
+        var,
+        package,
+        body,
+      } => {
+        if package.is_open() {
+          write!(f, "unpack ({})", package)?;
+        } else {
+          write!(f, "unpack {}", package)?;
+        }
+        write!(f, " as [{}, {}] in {}", type_var, var, body)
+      }
+      Term::Pair(first, second) => write!(f, "({}, {})", first, second),
+      Term::Proj(term, i) => {
+        if term.is_atom() {
+          write!(f, "{}.{}", term, i)
+        } else {
+          write!(f, "({}).{}", term, i)
+        }
+      }
+      Term::Inl(term, typ) => write!(f, "inl {} as {}", term, typ),
+      Term::Inr(term, typ) => write!(f, "inr {} as {}", term, typ),
+      Term::Case {
+        scrutinee,
+        left_var,
+        left,
+        right_var,
+        right,
+      } => write!(
+        f,
+        "case {} of inl {} => {} | inr {} => {}",
+        scrutinee, left_var, left, right_var, right
+      ),
+      Term::Record(fields) => {
+        write!(f, "{{")?;
+        fmt_fields(f, fields, " = ")?;
+        write!(f, "}}")
+      }
+      Term::Field(term, label) => {
+        if term.is_atom() {
+          write!(f, "{}.{}", term, label)
+        } else {
+          write!(f, "({}).{}", term, label)
+        }
+      }
+      Term::Tag { label, term, typ } => write!(f, "<{} = {}> as {}", label, term, typ),
+      Term::VariantCase {
+        scrutinee,
+        branches,
+      } => {
+        write!(f, "case {} of {{ ", scrutinee)?;
+        for (i, branch) in branches.iter().enumerate() {
+          if i > 0 {
+            write!(f, " | ")?;
+          }
+          write!(f, "<{} = {}> => {}", branch.label, branch.var, branch.body)?;
+        }
+        write!(f, " }}")
+      }
+      Term::Seq(first, second) => {
+        if first.is_open() {
+          write!(f, "({}); {}", first, second)
+        } else {
+          write!(f, "{}; {}", first, second)
+        }
+      }
+      Term::Let { name, value, body } => write!(f, "let {} = {} in {}", name, value, body),
+      Term::TypeLet {
+        type_var,
+        params,
+        typ,
+        body,
+      } => {
+        write!(f, "type {}", type_var)?;
+        for param in params {
+          write!(f, " {}", param)?;
+        }
+        write!(f, " = {}; {}", typ, body)
+      }
+      Term::Fix(term) => {
+        if term.is_atom() {
+          write!(f, "fix {}", term)
+        } else {
+          write!(f, "fix ({})", term)
+        }
+      }
+      Term::Fold(typ, term) | Term::Unfold(typ, term) => {
+        let keyword = if let Term::Fold(..) = self {
+          "fold"
+        } else {
+          "unfold"
+        };
+        if term.is_atom() {
+          write!(f, "{} [{}] {}", keyword, typ, term)
+        } else {
+          write!(f, "{} [{}] ({})", keyword, typ, term)
+        }
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  #[test]
+  fn type_declarations_print_as_they_parse() {
+    let term =
+      crate::parse("type Pair A B = {fst: A, snd: B}; λp: Pair (Unit -> Unit) Int. p").unwrap();
+    assert_eq!(crate::parse(&term.to_string()), Ok(term));
+  }
 }
@@ -0,0 +1,363 @@
+use crate::debruijn::Hint;
+use crate::untyped::Term;
+use std::cell::RefCell;
+use std::fmt;
+
+// Bytecode for erased terms, run by the virtual machine in `vm.rs`.
+//
+// Every λ is compiled to a function of one parameter. A function only sees
+// its own locals, the parameter first and then the variables bound by the
+// cases inside it, and the variables it captured: a closure copies the
+// values of the free variables of its λ into slots of its own when it is
+// created. Instructions take their operands from a stack and push their
+// result on it:
+//
+//   λx. (λy. x) x   ~>   main:              closure 1
+//                                           return
+//                        fn 0 y [local 0]:  captured 0
+//                                           return
+//                        fn 1 x:            closure 0
+//                                           local 0
+//                                           apply
+//                                           return
+//
+// Integers and () are stored unboxed on the stack, everything else is
+// allocated on the heap of the machine.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+  Int(i32),
+  Unit,
+  // Pushes a local of the current function.
+  Local(u16),
+  // Pushes a variable captured by the current closure.
+  Captured(u16),
+  // Allocates a closure of the function, capturing the slots it lists.
+  Closure(u32),
+  // Pops an argument and a closure and calls the closure.
+  Apply,
+  // Returns the value on top of the stack to the caller.
+  Return,
+  // Pops a closure and calls it with a recursive reference to itself, which
+  // calls it again whenever it is loaded from a slot.
+  Fix,
+  Pair,
+  Proj(u8),
+  Inl,
+  Inr,
+  // Pops a sum and pushes its payload, jumping to the address when it is a
+  // right injection.
+  Case(u32),
+  // Pops the fields of a record of the shape, the last field on top.
+  Record(u32),
+  Field(u32),
+  Tag(u32),
+  // Pops a variant, pushes its payload and jumps to the address its label
+  // has in the jump table.
+  Switch(u32),
+  // Moves the top of the stack into a new local.
+  Bind,
+  // Drops the last locals.
+  Unbind(u16),
+  Pop,
+  Jump(u32),
+}
+
+// Where a variable lives in the current function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Slot {
+  Local(u16),
+  Captured(u16),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Function {
+  pub hint: Hint,
+  pub code: Vec<Instruction>,
+  // The slots of the creating function that a closure captures, and the de
+  // Bruijn index each one has outside of the λ.
+  pub captures: Vec<Slot>,
+  pub free: Vec<usize>,
+  // The erased body of the λ, to read closures back as terms.
+  pub body: Term,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Program {
+  pub main: Vec<Instruction>,
+  pub functions: Vec<Function>,
+  pub labels: Vec<String>,
+  // The labels of each shape of record, in the order the fields are pushed.
+  pub shapes: Vec<Vec<u32>>,
+  // The label and address of each branch of each jump table.
+  pub tables: Vec<Vec<(u32, u32)>>,
+}
+
+// Compiles a closed erased term.
+pub fn compile(term: &Term) -> Program {
+  let mut program = Program::default();
+  let mut main = Vec::new();
+  program.compile(term, &Scope::default(), &mut main);
+  main.push(Instruction::Return);
+  program.main = main;
+  program
+}
+
+// The variables in scope in the function being compiled: `depth` locals,
+// the innermost one bound last, and then the captured ones.
+#[derive(Debug, Default)]
+pub(crate) struct Scope {
+  depth: usize,
+  free: Vec<usize>,
+}
+
+impl Scope {
+  // The scope of the body of a λ with the free variables `free`.
+  pub(crate) fn function(free: Vec<usize>) -> Scope {
+    Scope { depth: 1, free }
+  }
+
+  pub(crate) fn slot(&self, i: usize) -> Slot {
+    if i < self.depth {
+      Slot::Local((self.depth - 1 - i) as u16)
+    } else {
+      let captured = self
+        .free
+        .iter()
+        .position(|j| *j == i - self.depth)
+        .expect("the term is closed");
+      Slot::Captured(captured as u16)
+    }
+  }
+
+  pub(crate) fn bind(&self) -> Scope {
+    Scope {
+      depth: self.depth + 1,
+      free: self.free.clone(),
+    }
+  }
+}
+
+// The de Bruijn indices, outside of the λ with body `body`, of the variables
+// free in it.
+pub(crate) fn free_vars(body: &Term) -> Vec<usize> {
+  let free = RefCell::new(Vec::new());
+  body.map_vars(1, &|c, i| {
+    if i >= c {
+      free.borrow_mut().push(i - c);
+    }
+    Term::Var(i)
+  });
+  let mut free = free.into_inner();
+  free.sort_unstable();
+  free.dedup();
+  free
+}
+
+fn intern(labels: &mut Vec<String>, label: &str) -> u32 {
+  match labels.iter().position(|other| other == label) {
+    Some(i) => i as u32,
+    None => {
+      labels.push(String::from(label));
+      (labels.len() - 1) as u32
+    }
+  }
+}
+
+impl Program {
+  fn compile(&mut self, term: &Term, scope: &Scope, code: &mut Vec<Instruction>) {
+    match term {
+      Term::Int(n) => code.push(Instruction::Int(*n)),
+      Term::Unit => code.push(Instruction::Unit),
+      Term::Var(i) => code.push(match scope.slot(*i) {
+        Slot::Local(slot) => Instruction::Local(slot),
+        Slot::Captured(slot) => Instruction::Captured(slot),
+      }),
+      Term::App(f, arg) => {
+        self.compile(f, scope, code);
+        self.compile(arg, scope, code);
+        code.push(Instruction::Apply);
+      }
+      Term::Abs { hint, body } => {
+        let free = free_vars(body);
+        let captures = free.iter().map(|i| scope.slot(*i)).collect();
+        let inner = Scope::function(free.clone());
+        let mut function_code = Vec::new();
+        self.compile(body, &inner, &mut function_code);
+        function_code.push(Instruction::Return);
+        self.functions.push(Function {
+          hint: hint.clone(),
+          code: function_code,
+          captures,
+          free,
+          body: (**body).clone(),
+        });
+        code.push(Instruction::Closure((self.functions.len() - 1) as u32));
+      }
+      Term::Pair(first, second) => {
+        self.compile(first, scope, code);
+        self.compile(second, scope, code);
+        code.push(Instruction::Pair);
+      }
+      Term::Proj(term, i) => {
+        self.compile(term, scope, code);
+        code.push(Instruction::Proj(*i as u8));
+      }
+      Term::Inl(term) => {
+        self.compile(term, scope, code);
+        code.push(Instruction::Inl);
+      }
+      Term::Inr(term) => {
+        self.compile(term, scope, code);
+        code.push(Instruction::Inr);
+      }
+      Term::Case {
+        scrutinee,
+        left,
+        right,
+        ..
+      } => {
+        self.compile(scrutinee, scope, code);
+        let case = code.len();
+        code.push(Instruction::Case(0));
+        self.compile_branch(left, scope, code);
+        let jump = code.len();
+        code.push(Instruction::Jump(0));
+        code[case] = Instruction::Case(code.len() as u32);
+        self.compile_branch(right, scope, code);
+        code[jump] = Instruction::Jump(code.len() as u32);
+      }
+      Term::Record(fields) => {
+        let mut shape = Vec::new();
+        for (label, term) in fields {
+          self.compile(term, scope, code);
+          shape.push(intern(&mut self.labels, label));
+        }
+        let index = match self.shapes.iter().position(|other| *other == shape) {
+          Some(index) => index,
+          None => {
+            self.shapes.push(shape);
+            self.shapes.len() - 1
+          }
+        };
+        code.push(Instruction::Record(index as u32));
+      }
+      Term::Field(term, label) => {
+        self.compile(term, scope, code);
+        code.push(Instruction::Field(intern(&mut self.labels, label)));
+      }
+      Term::Tag(label, term) => {
+        self.compile(term, scope, code);
+        code.push(Instruction::Tag(intern(&mut self.labels, label)));
+      }
+      Term::VariantCase {
+        scrutinee,
+        branches,
+      } => {
+        self.compile(scrutinee, scope, code);
+        // The branches may have tables of their own.
+        let index = self.tables.len();
+        self.tables.push(Vec::new());
+        code.push(Instruction::Switch(index as u32));
+        let mut table = Vec::new();
+        let mut jumps = Vec::new();
+        for branch in branches {
+          table.push((intern(&mut self.labels, &branch.label), code.len() as u32));
+          self.compile_branch(&branch.body, scope, code);
+          jumps.push(code.len());
+          code.push(Instruction::Jump(0));
+        }
+        for jump in jumps {
+          code[jump] = Instruction::Jump(code.len() as u32);
+        }
+        self.tables[index] = table;
+      }
+      Term::Seq(first, second) => {
+        self.compile(first, scope, code);
+        code.push(Instruction::Pop);
+        self.compile(second, scope, code);
+      }
+      Term::Fix(term) => {
+        self.compile(term, scope, code);
+        code.push(Instruction::Fix);
+      }
+    }
+  }
+
+  // A branch of a case, which binds the payload on top of the stack.
+  fn compile_branch(&mut self, body: &Term, scope: &Scope, code: &mut Vec<Instruction>) {
+    code.push(Instruction::Bind);
+    self.compile(body, &scope.bind(), code);
+    code.push(Instruction::Unbind(1));
+  }
+
+  fn fmt_code(&self, code: &[Instruction], f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    for (address, instruction) in code.iter().enumerate() {
+      write!(f, "  {:>4}  ", address)?;
+      let label = |i: &u32| &self.labels[*i as usize];
+      match instruction {
+        Instruction::Int(n) => write!(f, "int {}", n)?,
+        Instruction::Unit => write!(f, "unit")?,
+        Instruction::Local(i) => write!(f, "local {}", i)?,
+        Instruction::Captured(i) => write!(f, "captured {}", i)?,
+        Instruction::Closure(i) => write!(f, "closure {}", i)?,
+        Instruction::Apply => write!(f, "apply")?,
+        Instruction::Return => write!(f, "return")?,
+        Instruction::Fix => write!(f, "fix")?,
+        Instruction::Pair => write!(f, "pair")?,
+        Instruction::Proj(i) => write!(f, "proj {}", i)?,
+        Instruction::Inl => write!(f, "inl")?,
+        Instruction::Inr => write!(f, "inr")?,
+        Instruction::Case(address) => write!(f, "case {}", address)?,
+        Instruction::Record(shape) => {
+          let labels: Vec<_> = self.shapes[*shape as usize]
+            .iter()
+            .map(|i| label(i).as_str())
+            .collect();
+          write!(f, "record {{{}}}", labels.join(", "))?
+        }
+        Instruction::Field(i) => write!(f, "field {}", label(i))?,
+        Instruction::Tag(i) => write!(f, "tag {}", label(i))?,
+        Instruction::Switch(table) => {
+          let branches: Vec<_> = self.tables[*table as usize]
+            .iter()
+            .map(|(i, address)| format!("{} => {}", label(i), address))
+            .collect();
+          write!(f, "switch {{{}}}", branches.join(", "))?
+        }
+        Instruction::Bind => write!(f, "bind")?,
+        Instruction::Unbind(n) => write!(f, "unbind {}", n)?,
+        Instruction::Pop => write!(f, "pop")?,
+        Instruction::Jump(address) => write!(f, "jump {}", address)?,
+      }
+      writeln!(f)?;
+    }
+    Ok(())
+  }
+}
+
+impl fmt::Display for Slot {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Slot::Local(i) => write!(f, "local {}", i),
+      Slot::Captured(i) => write!(f, "captured {}", i),
+    }
+  }
+}
+
+// Disassembles the program.
+impl fmt::Display for Program {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    writeln!(f, "main:")?;
+    self.fmt_code(&self.main, f)?;
+    for (i, function) in self.functions.iter().enumerate() {
+      write!(f, "fn {} {}", i, function.hint.0)?;
+      if !function.captures.is_empty() {
+        let captures: Vec<_> = function.captures.iter().map(Slot::to_string).collect();
+        write!(f, " [{}]", captures.join(", "))?;
+      }
+      writeln!(f, ":")?;
+      self.fmt_code(&function.code, f)?;
+    }
+    Ok(())
+  }
+}
@@ -0,0 +1,629 @@
+use crate::ast;
+use crate::bytecode::{free_vars, Scope, Slot};
+use crate::debruijn::Hint;
+use crate::typechecker::TypecheckerError;
+use crate::untyped::{self, Term};
+use std::fmt::Write;
+
+// A backend that compiles erased terms to standalone C.
+//
+// Closure conversion turns every λ into a function of its closure and its
+// argument, which reads its free variables from the closure, and lambda
+// lifting moves all those functions to the top level. The C program then
+// evaluates the lifted term with call-by-value and prints its value in the
+// syntax of erased terms, or `<function>` for a closure, since the bodies of
+// functions are gone by then.
+//
+// The runtime boxes every value, integers and closures alike, and allocates
+// from an arena that is never freed, which is enough for programs that run
+// to completion.
+
+// A lifted term. Variables are slots of the enclosing function, as in the
+// bytecode, and a λ is replaced by the allocation of a closure of a top-level
+// function that captures the listed slots.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Code {
+  Int(i32),
+  Unit,
+  Var(Slot),
+  Closure(usize, Vec<Slot>),
+  App(Box<Code>, Box<Code>),
+  Pair(Box<Code>, Box<Code>),
+  Proj(Box<Code>, usize),
+  Inl(Box<Code>),
+  Inr(Box<Code>),
+  Case(Box<Code>, Box<Code>, Box<Code>),
+  Record(Vec<(String, Code)>),
+  Field(Box<Code>, String),
+  Tag(String, Box<Code>),
+  Switch(Box<Code>, Vec<(String, Code)>),
+  Seq(Box<Code>, Box<Code>),
+  Fix(Box<Code>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Function {
+  pub hint: Hint,
+  // The number of variables its closures capture.
+  pub captured: usize,
+  pub body: Code,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Lifted {
+  pub functions: Vec<Function>,
+  pub main: Code,
+}
+
+// Closure converts and lambda lifts a closed erased term.
+pub fn lift(term: &Term) -> Lifted {
+  let mut lifted = Lifted {
+    functions: Vec::new(),
+    main: Code::Unit,
+  };
+  lifted.main = lifted.lift(term, &Scope::default());
+  lifted
+}
+
+impl Lifted {
+  fn lift(&mut self, term: &Term, scope: &Scope) -> Code {
+    let mut lift = |term: &Term| Box::new(self.lift(term, scope));
+    match term {
+      Term::Int(n) => Code::Int(*n),
+      Term::Unit => Code::Unit,
+      Term::Var(i) => Code::Var(scope.slot(*i)),
+      Term::App(function, arg) => Code::App(lift(function), lift(arg)),
+      Term::Abs { hint, body } => {
+        let free = free_vars(body);
+        let captures = free.iter().map(|i| scope.slot(*i)).collect();
+        let function = Function {
+          hint: hint.clone(),
+          captured: free.len(),
+          body: self.lift(body, &Scope::function(free)),
+        };
+        self.functions.push(function);
+        Code::Closure(self.functions.len() - 1, captures)
+      }
+      Term::Pair(first, second) => Code::Pair(lift(first), lift(second)),
+      Term::Proj(term, i) => Code::Proj(lift(term), *i),
+      Term::Inl(term) => Code::Inl(lift(term)),
+      Term::Inr(term) => Code::Inr(lift(term)),
+      Term::Case {
+        scrutinee,
+        left,
+        right,
+        ..
+      } => {
+        let scrutinee = lift(scrutinee);
+        let left = self.lift(left, &scope.bind());
+        let right = self.lift(right, &scope.bind());
+        Code::Case(scrutinee, Box::new(left), Box::new(right))
+      }
+      Term::Record(fields) => Code::Record(
+        fields
+          .iter()
+          .map(|(label, term)| (label.clone(), self.lift(term, scope)))
+          .collect(),
+      ),
+      Term::Field(term, label) => Code::Field(lift(term), label.clone()),
+      Term::Tag(label, term) => Code::Tag(label.clone(), lift(term)),
+      Term::VariantCase {
+        scrutinee,
+        branches,
+      } => {
+        let scrutinee = lift(scrutinee);
+        let branches = branches
+          .iter()
+          .map(|branch| {
+            let body = self.lift(&branch.body, &scope.bind());
+            (branch.label.clone(), body)
+          })
+          .collect();
+        Code::Switch(scrutinee, branches)
+      }
+      Term::Seq(first, second) => Code::Seq(lift(first), lift(second)),
+      Term::Fix(term) => Code::Fix(lift(term)),
+    }
+  }
+}
+
+const RUNTIME: &str = r#"#include <stdint.h>
+#include <stdio.h>
+#include <stdlib.h>
+
+enum kind { INT, UNIT, CLOSURE, REC, PAIR, INL, INR, RECORD, TAG };
+
+typedef struct object *value;
+
+/* `n` is the integer, the label of a tag or the number of fields of a
+   record, whose labels are `shape`. A REC is the closure that `fix` was
+   applied to, which is applied again whenever the variable bound to it is
+   loaded. */
+struct object {
+  enum kind kind;
+  int32_t n;
+  value (*code)(value self, value arg);
+  const int32_t *shape;
+  value fields[];
+};
+
+static char *arena;
+static size_t arena_left;
+
+static inline value alloc(enum kind kind, size_t fields) {
+  size_t size = (sizeof(struct object) + fields * sizeof(value) + 15) & ~(size_t)15;
+  if (size > arena_left) {
+    arena_left = size > (1 << 24) ? size : (1 << 24);
+    arena = malloc(arena_left);
+    if (!arena) {
+      fputs("out of memory\n", stderr);
+      exit(1);
+    }
+  }
+  value v = (value)arena;
+  arena += size;
+  arena_left -= size;
+  v->kind = kind;
+  return v;
+}
+
+static struct object unit = { UNIT, 0, 0, 0 };
+
+static inline value make_int(int32_t n) {
+  value v = alloc(INT, 0);
+  v->n = n;
+  return v;
+}
+
+static inline value make1(enum kind kind, value a) {
+  value v = alloc(kind, 1);
+  v->fields[0] = a;
+  return v;
+}
+
+static inline value make2(enum kind kind, value a, value b) {
+  value v = alloc(kind, 2);
+  v->fields[0] = a;
+  v->fields[1] = b;
+  return v;
+}
+
+static inline value make_closure(value (*code)(value, value), size_t captured) {
+  value v = alloc(CLOSURE, captured);
+  v->code = code;
+  return v;
+}
+
+static inline value make_record(const int32_t *shape, int32_t n) {
+  value v = alloc(RECORD, n);
+  v->n = n;
+  v->shape = shape;
+  return v;
+}
+
+static inline value make_tag(int32_t label, value a) {
+  value v = make1(TAG, a);
+  v->n = label;
+  return v;
+}
+
+static inline value apply(value f, value arg) {
+  return f->code(f, arg);
+}
+
+static inline value load(value v) {
+  return v->kind == REC ? apply(v->fields[0], v) : v;
+}
+
+static inline value fix(value f) {
+  return apply(f, make1(REC, f));
+}
+
+static inline value field(value record, int32_t label) {
+  for (int32_t i = 0; i < record->n; i++) {
+    if (record->shape[i] == label) {
+      return record->fields[i];
+    }
+  }
+  abort();
+}
+"#;
+
+const PRINTER: &str = r#"
+static void print(value v);
+
+static void print_atom(value v) {
+  if (v->kind == INL || v->kind == INR) {
+    putchar('(');
+    print(v);
+    putchar(')');
+  } else {
+    print(v);
+  }
+}
+
+static void print(value v) {
+  switch (v->kind) {
+  case INT:
+    printf("%d", v->n);
+    break;
+  case UNIT:
+    printf("()");
+    break;
+  case CLOSURE:
+  case REC:
+    printf("<function>");
+    break;
+  case PAIR:
+    putchar('(');
+    print(v->fields[0]);
+    printf(", ");
+    print(v->fields[1]);
+    putchar(')');
+    break;
+  case INL:
+  case INR:
+    printf(v->kind == INL ? "inl " : "inr ");
+    print_atom(v->fields[0]);
+    break;
+  case RECORD:
+    putchar('{');
+    for (int32_t i = 0; i < v->n; i++) {
+      printf(i > 0 ? ", %s = " : "%s = ", labels[v->shape[i]]);
+      print(v->fields[i]);
+    }
+    putchar('}');
+    break;
+  case TAG:
+    printf("<%s = ", labels[v->n]);
+    print(v->fields[0]);
+    putchar('>');
+    break;
+  }
+}
+"#;
+
+// Emits the C program for a lifted term.
+pub fn emit(lifted: &Lifted) -> String {
+  let mut emitter = Emitter::default();
+  let mut functions = String::new();
+  for (i, function) in lifted.functions.iter().enumerate() {
+    emitter.temps = 0;
+    let mut body = String::new();
+    let result = emitter.emit(&function.body, 1, 1, &mut body);
+    writeln!(
+      functions,
+      "\n/* λ{} */\nstatic value fn{}(value self, value l0) {{\n{}  return {};\n}}",
+      function.hint.0, i, body, result
+    )
+    .unwrap();
+  }
+  emitter.temps = 0;
+  let mut main = String::new();
+  let result = emitter.emit(&lifted.main, 0, 1, &mut main);
+
+  let labels: Vec<_> = emitter
+    .labels
+    .iter()
+    .map(|label| format!("\"{}\", ", label))
+    .collect();
+  let mut program = String::from(RUNTIME);
+  write!(
+    program,
+    "\nstatic const char *const labels[] = {{{}\"\"}};\n",
+    labels.concat()
+  )
+  .unwrap();
+  for (i, shape) in emitter.shapes.iter().enumerate() {
+    let labels: Vec<_> = shape.iter().map(i32::to_string).collect();
+    writeln!(
+      program,
+      "\nstatic const int32_t shape{}[] = {{{}}};",
+      i,
+      labels.join(", ")
+    )
+    .unwrap();
+  }
+  program.push_str(PRINTER);
+  writeln!(program).unwrap();
+  for i in 0..lifted.functions.len() {
+    writeln!(program, "static value fn{}(value self, value l0);", i).unwrap();
+  }
+  program.push_str(&functions);
+  write!(
+    program,
+    "\nint main(void) {{\n{}  print({});\n  putchar('\\n');\n  return 0;\n}}\n",
+    main, result
+  )
+  .unwrap();
+  program
+}
+
+#[derive(Debug, Default)]
+struct Emitter {
+  labels: Vec<String>,
+  shapes: Vec<Vec<i32>>,
+  temps: usize,
+}
+
+impl Emitter {
+  fn label(&mut self, label: &str) -> i32 {
+    match self.labels.iter().position(|other| other == label) {
+      Some(i) => i as i32,
+      None => {
+        self.labels.push(String::from(label));
+        (self.labels.len() - 1) as i32
+      }
+    }
+  }
+
+  fn temp(&mut self) -> String {
+    self.temps += 1;
+    format!("t{}", self.temps - 1)
+  }
+
+  // Writes the statements that compute `code` with `depth` locals in scope,
+  // and returns the variable that holds its value.
+  fn emit(&mut self, code: &Code, depth: usize, indent: usize, out: &mut String) -> String {
+    let pad = "  ".repeat(indent);
+    let slot = |slot: &Slot| match slot {
+      Slot::Local(i) => format!("l{}", i),
+      Slot::Captured(i) => format!("self->fields[{}]", i),
+    };
+    let value = match code {
+      Code::Int(n) => format!("make_int({})", n),
+      Code::Unit => String::from("&unit"),
+      Code::Var(var) => format!("load({})", slot(var)),
+      Code::Closure(function, captures) => {
+        let closure = self.temp();
+        writeln!(
+          out,
+          "{}value {} = make_closure(fn{}, {});",
+          pad,
+          closure,
+          function,
+          captures.len()
+        )
+        .unwrap();
+        for (i, captured) in captures.iter().enumerate() {
+          writeln!(
+            out,
+            "{}{}->fields[{}] = {};",
+            pad,
+            closure,
+            i,
+            slot(captured)
+          )
+          .unwrap();
+        }
+        return closure;
+      }
+      Code::App(function, arg) => {
+        let function = self.emit(function, depth, indent, out);
+        let arg = self.emit(arg, depth, indent, out);
+        format!("apply({}, {})", function, arg)
+      }
+      Code::Pair(first, second) => {
+        let first = self.emit(first, depth, indent, out);
+        let second = self.emit(second, depth, indent, out);
+        format!("make2(PAIR, {}, {})", first, second)
+      }
+      Code::Proj(pair, i) => {
+        let pair = self.emit(pair, depth, indent, out);
+        format!("{}->fields[{}]", pair, i - 1)
+      }
+      Code::Inl(term) => format!("make1(INL, {})", self.emit(term, depth, indent, out)),
+      Code::Inr(term) => format!("make1(INR, {})", self.emit(term, depth, indent, out)),
+      Code::Case(scrutinee, left, right) => {
+        let scrutinee = self.emit(scrutinee, depth, indent, out);
+        let result = self.temp();
+        writeln!(out, "{}value {};", pad, result).unwrap();
+        writeln!(out, "{}if ({}->kind == INL) {{", pad, scrutinee).unwrap();
+        self.emit_branch(left, &scrutinee, &result, depth, indent + 1, out);
+        writeln!(out, "{}}} else {{", pad).unwrap();
+        self.emit_branch(right, &scrutinee, &result, depth, indent + 1, out);
+        writeln!(out, "{}}}", pad).unwrap();
+        return result;
+      }
+      Code::Record(fields) => {
+        let values: Vec<_> = fields
+          .iter()
+          .map(|(_, code)| self.emit(code, depth, indent, out))
+          .collect();
+        let shape: Vec<_> = fields.iter().map(|(label, _)| self.label(label)).collect();
+        let index = match self.shapes.iter().position(|other| *other == shape) {
+          Some(index) => index,
+          None => {
+            self.shapes.push(shape);
+            self.shapes.len() - 1
+          }
+        };
+        let record = self.temp();
+        writeln!(
+          out,
+          "{}value {} = make_record(shape{}, {});",
+          pad,
+          record,
+          index,
+          fields.len()
+        )
+        .unwrap();
+        for (i, value) in values.iter().enumerate() {
+          writeln!(out, "{}{}->fields[{}] = {};", pad, record, i, value).unwrap();
+        }
+        return record;
+      }
+      Code::Field(record, label) => {
+        let record = self.emit(record, depth, indent, out);
+        format!("field({}, {})", record, self.label(label))
+      }
+      Code::Tag(label, term) => {
+        let term = self.emit(term, depth, indent, out);
+        format!("make_tag({}, {})", self.label(label), term)
+      }
+      Code::Switch(scrutinee, branches) => {
+        let scrutinee = self.emit(scrutinee, depth, indent, out);
+        let result = self.temp();
+        writeln!(out, "{}value {} = 0;", pad, result).unwrap();
+        writeln!(out, "{}switch ({}->n) {{", pad, scrutinee).unwrap();
+        for (label, body) in branches {
+          writeln!(out, "{}case {}: {{", pad, self.label(label)).unwrap();
+          self.emit_branch(body, &scrutinee, &result, depth, indent + 1, out);
+          writeln!(out, "{}  break;\n{}}}", pad, pad).unwrap();
+        }
+        writeln!(out, "{}}}", pad).unwrap();
+        return result;
+      }
+      Code::Seq(first, second) => {
+        self.emit(first, depth, indent, out);
+        return self.emit(second, depth, indent, out);
+      }
+      Code::Fix(term) => format!("fix({})", self.emit(term, depth, indent, out)),
+    };
+    let result = self.temp();
+    writeln!(out, "{}value {} = {};", pad, result, value).unwrap();
+    result
+  }
+
+  // A branch of a case, which binds the payload of the scrutinee and stores
+  // its value in `result`.
+  fn emit_branch(
+    &mut self,
+    body: &Code,
+    scrutinee: &str,
+    result: &str,
+    depth: usize,
+    indent: usize,
+    out: &mut String,
+  ) {
+    let pad = "  ".repeat(indent);
+    writeln!(out, "{}value l{} = {}->fields[0];", pad, depth, scrutinee).unwrap();
+    let value = self.emit(body, depth + 1, indent, out);
+    writeln!(out, "{}{} = {};", pad, result, value).unwrap();
+  }
+}
+
+// Typechecks the term, erases its types and compiles it to C.
+pub fn compile(term: &ast::Term) -> Result<String, TypecheckerError> {
+  Ok(emit(&lift(&untyped::erase_ast(term)?)))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{parse, prelude};
+  use std::process::Command;
+  use std::sync::atomic::{AtomicUsize, Ordering};
+
+  static PROGRAMS: AtomicUsize = AtomicUsize::new(0);
+
+  // Compiles the program with the local C compiler and returns what it
+  // prints.
+  fn run(input: &str) -> String {
+    let source = compile(&parse(input).unwrap()).unwrap();
+    let dir = std::env::temp_dir().join(format!(
+      "system_f_c_{}_{}",
+      std::process::id(),
+      PROGRAMS.fetch_add(1, Ordering::Relaxed)
+    ));
+    std::fs::create_dir_all(&dir).unwrap();
+    let (file, binary) = (dir.join("program.c"), dir.join("program"));
+    std::fs::write(&file, &source).unwrap();
+    let status = Command::new("cc")
+      .arg("-O2")
+      .arg("-o")
+      .arg(&binary)
+      .arg(&file)
+      .status()
+      .expect("a C compiler");
+    assert!(status.success(), "cc failed on\n{}", source);
+    let output = Command::new(&binary).output().unwrap();
+    std::fs::remove_dir_all(&dir).unwrap();
+    assert!(output.status.success());
+    String::from_utf8(output.stdout).unwrap()
+  }
+
+  fn assert_agrees(input: &str) {
+    let value = untyped::erase_ast(&parse(input).unwrap())
+      .unwrap()
+      .eval()
+      .unwrap();
+    assert_eq!(run(input), format!("{}\n", value), "{}", input);
+  }
+
+  #[test]
+  fn lifts_functions() {
+    let lifted = lift(&untyped::erase_ast(&parse("λx: Int. λy: Int. (x, y)").unwrap()).unwrap());
+    assert_eq!(
+      lifted.functions,
+      vec![
+        Function {
+          hint: Hint(String::from("y")),
+          captured: 1,
+          body: Code::Pair(
+            Box::new(Code::Var(Slot::Captured(0))),
+            Box::new(Code::Var(Slot::Local(0)))
+          ),
+        },
+        Function {
+          hint: Hint(String::from("x")),
+          captured: 0,
+          body: Code::Closure(0, vec![Slot::Local(0)]),
+        },
+      ]
+    );
+    assert_eq!(lifted.main, Code::Closure(1, Vec::new()));
+  }
+
+  #[test]
+  fn agrees_with_the_interpreter() {
+    assert_agrees("(λx: Int. λy: Int. x) 1 2");
+    assert_agrees("let id = ΛX: *. λx: X. x in (id [Int] 1, id [Unit] ())");
+    assert_agrees("let f = λx: Int. (x, x) in let x = 2 in f x");
+    assert_agrees(
+      "case inr (inl 3 as Int + Unit) as Int + (Int + Unit) of inl x => inr () as (Int + Unit) + Unit | inr y => inl y as (Int + Unit) + Unit",
+    );
+    assert_agrees("let r = {a = 1, b = (2, 3)} in {b = r.b.2, a = r.a}");
+    assert_agrees(
+      "case <some = 1> as <some: Int, none: Unit> of { <some = n> => <ok = n> as <ok: Int> | <none = u> => <ok = 0> as <ok: Int> }",
+    );
+    assert_agrees("((); 1)");
+    // Functions are compiled to code, so only closures are printed.
+    assert_eq!(run("(λx: Int. λy: Int. x, 1)"), "(<function>, 1)\n");
+    // The body of a type abstraction only runs once it is applied to a type.
+    assert_agrees("(ΛX: *. (λx: Int. x) 1) [Unit]");
+    assert_eq!(run("(ΛX: *. fix (λx: Int. x), 1)"), "(<function>, 1)\n");
+  }
+
+  #[test]
+  fn runs_encodings_and_recursion() {
+    let nat = format!("({})", prelude::NAT);
+    let double = format!(
+      "let rec double: {nat} -> {nat} = λn. isZero n [Unit -> {nat}] (λu. zero) (λu. succ (succ (double (pred n)))) () in double",
+      nat = nat
+    );
+    let three = "succ (succ (succ zero))";
+    assert_agrees(&format!(
+      "equal (({}) ({})) (plus ({}) ({})) [Int] 1 0",
+      double, three, three, three
+    ));
+    let list = "μL. <nil: Unit, cons: Int × L>";
+    let unfolded = format!("<nil: Unit, cons: Int × ({})>", list);
+    let nil = format!("fold [{}] (<nil = ()> as {})", list, unfolded);
+    let cons = |head: &str, tail: &str| {
+      format!(
+        "fold [{}] (<cons = ({}, {})> as {})",
+        list, head, tail, unfolded
+      )
+    };
+    let last = format!(
+      "let rec last: ({l}) -> Int = λxs. case unfold [{l}] xs of {{ <nil = u> => 0 | <cons = c> => case unfold [{l}] c.2 of {{ <nil = u> => c.1 | <cons = d> => last c.2 }} }} in last",
+      l = list
+    );
+    assert_agrees(&format!(
+      "({}) ({})",
+      last,
+      cons("1", &cons("2", &cons("3", &nil)))
+    ));
+  }
+}
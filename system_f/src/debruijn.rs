@@ -0,0 +1,2600 @@
+use crate::ast::{self, Kind};
+use crate::nbe;
+use crate::strategy::{Evaluation, Strategy};
+use crate::typechecker::{self, TypecheckerError};
+use lambda_core::names::{fresh_name, lookup, name_of, shift_index};
+use lambda_core::trace::{Redex, Trace};
+use std::cell::Cell;
+use std::sync::OnceLock;
+use thiserror::Error;
+
+pub use lambda_core::Hint;
+
+// Locally nameless intermediate representation.
+//
+// Bound variables are replaced by de Bruijn indices: the number of binders of
+// the same sort between the occurrence and the binder it refers to. Term
+// variables and type variables live in separate index spaces, so `λ` only
+// shifts term indices and `Λ` only shifts type indices.
+//
+//   λx: Int. λy: Int. x        ~>   λ: Int. λ: Int. 1
+//   ΛX: *. λx: X. x            ~>   Λ: *. λ: 0. 0
+//
+// Substitution on this representation cannot capture variables, and two
+// terms are alpha-equivalent exactly when their representations are equal.
+
+// Type ::=
+//  | Int
+//  | Bool
+//  | Unit
+//  | Type -> Type
+//  | Type × Type
+//  | Type + Type
+//  | {Label: Type, ...}   -- fields sorted by label
+//  | <Label: Type, ...>   -- fields sorted by label
+//  | n                -- type variable
+//  | Type Type        -- application of a type alias, before it is expanded
+//  | ∀ : Kind . Type
+//  | ∃ : Kind . Type
+//  | μ . Type
+#[derive(Debug, Clone, PartialEq)]
+#[allow(clippy::enum_variant_names)]
+pub enum Type {
+  Int,
+  Bool,
+  Unit,
+  Arrow(Box<Type>, Box<Type>),
+  Product(Box<Type>, Box<Type>),
+  Sum(Box<Type>, Box<Type>),
+  Record(Vec<(String, Type)>),
+  Variant(Vec<(String, Type)>),
+  TypeVar(usize),
+  App(Box<Type>, Box<Type>),
+  Forall {
+    hint: Hint,
+    kind: Kind,
+    typ: Box<Type>,
+  },
+  Exists {
+    hint: Hint,
+    kind: Kind,
+    typ: Box<Type>,
+  },
+  Mu {
+    hint: Hint,
+    typ: Box<Type>,
+  },
+}
+
+// Term ::=
+//  | Int
+//  | ()
+//  | n                 -- term variable
+//  | Term Term
+//  | λ : Type . Term
+//  | Λ : Kind . Term
+//  | Term [Type]
+//  | pack [Type, Term] as Type
+//  | unpack Term as [ , ] in Term   -- binds a type and a term variable
+//  | (Term, Term)
+//  | Term.n
+//  | inl Term as Type
+//  | inr Term as Type
+//  | case Term of inl => Term | inr => Term   -- each branch binds a term variable
+//  | {Label = Term, ...}
+//  | Term.Label
+//  | <Label = Term> as Type
+//  | case Term of { <Label = > => Term | ... }  -- each branch binds a term variable
+//  | Term; Term
+//  | let Term in Term   -- binds a term variable
+//  | fix Term
+//  | fold [Type] Term
+//  | unfold [Type] Term
+#[derive(Debug, Clone, PartialEq)]
+pub enum Term {
+  Int(i32),
+  Unit,
+  Var(usize),
+  App(Box<Term>, Box<Term>),
+  Abs {
+    hint: Hint,
+    param_type: Type,
+    body: Box<Term>,
+  },
+  UniversalAbs {
+    hint: Hint,
+    kind: Kind,
+    body: Box<Term>,
+  },
+  UniversalApp(Box<Term>, Type),
+  Pack {
+    witness: Type,
+    term: Box<Term>,
+    typ: Type,
+  },
+  Unpack {
+    type_hint: Hint,
+    hint: Hint,
+    package: Box<Term>,
+    body: Box<Term>,
+  },
+  Pair(Box<Term>, Box<Term>),
+  Proj(Box<Term>, usize),
+  Inl(Box<Term>, Type),
+  Inr(Box<Term>, Type),
+  Case {
+    scrutinee: Box<Term>,
+    left_hint: Hint,
+    left: Box<Term>,
+    right_hint: Hint,
+    right: Box<Term>,
+  },
+  Record(Vec<(String, Term)>),
+  Field(Box<Term>, String),
+  Tag {
+    label: String,
+    term: Box<Term>,
+    typ: Type,
+  },
+  VariantCase {
+    scrutinee: Box<Term>,
+    branches: Vec<Branch>,
+  },
+  Seq(Box<Term>, Box<Term>),
+  Let {
+    hint: Hint,
+    value: Box<Term>,
+    body: Box<Term>,
+  },
+  Fix(Box<Term>),
+  Fold(Type, Box<Term>),
+  Unfold(Type, Box<Term>),
+}
+
+// With `fix` a term can reduce forever, so evaluation gives up after this
+// many steps.
+pub const STEP_LIMIT: usize = 1_000_000;
+
+#[derive(Debug, PartialEq, Error)]
+pub enum EvalError {
+  #[error(transparent)]
+  Typechecker(#[from] TypecheckerError),
+  #[error("evaluation did not finish within {0} steps")]
+  StepLimitExceeded(usize),
+  #[error("normalization nested more than {0} evaluations deep")]
+  DepthLimitExceeded(usize),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Branch {
+  pub label: String,
+  pub hint: Hint,
+  pub body: Term,
+}
+
+// Γ, innermost binding last. Every type stored in `terms` is valid in the
+// type variable context at the current depth.
+#[derive(Debug, Clone, Default)]
+pub struct Context {
+  terms: Vec<(String, Type)>,
+  types: Vec<(String, Kind)>,
+}
+
+// Fails if a label appears more than once.
+pub fn check_distinct(labels: &[&String]) -> Result<(), TypecheckerError> {
+  for (i, label) in labels.iter().enumerate() {
+    if labels[..i].contains(label) {
+      return Err(TypecheckerError::DuplicateLabel((*label).clone()));
+    }
+  }
+  Ok(())
+}
+
+// Sorts the fields of a record or variant type by label, so that types that
+// only list their fields in a different order are equal.
+fn sort_fields<T>(mut fields: Vec<(String, T)>) -> Result<Vec<(String, T)>, TypecheckerError> {
+  check_distinct(&fields.iter().map(|(label, _)| label).collect::<Vec<_>>())?;
+  fields.sort_by(|(a, _), (b, _)| a.cmp(b));
+  Ok(fields)
+}
+
+pub fn field<'a, T>(fields: &'a [(String, T)], label: &str) -> Option<&'a T> {
+  fields
+    .iter()
+    .find(|(other, _)| other == label)
+    .map(|(_, value)| value)
+}
+
+// Matches the labels of the branches of a case with the fields of the variant
+// type `typ` it scrutinizes, returning the type each branch binds. Every label
+// must be handled exactly once.
+pub fn branch_types<'a, T>(
+  labels: &[&String],
+  fields: &'a [(String, T)],
+  typ: &dyn Fn() -> ast::Type,
+) -> Result<Vec<&'a T>, TypecheckerError> {
+  check_distinct(labels)?;
+  let types = labels
+    .iter()
+    .map(|label| {
+      field(fields, label).ok_or_else(|| TypecheckerError::MissingLabel {
+        label: (*label).clone(),
+        typ: typ(),
+      })
+    })
+    .collect::<Result<Vec<_>, _>>()?;
+  match fields.iter().find(|(label, _)| !labels.contains(&label)) {
+    Some((label, _)) => Err(TypecheckerError::NonExhaustiveMatch {
+      label: label.clone(),
+      typ: typ(),
+    }),
+    None => Ok(types),
+  }
+}
+
+impl Context {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  pub fn assign(&self, name: String, typ: Type) -> Context {
+    let mut ctx = self.clone();
+    ctx.terms.push((name, typ));
+    ctx
+  }
+
+  pub fn assign_type(&self, name: String, kind: Kind) -> Context {
+    let mut ctx = self.clone();
+    for (_, typ) in ctx.terms.iter_mut() {
+      *typ = typ.shift(1, 0);
+    }
+    ctx.types.push((name, kind));
+    ctx
+  }
+
+  pub fn get(&self, i: usize) -> Option<&Type> {
+    let position = self.terms.len().checked_sub(i + 1)?;
+    Some(&self.terms[position].1)
+  }
+
+  // The number of type variables in scope.
+  pub fn type_depth(&self) -> usize {
+    self.types.len()
+  }
+
+  fn get_kind(&self, i: usize) -> Option<&Kind> {
+    let position = self.types.len().checked_sub(i + 1)?;
+    Some(&self.types[position].1)
+  }
+}
+
+// An alias with n parameters has kind * -> ... -> * with n arrows.
+fn alias_kind(arity: usize) -> Kind {
+  (0..arity).fold(Kind::Star, |kind, _| {
+    Kind::Arrow(Box::new(Kind::Star), Box::new(kind))
+  })
+}
+
+fn arity(kind: &Kind) -> usize {
+  match kind {
+    Kind::Star => 0,
+    Kind::Arrow(_, result) => 1 + arity(result),
+  }
+}
+
+// Splits `T1 T2 ... Tn` into T1 and its arguments T2 ... Tn.
+fn spine(typ: &ast::Type) -> (&ast::Type, Vec<&ast::Type>) {
+  match typ {
+    ast::Type::App(function, arg) => {
+      let (head, mut args) = spine(function);
+      args.push(arg);
+      (head, args)
+    }
+    _ => (typ, Vec::new()),
+  }
+}
+
+impl Type {
+  pub fn from_ast(ctx: &Context, typ: &ast::Type) -> Result<Type, TypecheckerError> {
+    match typ {
+      ast::Type::Int => Ok(Type::Int),
+      ast::Type::Bool => Ok(Type::Bool),
+      ast::Type::Unit => Ok(Type::Unit),
+      ast::Type::Arrow(param_type, return_type) => Ok(Type::Arrow(
+        Box::new(Type::from_ast(ctx, param_type)?),
+        Box::new(Type::from_ast(ctx, return_type)?),
+      )),
+      ast::Type::Product(left, right) => Ok(Type::Product(
+        Box::new(Type::from_ast(ctx, left)?),
+        Box::new(Type::from_ast(ctx, right)?),
+      )),
+      ast::Type::Sum(left, right) => Ok(Type::Sum(
+        Box::new(Type::from_ast(ctx, left)?),
+        Box::new(Type::from_ast(ctx, right)?),
+      )),
+      ast::Type::Record(fields) => Ok(Type::Record(sort_fields(Type::fields_from_ast(
+        ctx, fields,
+      )?)?)),
+      ast::Type::Variant(fields) => Ok(Type::Variant(sort_fields(Type::fields_from_ast(
+        ctx, fields,
+      )?)?)),
+      ast::Type::TypeVar(_) | ast::Type::App(..) => {
+        // Aliases must be given all their arguments.
+        let (head, args) = spine(typ);
+        match head {
+          ast::Type::TypeVar(x) => match lookup(&ctx.types, x) {
+            None => Err(TypecheckerError::UndefinedTypeVariable(x.clone())),
+            Some(i) => {
+              let expected = arity(ctx.get_kind(i).unwrap());
+              if expected != args.len() {
+                return Err(TypecheckerError::WrongNumberOfTypeArguments {
+                  name: x.clone(),
+                  expected,
+                  got: args.len(),
+                });
+              }
+              args.iter().try_fold(Type::TypeVar(i), |function, arg| {
+                Ok(Type::App(
+                  Box::new(function),
+                  Box::new(Type::from_ast(ctx, arg)?),
+                ))
+              })
+            }
+          },
+          _ => Err(TypecheckerError::UnexpectedType {
+            expected: String::from("type alias"),
+            got: head.clone(),
+          }),
+        }
+      }
+      ast::Type::Forall {
+        type_var,
+        kind,
+        typ,
+      } => {
+        let ctx = ctx.assign_type(type_var.clone(), kind.clone());
+        Ok(Type::Forall {
+          hint: Hint(type_var.clone()),
+          kind: kind.clone(),
+          typ: Box::new(Type::from_ast(&ctx, typ)?),
+        })
+      }
+      ast::Type::Exists {
+        type_var,
+        kind,
+        typ,
+      } => {
+        let ctx = ctx.assign_type(type_var.clone(), kind.clone());
+        Ok(Type::Exists {
+          hint: Hint(type_var.clone()),
+          kind: kind.clone(),
+          typ: Box::new(Type::from_ast(&ctx, typ)?),
+        })
+      }
+      ast::Type::Mu { type_var, typ } => {
+        let ctx = ctx.assign_type(type_var.clone(), Kind::Star);
+        Ok(Type::Mu {
+          hint: Hint(type_var.clone()),
+          typ: Box::new(Type::from_ast(&ctx, typ)?),
+        })
+      }
+    }
+  }
+
+  fn fields_from_ast(
+    ctx: &Context,
+    fields: &[(String, ast::Type)],
+  ) -> Result<Vec<(String, Type)>, TypecheckerError> {
+    fields
+      .iter()
+      .map(|(label, typ)| Ok((label.clone(), Type::from_ast(ctx, typ)?)))
+      .collect()
+  }
+
+  fn fields_to_ast(ctx: &Context, fields: &[(String, Type)]) -> Vec<(String, ast::Type)> {
+    fields
+      .iter()
+      .map(|(label, typ)| (label.clone(), typ.to_ast(ctx)))
+      .collect()
+  }
+
+  pub fn to_ast(&self, ctx: &Context) -> ast::Type {
+    match self {
+      Type::Int => ast::Type::Int,
+      Type::Bool => ast::Type::Bool,
+      Type::Unit => ast::Type::Unit,
+      Type::Arrow(param_type, return_type) => ast::Type::Arrow(
+        Box::new(param_type.to_ast(ctx)),
+        Box::new(return_type.to_ast(ctx)),
+      ),
+      Type::Product(left, right) => {
+        ast::Type::Product(Box::new(left.to_ast(ctx)), Box::new(right.to_ast(ctx)))
+      }
+      Type::Sum(left, right) => {
+        ast::Type::Sum(Box::new(left.to_ast(ctx)), Box::new(right.to_ast(ctx)))
+      }
+      Type::Record(fields) => ast::Type::Record(Type::fields_to_ast(ctx, fields)),
+      Type::Variant(fields) => ast::Type::Variant(Type::fields_to_ast(ctx, fields)),
+      Type::TypeVar(i) => ast::Type::TypeVar(name_of(&ctx.types, *i)),
+      Type::App(function, arg) => {
+        ast::Type::App(Box::new(function.to_ast(ctx)), Box::new(arg.to_ast(ctx)))
+      }
+      Type::Forall { hint, kind, typ } => {
+        let type_var = fresh_name(&ctx.types, &hint.0);
+        let ctx = ctx.assign_type(type_var.clone(), kind.clone());
+        ast::Type::Forall {
+          type_var,
+          kind: kind.clone(),
+          typ: Box::new(typ.to_ast(&ctx)),
+        }
+      }
+      Type::Exists { hint, kind, typ } => {
+        let type_var = fresh_name(&ctx.types, &hint.0);
+        let ctx = ctx.assign_type(type_var.clone(), kind.clone());
+        ast::Type::Exists {
+          type_var,
+          kind: kind.clone(),
+          typ: Box::new(typ.to_ast(&ctx)),
+        }
+      }
+      Type::Mu { hint, typ } => {
+        let type_var = fresh_name(&ctx.types, &hint.0);
+        let ctx = ctx.assign_type(type_var.clone(), Kind::Star);
+        ast::Type::Mu {
+          type_var,
+          typ: Box::new(typ.to_ast(&ctx)),
+        }
+      }
+    }
+  }
+
+  // Rebuilds the type, replacing every type variable with `on_var(cutoff, index)`,
+  // where `cutoff` is the number of binders crossed so far.
+  pub(crate) fn map_vars(&self, cutoff: usize, on_var: &dyn Fn(usize, usize) -> Type) -> Type {
+    match self {
+      Type::Int => Type::Int,
+      Type::Bool => Type::Bool,
+      Type::Unit => Type::Unit,
+      Type::Arrow(param_type, return_type) => Type::Arrow(
+        Box::new(param_type.map_vars(cutoff, on_var)),
+        Box::new(return_type.map_vars(cutoff, on_var)),
+      ),
+      Type::Product(left, right) => Type::Product(
+        Box::new(left.map_vars(cutoff, on_var)),
+        Box::new(right.map_vars(cutoff, on_var)),
+      ),
+      Type::Sum(left, right) => Type::Sum(
+        Box::new(left.map_vars(cutoff, on_var)),
+        Box::new(right.map_vars(cutoff, on_var)),
+      ),
+      Type::Record(fields) => Type::Record(
+        fields
+          .iter()
+          .map(|(label, typ)| (label.clone(), typ.map_vars(cutoff, on_var)))
+          .collect(),
+      ),
+      Type::Variant(fields) => Type::Variant(
+        fields
+          .iter()
+          .map(|(label, typ)| (label.clone(), typ.map_vars(cutoff, on_var)))
+          .collect(),
+      ),
+      Type::TypeVar(i) => on_var(cutoff, *i),
+      Type::App(function, arg) => Type::App(
+        Box::new(function.map_vars(cutoff, on_var)),
+        Box::new(arg.map_vars(cutoff, on_var)),
+      ),
+      Type::Forall { hint, kind, typ } => Type::Forall {
+        hint: hint.clone(),
+        kind: kind.clone(),
+        typ: Box::new(typ.map_vars(cutoff + 1, on_var)),
+      },
+      Type::Exists { hint, kind, typ } => Type::Exists {
+        hint: hint.clone(),
+        kind: kind.clone(),
+        typ: Box::new(typ.map_vars(cutoff + 1, on_var)),
+      },
+      Type::Mu { hint, typ } => Type::Mu {
+        hint: hint.clone(),
+        typ: Box::new(typ.map_vars(cutoff + 1, on_var)),
+      },
+    }
+  }
+
+  // ↑d,c(T): adds `d` to every type variable that is free above `cutoff`.
+  pub fn shift(&self, d: isize, cutoff: usize) -> Type {
+    self.map_vars(cutoff, &|c, i| {
+      if i >= c {
+        Type::TypeVar(shift_index(i, d))
+      } else {
+        Type::TypeVar(i)
+      }
+    })
+  }
+
+  // T[S/j]
+  pub fn subst(&self, j: usize, s: &Type) -> Type {
+    self.map_vars(0, &|c, i| {
+      if i == j + c {
+        s.shift(c as isize, 0)
+      } else {
+        Type::TypeVar(i)
+      }
+    })
+  }
+
+  // Given the body T of `∀α. T`, computes T[S/α].
+  pub fn instantiate(&self, s: &Type) -> Type {
+    self.subst(0, &s.shift(1, 0)).shift(-1, 0)
+  }
+
+  // The inverse of alias expansion: replaces every part of the type that has the
+  // shape of the alias body by an application of the alias, bound at `cutoff`.
+  fn fold_alias(&self, cutoff: usize, body: &Type, n: usize) -> Type {
+    let mut args = vec![None; n];
+    if body.matches(self, 0, cutoff + 1, &mut args) {
+      if let Some(args) = args.into_iter().collect::<Option<Vec<Type>>>() {
+        return args.iter().fold(Type::TypeVar(cutoff), |function, arg| {
+          Type::App(
+            Box::new(function),
+            Box::new(arg.fold_alias(cutoff, body, n)),
+          )
+        });
+      }
+    }
+    let fold = |typ: &Type| Box::new(typ.fold_alias(cutoff, body, n));
+    let fold_fields = |fields: &Vec<(String, Type)>| {
+      fields
+        .iter()
+        .map(|(label, typ)| (label.clone(), typ.fold_alias(cutoff, body, n)))
+        .collect()
+    };
+    match self {
+      Type::Int | Type::Bool | Type::Unit | Type::TypeVar(_) => self.clone(),
+      Type::Arrow(left, right) => Type::Arrow(fold(left), fold(right)),
+      Type::Product(left, right) => Type::Product(fold(left), fold(right)),
+      Type::Sum(left, right) => Type::Sum(fold(left), fold(right)),
+      Type::Record(fields) => Type::Record(fold_fields(fields)),
+      Type::Variant(fields) => Type::Variant(fold_fields(fields)),
+      Type::App(function, arg) => Type::App(fold(function), fold(arg)),
+      Type::Forall { hint, kind, typ } => Type::Forall {
+        hint: hint.clone(),
+        kind: kind.clone(),
+        typ: Box::new(typ.fold_alias(cutoff + 1, body, n)),
+      },
+      Type::Exists { hint, kind, typ } => Type::Exists {
+        hint: hint.clone(),
+        kind: kind.clone(),
+        typ: Box::new(typ.fold_alias(cutoff + 1, body, n)),
+      },
+      Type::Mu { hint, typ } => Type::Mu {
+        hint: hint.clone(),
+        typ: Box::new(typ.fold_alias(cutoff + 1, body, n)),
+      },
+    }
+  }
+
+  // Matches `typ` against the alias body `self`, whose n = args.len()
+  // parameters are its innermost bound type variables, filling in `args`.
+  // `c` counts the binders crossed inside both types, and a free variable of
+  // the body is `d` binders further out in `typ`.
+  fn matches(&self, typ: &Type, c: usize, d: usize, args: &mut [Option<Type>]) -> bool {
+    let n = args.len();
+    let matches_fields = |a: &[(String, Type)], b: &[(String, Type)], args: &mut [Option<Type>]| {
+      a.len() == b.len()
+        && a
+          .iter()
+          .zip(b)
+          .all(|((label_a, a), (label_b, b))| label_a == label_b && a.matches(b, c, d, args))
+    };
+    match (self, typ) {
+      (Type::TypeVar(i), _) if *i >= c && *i < c + n => {
+        // The argument cannot mention the variables bound inside the body.
+        if (0..c).any(|j| typ.occurs(j)) {
+          return false;
+        }
+        let arg = typ.shift(-(c as isize), 0);
+        let param = &mut args[n - 1 - (i - c)];
+        match param {
+          Some(other) => *other == arg,
+          None => {
+            *param = Some(arg);
+            true
+          }
+        }
+      }
+      (Type::TypeVar(i), Type::TypeVar(j)) if *i < c => i == j,
+      (Type::TypeVar(i), Type::TypeVar(j)) => *j == i - n + d,
+      (Type::Int, Type::Int) | (Type::Bool, Type::Bool) | (Type::Unit, Type::Unit) => true,
+      (Type::Arrow(left_a, right_a), Type::Arrow(left_b, right_b))
+      | (Type::Product(left_a, right_a), Type::Product(left_b, right_b))
+      | (Type::Sum(left_a, right_a), Type::Sum(left_b, right_b))
+      | (Type::App(left_a, right_a), Type::App(left_b, right_b)) => {
+        left_a.matches(left_b, c, d, args) && right_a.matches(right_b, c, d, args)
+      }
+      (Type::Record(a), Type::Record(b)) | (Type::Variant(a), Type::Variant(b)) => {
+        matches_fields(a, b, args)
+      }
+      (
+        Type::Forall {
+          kind: kind_a,
+          typ: a,
+          ..
+        },
+        Type::Forall {
+          kind: kind_b,
+          typ: b,
+          ..
+        },
+      )
+      | (
+        Type::Exists {
+          kind: kind_a,
+          typ: a,
+          ..
+        },
+        Type::Exists {
+          kind: kind_b,
+          typ: b,
+          ..
+        },
+      ) => kind_a == kind_b && a.matches(b, c + 1, d, args),
+      (Type::Mu { typ: a, .. }, Type::Mu { typ: b, .. }) => a.matches(b, c + 1, d, args),
+      _ => false,
+    }
+  }
+
+  // Whether the type variable `j` occurs free in T.
+  pub fn occurs(&self, j: usize) -> bool {
+    let occurs = Cell::new(false);
+    self.map_vars(0, &|c, i| {
+      if i == j + c {
+        occurs.set(true);
+      }
+      Type::TypeVar(i)
+    });
+    occurs.get()
+  }
+}
+
+impl Term {
+  pub fn from_ast(ctx: &Context, term: &ast::Term) -> Result<Term, TypecheckerError> {
+    match term {
+      ast::Term::Int(n) => Ok(Term::Int(*n)),
+      ast::Term::Unit => Ok(Term::Unit),
+      ast::Term::Var(x) => match lookup(&ctx.terms, x) {
+        None => Err(TypecheckerError::UndefinedVariable(x.clone())),
+        Some(i) => Ok(Term::Var(i)),
+      },
+      ast::Term::App(f, arg) => Ok(Term::App(
+        Box::new(Term::from_ast(ctx, f)?),
+        Box::new(Term::from_ast(ctx, arg)?),
+      )),
+      ast::Term::Abs {
+        param_name,
+        param_type: Some(param_type),
+        body,
+      } => {
+        let param_type = Type::from_ast(ctx, param_type)?;
+        let ctx = ctx.assign(param_name.clone(), param_type.clone());
+        Ok(Term::Abs {
+          hint: Hint(param_name.clone()),
+          param_type,
+          body: Box::new(Term::from_ast(&ctx, body)?),
+        })
+      }
+      ast::Term::Abs {
+        param_type: None, ..
+      } => Err(TypecheckerError::MissingAnnotation(Box::new(term.clone()))),
+      // Annotations only guide the typechecker.
+      ast::Term::Ann(term, _) => Term::from_ast(ctx, term),
+      ast::Term::UniversalAbs {
+        type_var,
+        kind,
+        body,
+      } => {
+        let ctx = ctx.assign_type(type_var.clone(), kind.clone());
+        Ok(Term::UniversalAbs {
+          hint: Hint(type_var.clone()),
+          kind: kind.clone(),
+          body: Box::new(Term::from_ast(&ctx, body)?),
+        })
+      }
+      ast::Term::UniversalApp(term, typ) => Ok(Term::UniversalApp(
+        Box::new(Term::from_ast(ctx, term)?),
+        Type::from_ast(ctx, typ)?,
+      )),
+      ast::Term::Pack { witness, term, typ } => Ok(Term::Pack {
+        witness: Type::from_ast(ctx, witness)?,
+        term: Box::new(Term::from_ast(ctx, term)?),
+        typ: Type::from_ast(ctx, typ)?,
+      }),
+      ast::Term::Unpack {
+        type_var,
+        var,
+        package,
+        body,
+      } => {
+        let package = Term::from_ast(ctx, package)?;
+        // Only the names matter here, the type of `var` is found by type_of.
+        let ctx = ctx
+          .assign_type(type_var.clone(), Kind::Star)
+          .assign(var.clone(), Type::Int);
+        Ok(Term::Unpack {
+          type_hint: Hint(type_var.clone()),
+          hint: Hint(var.clone()),
+          package: Box::new(package),
+          body: Box::new(Term::from_ast(&ctx, body)?),
+        })
+      }
+      ast::Term::Pair(first, second) => Ok(Term::Pair(
+        Box::new(Term::from_ast(ctx, first)?),
+        Box::new(Term::from_ast(ctx, second)?),
+      )),
+      ast::Term::Proj(term, i) => Ok(Term::Proj(Box::new(Term::from_ast(ctx, term)?), *i)),
+      ast::Term::Inl(term, typ) => Ok(Term::Inl(
+        Box::new(Term::from_ast(ctx, term)?),
+        Type::from_ast(ctx, typ)?,
+      )),
+      ast::Term::Inr(term, typ) => Ok(Term::Inr(
+        Box::new(Term::from_ast(ctx, term)?),
+        Type::from_ast(ctx, typ)?,
+      )),
+      ast::Term::Case {
+        scrutinee,
+        left_var,
+        left,
+        right_var,
+        right,
+      } => {
+        let left_ctx = ctx.assign(left_var.clone(), Type::Int);
+        let right_ctx = ctx.assign(right_var.clone(), Type::Int);
+        Ok(Term::Case {
+          scrutinee: Box::new(Term::from_ast(ctx, scrutinee)?),
+          left_hint: Hint(left_var.clone()),
+          left: Box::new(Term::from_ast(&left_ctx, left)?),
+          right_hint: Hint(right_var.clone()),
+          right: Box::new(Term::from_ast(&right_ctx, right)?),
+        })
+      }
+      ast::Term::Record(fields) => Ok(Term::Record(
+        fields
+          .iter()
+          .map(|(label, term)| Ok((label.clone(), Term::from_ast(ctx, term)?)))
+          .collect::<Result<_, TypecheckerError>>()?,
+      )),
+      ast::Term::Field(term, label) => Ok(Term::Field(
+        Box::new(Term::from_ast(ctx, term)?),
+        label.clone(),
+      )),
+      ast::Term::Tag { label, term, typ } => Ok(Term::Tag {
+        label: label.clone(),
+        term: Box::new(Term::from_ast(ctx, term)?),
+        typ: Type::from_ast(ctx, typ)?,
+      }),
+      ast::Term::VariantCase {
+        scrutinee,
+        branches,
+      } => Ok(Term::VariantCase {
+        scrutinee: Box::new(Term::from_ast(ctx, scrutinee)?),
+        branches: branches
+          .iter()
+          .map(|branch| {
+            let ctx = ctx.assign(branch.var.clone(), Type::Int);
+            Ok(Branch {
+              label: branch.label.clone(),
+              hint: Hint(branch.var.clone()),
+              body: Term::from_ast(&ctx, &branch.body)?,
+            })
+          })
+          .collect::<Result<_, TypecheckerError>>()?,
+      }),
+      ast::Term::Seq(first, second) => Ok(Term::Seq(
+        Box::new(Term::from_ast(ctx, first)?),
+        Box::new(Term::from_ast(ctx, second)?),
+      )),
+      ast::Term::Let { name, value, body } => {
+        // As in Unpack, only the name of the bound variable matters here.
+        let body_ctx = ctx.assign(name.clone(), Type::Int);
+        Ok(Term::Let {
+          hint: Hint(name.clone()),
+          value: Box::new(Term::from_ast(ctx, value)?),
+          body: Box::new(Term::from_ast(&body_ctx, body)?),
+        })
+      }
+      // Aliases have no representation of their own, they are substituted away.
+      ast::Term::TypeLet {
+        type_var,
+        params,
+        typ,
+        body,
+      } => Term::from_ast(ctx, &expand_alias_in_term(type_var, params, typ, body)?),
+      ast::Term::Fix(term) => Ok(Term::Fix(Box::new(Term::from_ast(ctx, term)?))),
+      ast::Term::Fold(typ, term) => Ok(Term::Fold(
+        Type::from_ast(ctx, typ)?,
+        Box::new(Term::from_ast(ctx, term)?),
+      )),
+      ast::Term::Unfold(typ, term) => Ok(Term::Unfold(
+        Type::from_ast(ctx, typ)?,
+        Box::new(Term::from_ast(ctx, term)?),
+      )),
+    }
+  }
+
+  pub fn to_ast(&self, ctx: &Context) -> ast::Term {
+    match self {
+      Term::Int(n) => ast::Term::Int(*n),
+      Term::Unit => ast::Term::Unit,
+      Term::Var(i) => ast::Term::Var(name_of(&ctx.terms, *i)),
+      Term::App(f, arg) => ast::Term::App(Box::new(f.to_ast(ctx)), Box::new(arg.to_ast(ctx))),
+      Term::Abs {
+        hint,
+        param_type,
+        body,
+      } => {
+        let param_name = fresh_name(&ctx.terms, &hint.0);
+        let ctx = ctx.assign(param_name.clone(), param_type.clone());
+        ast::Term::Abs {
+          param_name,
+          param_type: Some(param_type.to_ast(&ctx)),
+          body: Box::new(body.to_ast(&ctx)),
+        }
+      }
+      Term::UniversalAbs { hint, kind, body } => {
+        let type_var = fresh_name(&ctx.types, &hint.0);
+        let ctx = ctx.assign_type(type_var.clone(), kind.clone());
+        ast::Term::UniversalAbs {
+          type_var,
+          kind: kind.clone(),
+          body: Box::new(body.to_ast(&ctx)),
+        }
+      }
+      Term::UniversalApp(term, typ) => {
+        ast::Term::UniversalApp(Box::new(term.to_ast(ctx)), typ.to_ast(ctx))
+      }
+      Term::Pack { witness, term, typ } => ast::Term::Pack {
+        witness: witness.to_ast(ctx),
+        term: Box::new(term.to_ast(ctx)),
+        typ: typ.to_ast(ctx),
+      },
+      Term::Unpack {
+        type_hint,
+        hint,
+        package,
+        body,
+      } => {
+        let type_var = fresh_name(&ctx.types, &type_hint.0);
+        let var = fresh_name(&ctx.terms, &hint.0);
+        // As in from_ast, only the names are needed.
+        let body_ctx = ctx
+          .assign_type(type_var.clone(), Kind::Star)
+          .assign(var.clone(), Type::Int);
+        ast::Term::Unpack {
+          type_var,
+          var,
+          package: Box::new(package.to_ast(ctx)),
+          body: Box::new(body.to_ast(&body_ctx)),
+        }
+      }
+      Term::Pair(first, second) => {
+        ast::Term::Pair(Box::new(first.to_ast(ctx)), Box::new(second.to_ast(ctx)))
+      }
+      Term::Proj(term, i) => ast::Term::Proj(Box::new(term.to_ast(ctx)), *i),
+      Term::Inl(term, typ) => ast::Term::Inl(Box::new(term.to_ast(ctx)), typ.to_ast(ctx)),
+      Term::Inr(term, typ) => ast::Term::Inr(Box::new(term.to_ast(ctx)), typ.to_ast(ctx)),
+      Term::Case {
+        scrutinee,
+        left_hint,
+        left,
+        right_hint,
+        right,
+      } => {
+        let left_var = fresh_name(&ctx.terms, &left_hint.0);
+        let right_var = fresh_name(&ctx.terms, &right_hint.0);
+        let left_ctx = ctx.assign(left_var.clone(), Type::Int);
+        let right_ctx = ctx.assign(right_var.clone(), Type::Int);
+        ast::Term::Case {
+          scrutinee: Box::new(scrutinee.to_ast(ctx)),
+          left_var,
+          left: Box::new(left.to_ast(&left_ctx)),
+          right_var,
+          right: Box::new(right.to_ast(&right_ctx)),
+        }
+      }
+      Term::Record(fields) => ast::Term::Record(
+        fields
+          .iter()
+          .map(|(label, term)| (label.clone(), term.to_ast(ctx)))
+          .collect(),
+      ),
+      Term::Field(term, label) => ast::Term::Field(Box::new(term.to_ast(ctx)), label.clone()),
+      Term::Tag { label, term, typ } => ast::Term::Tag {
+        label: label.clone(),
+        term: Box::new(term.to_ast(ctx)),
+        typ: typ.to_ast(ctx),
+      },
+      Term::VariantCase {
+        scrutinee,
+        branches,
+      } => ast::Term::VariantCase {
+        scrutinee: Box::new(scrutinee.to_ast(ctx)),
+        branches: branches
+          .iter()
+          .map(|branch| {
+            let var = fresh_name(&ctx.terms, &branch.hint.0);
+            let body_ctx = ctx.assign(var.clone(), Type::Int);
+            ast::Branch {
+              label: branch.label.clone(),
+              var,
+              body: branch.body.to_ast(&body_ctx),
+            }
+          })
+          .collect(),
+      },
+      Term::Seq(first, second) => {
+        ast::Term::Seq(Box::new(first.to_ast(ctx)), Box::new(second.to_ast(ctx)))
+      }
+      Term::Let { hint, value, body } => {
+        let name = fresh_name(&ctx.terms, &hint.0);
+        let body_ctx = ctx.assign(name.clone(), Type::Int);
+        ast::Term::Let {
+          name,
+          value: Box::new(value.to_ast(ctx)),
+          body: Box::new(body.to_ast(&body_ctx)),
+        }
+      }
+      Term::Fix(term) => ast::Term::Fix(Box::new(term.to_ast(ctx))),
+      Term::Fold(typ, term) => ast::Term::Fold(typ.to_ast(ctx), Box::new(term.to_ast(ctx))),
+      Term::Unfold(typ, term) => ast::Term::Unfold(typ.to_ast(ctx), Box::new(term.to_ast(ctx))),
+    }
+  }
+
+  // Rebuilds the term, replacing every term variable with
+  // `on_var(term_cutoff, type_cutoff, index)` and every type annotation with
+  // `on_type(type_cutoff, typ)`.
+  pub(crate) fn map_vars(
+    &self,
+    term_cutoff: usize,
+    type_cutoff: usize,
+    on_var: &dyn Fn(usize, usize, usize) -> Term,
+    on_type: &dyn Fn(usize, &Type) -> Type,
+  ) -> Term {
+    match self {
+      Term::Int(n) => Term::Int(*n),
+      Term::Unit => Term::Unit,
+      Term::Var(i) => on_var(term_cutoff, type_cutoff, *i),
+      Term::App(f, arg) => Term::App(
+        Box::new(f.map_vars(term_cutoff, type_cutoff, on_var, on_type)),
+        Box::new(arg.map_vars(term_cutoff, type_cutoff, on_var, on_type)),
+      ),
+      Term::Abs {
+        hint,
+        param_type,
+        body,
+      } => Term::Abs {
+        hint: hint.clone(),
+        param_type: on_type(type_cutoff, param_type),
+        body: Box::new(body.map_vars(term_cutoff + 1, type_cutoff, on_var, on_type)),
+      },
+      Term::UniversalAbs { hint, kind, body } => Term::UniversalAbs {
+        hint: hint.clone(),
+        kind: kind.clone(),
+        body: Box::new(body.map_vars(term_cutoff, type_cutoff + 1, on_var, on_type)),
+      },
+      Term::UniversalApp(term, typ) => Term::UniversalApp(
+        Box::new(term.map_vars(term_cutoff, type_cutoff, on_var, on_type)),
+        on_type(type_cutoff, typ),
+      ),
+      Term::Pack { witness, term, typ } => Term::Pack {
+        witness: on_type(type_cutoff, witness),
+        term: Box::new(term.map_vars(term_cutoff, type_cutoff, on_var, on_type)),
+        typ: on_type(type_cutoff, typ),
+      },
+      Term::Unpack {
+        type_hint,
+        hint,
+        package,
+        body,
+      } => Term::Unpack {
+        type_hint: type_hint.clone(),
+        hint: hint.clone(),
+        package: Box::new(package.map_vars(term_cutoff, type_cutoff, on_var, on_type)),
+        body: Box::new(body.map_vars(term_cutoff + 1, type_cutoff + 1, on_var, on_type)),
+      },
+      Term::Pair(first, second) => Term::Pair(
+        Box::new(first.map_vars(term_cutoff, type_cutoff, on_var, on_type)),
+        Box::new(second.map_vars(term_cutoff, type_cutoff, on_var, on_type)),
+      ),
+      Term::Proj(term, i) => Term::Proj(
+        Box::new(term.map_vars(term_cutoff, type_cutoff, on_var, on_type)),
+        *i,
+      ),
+      Term::Inl(term, typ) => Term::Inl(
+        Box::new(term.map_vars(term_cutoff, type_cutoff, on_var, on_type)),
+        on_type(type_cutoff, typ),
+      ),
+      Term::Inr(term, typ) => Term::Inr(
+        Box::new(term.map_vars(term_cutoff, type_cutoff, on_var, on_type)),
+        on_type(type_cutoff, typ),
+      ),
+      Term::Case {
+        scrutinee,
+        left_hint,
+        left,
+        right_hint,
+        right,
+      } => Term::Case {
+        scrutinee: Box::new(scrutinee.map_vars(term_cutoff, type_cutoff, on_var, on_type)),
+        left_hint: left_hint.clone(),
+        left: Box::new(left.map_vars(term_cutoff + 1, type_cutoff, on_var, on_type)),
+        right_hint: right_hint.clone(),
+        right: Box::new(right.map_vars(term_cutoff + 1, type_cutoff, on_var, on_type)),
+      },
+      Term::Record(fields) => Term::Record(
+        fields
+          .iter()
+          .map(|(label, term)| {
+            (
+              label.clone(),
+              term.map_vars(term_cutoff, type_cutoff, on_var, on_type),
+            )
+          })
+          .collect(),
+      ),
+      Term::Field(term, label) => Term::Field(
+        Box::new(term.map_vars(term_cutoff, type_cutoff, on_var, on_type)),
+        label.clone(),
+      ),
+      Term::Tag { label, term, typ } => Term::Tag {
+        label: label.clone(),
+        term: Box::new(term.map_vars(term_cutoff, type_cutoff, on_var, on_type)),
+        typ: on_type(type_cutoff, typ),
+      },
+      Term::VariantCase {
+        scrutinee,
+        branches,
+      } => Term::VariantCase {
+        scrutinee: Box::new(scrutinee.map_vars(term_cutoff, type_cutoff, on_var, on_type)),
+        branches: branches
+          .iter()
+          .map(|branch| Branch {
+            label: branch.label.clone(),
+            hint: branch.hint.clone(),
+            body: branch
+              .body
+              .map_vars(term_cutoff + 1, type_cutoff, on_var, on_type),
+          })
+          .collect(),
+      },
+      Term::Seq(first, second) => Term::Seq(
+        Box::new(first.map_vars(term_cutoff, type_cutoff, on_var, on_type)),
+        Box::new(second.map_vars(term_cutoff, type_cutoff, on_var, on_type)),
+      ),
+      Term::Let { hint, value, body } => Term::Let {
+        hint: hint.clone(),
+        value: Box::new(value.map_vars(term_cutoff, type_cutoff, on_var, on_type)),
+        body: Box::new(body.map_vars(term_cutoff + 1, type_cutoff, on_var, on_type)),
+      },
+      Term::Fix(term) => Term::Fix(Box::new(term.map_vars(
+        term_cutoff,
+        type_cutoff,
+        on_var,
+        on_type,
+      ))),
+      Term::Fold(typ, term) => Term::Fold(
+        on_type(type_cutoff, typ),
+        Box::new(term.map_vars(term_cutoff, type_cutoff, on_var, on_type)),
+      ),
+      Term::Unfold(typ, term) => Term::Unfold(
+        on_type(type_cutoff, typ),
+        Box::new(term.map_vars(term_cutoff, type_cutoff, on_var, on_type)),
+      ),
+    }
+  }
+
+  // ↑d,c(t) on term variables.
+  pub fn shift(&self, d: isize, cutoff: usize) -> Term {
+    self.map_vars(
+      cutoff,
+      0,
+      &|c, _, i| {
+        if i >= c {
+          Term::Var(shift_index(i, d))
+        } else {
+          Term::Var(i)
+        }
+      },
+      &|_, typ| typ.clone(),
+    )
+  }
+
+  // ↑d,c(t) on the type variables appearing in annotations.
+  pub fn shift_types(&self, d: isize, cutoff: usize) -> Term {
+    self.map_vars(0, cutoff, &|_, _, i| Term::Var(i), &|c, typ| {
+      typ.shift(d, c)
+    })
+  }
+
+  // t[s/j]
+  pub fn subst(&self, j: usize, s: &Term) -> Term {
+    self.map_vars(
+      0,
+      0,
+      &|c, type_c, i| {
+        if i == j + c {
+          s.shift(c as isize, 0).shift_types(type_c as isize, 0)
+        } else {
+          Term::Var(i)
+        }
+      },
+      &|_, typ| typ.clone(),
+    )
+  }
+
+  // t[S/j]
+  pub fn subst_type(&self, j: usize, s: &Type) -> Term {
+    self.map_vars(0, 0, &|_, _, i| Term::Var(i), &|c, typ| {
+      typ.subst(j + c, &s.shift(c as isize, 0))
+    })
+  }
+
+  // Given the body t of `λx. t`, computes t[v/x].
+  pub fn instantiate(&self, v: &Term) -> Term {
+    self.subst(0, &v.shift(1, 0)).shift(-1, 0)
+  }
+
+  // Given the body t of `Λα. t`, computes t[σ/α].
+  pub fn instantiate_type(&self, s: &Type) -> Term {
+    self.subst_type(0, &s.shift(1, 0)).shift_types(-1, 0)
+  }
+
+  pub fn type_of(&self, ctx: &Context) -> Result<Type, TypecheckerError> {
+    match self {
+      Term::Int(_) => Ok(Type::Int),
+      Term::Unit => Ok(Type::Unit),
+      // Γ(x) = τ
+      // --------
+      // Γ ⊢ x: τ
+      Term::Var(i) => match ctx.get(*i) {
+        None => Err(TypecheckerError::UndefinedVariable(name_of(&ctx.terms, *i))),
+        Some(typ) => Ok(typ.clone()),
+      },
+      // Γ ⊢ t1: σ -> τ    Γ ⊢ t2: σ
+      // ---------------------------
+      //       Γ ⊢ (t1 t2): τ
+      Term::App(f, arg) => match f.type_of(ctx)? {
+        Type::Arrow(param_type, return_type) => {
+          let arg_type = arg.type_of(ctx)?;
+
+          if !param_type.equiv(&arg_type, ctx.type_depth()) {
+            return Err(TypecheckerError::TypeMismatch {
+              term: Box::new(arg.to_ast(ctx)),
+              expected: param_type.to_ast(ctx),
+              got: arg_type.to_ast(ctx),
+            });
+          }
+
+          Ok(*return_type)
+        }
+        _ => Err(TypecheckerError::UnexpectedTerm {
+          expected: String::from("abstraction"),
+          got: Box::new(f.to_ast(ctx)),
+        }),
+      },
+      //    Γ, x: σ ⊢ t: τ
+      // ----------------------
+      // Γ ⊢ (λx: σ. t): σ -> τ
+      Term::Abs {
+        hint,
+        param_type,
+        body,
+      } => {
+        let ctx = ctx.assign(fresh_name(&ctx.terms, &hint.0), param_type.clone());
+        let body_typ = body.type_of(&ctx)?;
+        Ok(Type::Arrow(
+          Box::new(param_type.clone()),
+          Box::new(body_typ),
+        ))
+      }
+      //      Γ, α : κ ⊢ t : τ
+      // ----------------------------
+      // Γ ⊢ (Λα : κ. t) : (∀α : κ . τ)
+      Term::UniversalAbs { hint, kind, body } => {
+        let ctx = ctx.assign_type(fresh_name(&ctx.types, &hint.0), kind.clone());
+        Ok(Type::Forall {
+          hint: hint.clone(),
+          kind: kind.clone(),
+          typ: Box::new(body.type_of(&ctx)?),
+        })
+      }
+      // Γ ⊢ t : (∀α : κ . τ)   Γ ⊢ σ
+      // ----------------------------
+      //      Γ ⊢ t σ : τ[σ/α]
+      Term::UniversalApp(term, arg_typ) => match term.type_of(ctx)? {
+        Type::Forall { typ, .. } => Ok(typ.instantiate(arg_typ)),
+        _ => Err(TypecheckerError::UnexpectedTerm {
+          expected: String::from("type abstraction"),
+          got: Box::new(term.to_ast(ctx)),
+        }),
+      },
+      //           Γ ⊢ t : τ[σ/α]
+      // ----------------------------------------
+      // Γ ⊢ pack [σ, t] as ∃α : κ. τ : ∃α : κ. τ
+      Term::Pack { witness, term, typ } => match typ {
+        Type::Exists { typ: body, .. } => {
+          let expected = body.instantiate(witness);
+          let got = term.type_of(ctx)?;
+
+          if !got.equiv(&expected, ctx.type_depth()) {
+            return Err(TypecheckerError::TypeMismatch {
+              term: Box::new(term.to_ast(ctx)),
+              expected: expected.to_ast(ctx),
+              got: got.to_ast(ctx),
+            });
+          }
+
+          Ok(typ.clone())
+        }
+        _ => Err(TypecheckerError::UnexpectedType {
+          expected: String::from("existential type"),
+          got: typ.to_ast(ctx),
+        }),
+      },
+      // Γ ⊢ t1 : ∃α : κ. τ1    Γ, α : κ, x: τ1 ⊢ t2 : τ2    α ∉ FV(τ2)
+      // -------------------------------------------------------------
+      //            Γ ⊢ unpack t1 as [α, x] in t2 : τ2
+      Term::Unpack {
+        type_hint,
+        hint,
+        package,
+        body,
+      } => match package.type_of(ctx)? {
+        Type::Exists { kind, typ, .. } => {
+          let type_var = fresh_name(&ctx.types, &type_hint.0);
+          let ctx = ctx.assign_type(type_var.clone(), kind);
+          let ctx = ctx.assign(fresh_name(&ctx.terms, &hint.0), *typ);
+          let body_typ = body.type_of(&ctx)?;
+
+          if body_typ.occurs(0) {
+            return Err(TypecheckerError::EscapingTypeVariable {
+              type_var,
+              typ: body_typ.to_ast(&ctx),
+            });
+          }
+
+          Ok(body_typ.shift(-1, 0))
+        }
+        _ => Err(TypecheckerError::UnexpectedTerm {
+          expected: String::from("package"),
+          got: Box::new(package.to_ast(ctx)),
+        }),
+      },
+      // Γ ⊢ t1 : τ1    Γ ⊢ t2 : τ2
+      // --------------------------
+      // Γ ⊢ (t1, t2) : τ1 × τ2
+      Term::Pair(first, second) => Ok(Type::Product(
+        Box::new(first.type_of(ctx)?),
+        Box::new(second.type_of(ctx)?),
+      )),
+      // Γ ⊢ t : τ1 × τ2
+      // ---------------
+      //  Γ ⊢ t.i : τi
+      Term::Proj(term, i) => match (term.type_of(ctx)?, i) {
+        (Type::Product(first, _), 1) => Ok(*first),
+        (Type::Product(_, second), 2) => Ok(*second),
+        (typ, _) => Err(TypecheckerError::UnexpectedType {
+          expected: format!("a pair type with a component {}", i),
+          got: typ.to_ast(ctx),
+        }),
+      },
+      //          Γ ⊢ t : τ1
+      // -------------------------------
+      // Γ ⊢ inl t as τ1 + τ2 : τ1 + τ2
+      //
+      //          Γ ⊢ t : τ2
+      // -------------------------------
+      // Γ ⊢ inr t as τ1 + τ2 : τ1 + τ2
+      Term::Inl(term, typ) | Term::Inr(term, typ) => match typ {
+        Type::Sum(left, right) => {
+          let expected = if let Term::Inl(..) = self {
+            left
+          } else {
+            right
+          };
+          let got = term.type_of(ctx)?;
+
+          if !got.equiv(expected, ctx.type_depth()) {
+            return Err(TypecheckerError::TypeMismatch {
+              term: Box::new(term.to_ast(ctx)),
+              expected: expected.to_ast(ctx),
+              got: got.to_ast(ctx),
+            });
+          }
+
+          Ok(typ.clone())
+        }
+        _ => Err(TypecheckerError::UnexpectedType {
+          expected: String::from("sum type"),
+          got: typ.to_ast(ctx),
+        }),
+      },
+      // Γ ⊢ t : τ1 + τ2    Γ, x1: τ1 ⊢ t1 : τ    Γ, x2: τ2 ⊢ t2 : τ
+      // ----------------------------------------------------------
+      //    Γ ⊢ case t of inl x1 => t1 | inr x2 => t2 : τ
+      Term::Case {
+        scrutinee,
+        left_hint,
+        left,
+        right_hint,
+        right,
+      } => match scrutinee.type_of(ctx)? {
+        Type::Sum(left_typ, right_typ) => {
+          let left_ctx = ctx.assign(fresh_name(&ctx.terms, &left_hint.0), *left_typ);
+          let right_ctx = ctx.assign(fresh_name(&ctx.terms, &right_hint.0), *right_typ);
+          let typ = left.type_of(&left_ctx)?;
+          let right_branch_typ = right.type_of(&right_ctx)?;
+
+          if !right_branch_typ.equiv(&typ, ctx.type_depth()) {
+            return Err(TypecheckerError::TypeMismatch {
+              term: Box::new(right.to_ast(&right_ctx)),
+              expected: typ.to_ast(ctx),
+              got: right_branch_typ.to_ast(ctx),
+            });
+          }
+
+          Ok(typ)
+        }
+        typ => Err(TypecheckerError::UnexpectedType {
+          expected: String::from("sum type"),
+          got: typ.to_ast(ctx),
+        }),
+      },
+      // Γ ⊢ ti : τi for each i    the labels li are distinct
+      // ---------------------------------------------------
+      //   Γ ⊢ {l1 = t1, ..., ln = tn} : {l1: τ1, ..., ln: τn}
+      Term::Record(fields) => Ok(Type::Record(sort_fields(
+        fields
+          .iter()
+          .map(|(label, term)| Ok((label.clone(), term.type_of(ctx)?)))
+          .collect::<Result<_, TypecheckerError>>()?,
+      )?)),
+      // Γ ⊢ t : {l1: τ1, ..., ln: τn}
+      // -----------------------------
+      //        Γ ⊢ t.li : τi
+      Term::Field(term, label) => match term.type_of(ctx)? {
+        Type::Record(fields) => match field(&fields, label) {
+          Some(typ) => Ok(typ.clone()),
+          None => Err(TypecheckerError::MissingLabel {
+            label: label.clone(),
+            typ: Type::Record(fields).to_ast(ctx),
+          }),
+        },
+        typ => Err(TypecheckerError::UnexpectedType {
+          expected: String::from("record type"),
+          got: typ.to_ast(ctx),
+        }),
+      },
+      //                     Γ ⊢ t : τi
+      // ------------------------------------------------------
+      // Γ ⊢ <li = t> as <l1: τ1, ..., ln: τn> : <l1: τ1, ..., ln: τn>
+      Term::Tag { label, term, typ } => match typ {
+        Type::Variant(fields) => match field(fields, label) {
+          Some(expected) => {
+            let got = term.type_of(ctx)?;
+
+            if !got.equiv(expected, ctx.type_depth()) {
+              return Err(TypecheckerError::TypeMismatch {
+                term: Box::new(term.to_ast(ctx)),
+                expected: expected.to_ast(ctx),
+                got: got.to_ast(ctx),
+              });
+            }
+
+            Ok(typ.clone())
+          }
+          None => Err(TypecheckerError::MissingLabel {
+            label: label.clone(),
+            typ: typ.to_ast(ctx),
+          }),
+        },
+        _ => Err(TypecheckerError::UnexpectedType {
+          expected: String::from("variant type"),
+          got: typ.to_ast(ctx),
+        }),
+      },
+      // Γ ⊢ t : <l1: τ1, ..., ln: τn>    Γ, xi: τi ⊢ ti : τ for each i
+      // ---------------------------------------------------------------
+      //   Γ ⊢ case t of { <l1 = x1> => t1 | ... | <ln = xn> => tn } : τ
+      Term::VariantCase {
+        scrutinee,
+        branches,
+      } => match scrutinee.type_of(ctx)? {
+        Type::Variant(fields) => {
+          let labels: Vec<&String> = branches.iter().map(|branch| &branch.label).collect();
+          let variant_typ = || Type::Variant(fields.clone()).to_ast(ctx);
+          let branch_types = branch_types(&labels, &fields, &variant_typ)?;
+          let mut typ: Option<Type> = None;
+          for (branch, var_typ) in branches.iter().zip(branch_types) {
+            let branch_ctx = ctx.assign(fresh_name(&ctx.terms, &branch.hint.0), var_typ.clone());
+            let branch_typ = branch.body.type_of(&branch_ctx)?;
+            match &typ {
+              Some(typ) if !typ.equiv(&branch_typ, ctx.type_depth()) => {
+                return Err(TypecheckerError::TypeMismatch {
+                  term: Box::new(branch.body.to_ast(&branch_ctx)),
+                  expected: typ.to_ast(ctx),
+                  got: branch_typ.to_ast(ctx),
+                })
+              }
+              _ => typ = Some(branch_typ),
+            }
+          }
+          typ.ok_or_else(|| TypecheckerError::UnexpectedType {
+            expected: String::from("a variant type with at least one label"),
+            got: variant_typ(),
+          })
+        }
+        typ => Err(TypecheckerError::UnexpectedType {
+          expected: String::from("variant type"),
+          got: typ.to_ast(ctx),
+        }),
+      },
+      // Γ ⊢ t1 : Unit    Γ ⊢ t2 : τ
+      // ---------------------------
+      //      Γ ⊢ t1; t2 : τ
+      Term::Seq(first, second) => {
+        let first_typ = first.type_of(ctx)?;
+
+        if first_typ != Type::Unit {
+          return Err(TypecheckerError::TypeMismatch {
+            term: Box::new(first.to_ast(ctx)),
+            expected: ast::Type::Unit,
+            got: first_typ.to_ast(ctx),
+          });
+        }
+
+        second.type_of(ctx)
+      }
+      // Γ ⊢ t1 : σ    Γ, x: σ ⊢ t2 : τ
+      // -----------------------------
+      //   Γ ⊢ let x = t1 in t2 : τ
+      Term::Let { hint, value, body } => {
+        let value_typ = value.type_of(ctx)?;
+        let ctx = ctx.assign(fresh_name(&ctx.terms, &hint.0), value_typ);
+        body.type_of(&ctx)
+      }
+      // Γ ⊢ t : τ -> τ
+      // --------------
+      // Γ ⊢ fix t : τ
+      Term::Fix(term) => match term.type_of(ctx)? {
+        Type::Arrow(param_type, return_type)
+          if param_type.equiv(&return_type, ctx.type_depth()) =>
+        {
+          Ok(*return_type)
+        }
+        typ => Err(TypecheckerError::UnexpectedType {
+          expected: String::from("function type of the form T -> T"),
+          got: typ.to_ast(ctx),
+        }),
+      },
+      //       Γ ⊢ t : τ[μα. τ/α]
+      // ---------------------------
+      // Γ ⊢ fold [μα. τ] t : μα. τ
+      Term::Fold(typ, term) => match typ {
+        Type::Mu { typ: body, .. } => {
+          let expected = body.instantiate(typ);
+          let got = term.type_of(ctx)?;
+
+          if !got.equiv(&expected, ctx.type_depth()) {
+            return Err(TypecheckerError::TypeMismatch {
+              term: Box::new(term.to_ast(ctx)),
+              expected: expected.to_ast(ctx),
+              got: got.to_ast(ctx),
+            });
+          }
+
+          Ok(typ.clone())
+        }
+        _ => Err(TypecheckerError::UnexpectedType {
+          expected: String::from("recursive type"),
+          got: typ.to_ast(ctx),
+        }),
+      },
+      //         Γ ⊢ t : μα. τ
+      // -------------------------------
+      // Γ ⊢ unfold [μα. τ] t : τ[μα. τ/α]
+      Term::Unfold(typ, term) => match typ {
+        Type::Mu { typ: body, .. } => {
+          let got = term.type_of(ctx)?;
+
+          if !got.equiv(typ, ctx.type_depth()) {
+            return Err(TypecheckerError::TypeMismatch {
+              term: Box::new(term.to_ast(ctx)),
+              expected: typ.to_ast(ctx),
+              got: got.to_ast(ctx),
+            });
+          }
+
+          Ok(body.instantiate(typ))
+        }
+        _ => Err(TypecheckerError::UnexpectedType {
+          expected: String::from("recursive type"),
+          got: typ.to_ast(ctx),
+        }),
+      },
+    }
+  }
+
+  pub fn is_value(&self) -> bool {
+    match self {
+      Term::Int(_) | Term::Unit | Term::Abs { .. } | Term::UniversalAbs { .. } => true,
+      Term::Pack { term, .. }
+      | Term::Fold(_, term)
+      | Term::Inl(term, _)
+      | Term::Inr(term, _)
+      | Term::Tag { term, .. } => term.is_value(),
+      Term::Record(fields) => fields.iter().all(|(_, term)| term.is_value()),
+      Term::Pair(first, second) => first.is_value() && second.is_value(),
+      _ => false,
+    }
+  }
+
+  // Performs a single call-by-value reduction step, or returns `None` when
+  // the term is a value or stuck.
+  pub fn step(&self) -> Option<Term> {
+    self.step_traced().map(|(next, _)| next)
+  }
+
+  // Like `step`, also returning where the redex was and the rules that
+  // reduced it.
+  pub fn step_traced(&self) -> Option<(Term, Redex)> {
+    Evaluation::new(Strategy::CallByValue).step(self)
+  }
+
+  // A step of `ev`'s strategy.
+  pub(crate) fn reduce(&self, ev: &mut Evaluation) -> Option<Term> {
+    if ev.strategy().is_strong() {
+      self.reduce_strong(ev)
+    } else {
+      self.reduce_weak(ev)
+    }
+  }
+
+  // A step of call-by-value, or of call-by-name or call-by-need, which only
+  // differ from it in the arguments and let bindings they substitute.
+  pub(crate) fn reduce_weak(&self, ev: &mut Evaluation) -> Option<Term> {
+    match self {
+      // Only a variable bound on the heap of call-by-need can step.
+      Term::Var(i) => ev.lookup(*i),
+      Term::App(f, arg) => {
+        if !f.is_value() {
+          //    t1 |> t1'
+          // ---------------
+          // t1 t2 |> t1' t2
+          Some(Term::App(
+            Box::new(f.reduce(ev.enter("E-App1", 0))?),
+            arg.clone(),
+          ))
+        } else if let (Term::Abs { hint, body, .. }, true) = (&**f, ev.strategy().is_lazy()) {
+          // ------------------------
+          // (λx: τ. t) t' |> t[t'/x]
+          Some(ev.bind_lazily(hint, arg, body, "E-AppAbs", "E-AppAlloc"))
+        } else if !arg.is_value() {
+          //   t2 |> t2'
+          // ------------
+          // v t2 |> v t2'
+          Some(Term::App(
+            f.clone(),
+            Box::new(arg.reduce(ev.enter("E-App2", 1))?),
+          ))
+        } else {
+          match &**f {
+            // ------------------------
+            // (λx: τ. t) v |> t[v/x]
+            Term::Abs { body, .. } => Some(ev.contract("E-AppAbs", || body.instantiate(arg))),
+            _ => None,
+          }
+        }
+      }
+      Term::UniversalApp(term, typ) => {
+        if !term.is_value() {
+          //    t |> t'
+          // -------------
+          // t σ |> t' σ
+          Some(Term::UniversalApp(
+            Box::new(term.reduce(ev.enter("E-TApp", 0))?),
+            typ.clone(),
+          ))
+        } else {
+          match &**term {
+            // -------------------------
+            // (Λα : κ . t) σ |> t[σ/α]
+            Term::UniversalAbs { body, .. } => {
+              Some(ev.contract("E-TappTabs", || body.instantiate_type(typ)))
+            }
+            _ => None,
+          }
+        }
+      }
+      //                       t |> t'
+      // --------------------------------------------------
+      // pack [σ, t] as τ |> pack [σ, t'] as τ
+      Term::Pack { witness, term, typ } => Some(Term::Pack {
+        witness: witness.clone(),
+        term: Box::new(term.reduce(ev.enter("E-Pack", 0))?),
+        typ: typ.clone(),
+      }),
+      Term::Unpack {
+        type_hint,
+        hint,
+        package,
+        body,
+      } => {
+        if !package.is_value() {
+          //                    t1 |> t1'
+          // --------------------------------------------------
+          // unpack t1 as [α, x] in t2 |> unpack t1' as [α, x] in t2
+          Some(Term::Unpack {
+            type_hint: type_hint.clone(),
+            hint: hint.clone(),
+            package: Box::new(package.reduce(ev.enter("E-Unpack", 0))?),
+            body: body.clone(),
+          })
+        } else {
+          match &**package {
+            // ---------------------------------------------------------
+            // unpack (pack [σ, v] as τ) as [α, x] in t |> t[σ/α][v/x]
+            Term::Pack { witness, term, .. } => Some(ev.contract("E-UnpackPack", || {
+              body.instantiate_type(witness).instantiate(term)
+            })),
+            _ => None,
+          }
+        }
+      }
+      Term::Pair(first, second) => {
+        if !first.is_value() {
+          //       t1 |> t1'
+          // ---------------------
+          // (t1, t2) |> (t1', t2)
+          Some(Term::Pair(
+            Box::new(first.reduce(ev.enter("E-Pair1", 0))?),
+            second.clone(),
+          ))
+        } else {
+          //       t2 |> t2'
+          // ---------------------
+          // (v1, t2) |> (v1, t2')
+          Some(Term::Pair(
+            first.clone(),
+            Box::new(second.reduce(ev.enter("E-Pair2", 1))?),
+          ))
+        }
+      }
+      Term::Proj(term, i) => {
+        if !term.is_value() {
+          //    t |> t'
+          // -------------
+          // t.i |> t'.i
+          Some(Term::Proj(
+            Box::new(term.reduce(ev.enter("E-Proj", 0))?),
+            *i,
+          ))
+        } else {
+          match (&**term, i) {
+            // ---------------
+            // (v1, v2).1 |> v1
+            (Term::Pair(first, _), 1) => Some(ev.contract("E-PairBeta1", || *first.clone())),
+            // ---------------
+            // (v1, v2).2 |> v2
+            (Term::Pair(_, second), 2) => Some(ev.contract("E-PairBeta2", || *second.clone())),
+            _ => None,
+          }
+        }
+      }
+      //          t |> t'
+      // -----------------------
+      // inl t as τ |> inl t' as τ
+      Term::Inl(term, typ) => Some(Term::Inl(
+        Box::new(term.reduce(ev.enter("E-Inl", 0))?),
+        typ.clone(),
+      )),
+      //          t |> t'
+      // -----------------------
+      // inr t as τ |> inr t' as τ
+      Term::Inr(term, typ) => Some(Term::Inr(
+        Box::new(term.reduce(ev.enter("E-Inr", 0))?),
+        typ.clone(),
+      )),
+      Term::Case {
+        scrutinee,
+        left_hint,
+        left,
+        right_hint,
+        right,
+      } => {
+        if !scrutinee.is_value() {
+          //                              t |> t'
+          // ---------------------------------------------------------------------------
+          // case t of inl x1 => t1 | inr x2 => t2 |> case t' of inl x1 => t1 | inr x2 => t2
+          Some(Term::Case {
+            scrutinee: Box::new(scrutinee.reduce(ev.enter("E-Case", 0))?),
+            left_hint: left_hint.clone(),
+            left: left.clone(),
+            right_hint: right_hint.clone(),
+            right: right.clone(),
+          })
+        } else {
+          match &**scrutinee {
+            // --------------------------------------------------------------
+            // case (inl v as τ) of inl x1 => t1 | inr x2 => t2 |> t1[v/x1]
+            Term::Inl(value, _) => Some(ev.contract("E-CaseInl", || left.instantiate(value))),
+            // --------------------------------------------------------------
+            // case (inr v as τ) of inl x1 => t1 | inr x2 => t2 |> t2[v/x2]
+            Term::Inr(value, _) => Some(ev.contract("E-CaseInr", || right.instantiate(value))),
+            _ => None,
+          }
+        }
+      }
+      //                        ti |> ti'
+      // --------------------------------------------------------
+      // {l1 = v1, ..., li = ti, ...} |> {l1 = v1, ..., li = ti', ...}
+      Term::Record(fields) => {
+        let i = fields.iter().position(|(_, term)| !term.is_value())?;
+        let mut fields = fields.clone();
+        fields[i].1 = fields[i].1.reduce(ev.enter("E-Rcd", i))?;
+        Some(Term::Record(fields))
+      }
+      Term::Field(term, label) => {
+        if !term.is_value() {
+          //    t |> t'
+          // -------------
+          // t.l |> t'.l
+          Some(Term::Field(
+            Box::new(term.reduce(ev.enter("E-RcdProj", 0))?),
+            label.clone(),
+          ))
+        } else {
+          match &**term {
+            // ------------------------------
+            // {l1 = v1, ..., ln = vn}.li |> vi
+            Term::Record(fields) => {
+              let value = field(fields, label)?;
+              Some(ev.contract("E-ProjRcd", || value.clone()))
+            }
+            _ => None,
+          }
+        }
+      }
+      //             t |> t'
+      // -----------------------------
+      // <l = t> as τ |> <l = t'> as τ
+      Term::Tag { label, term, typ } => Some(Term::Tag {
+        label: label.clone(),
+        term: Box::new(term.reduce(ev.enter("E-Variant", 0))?),
+        typ: typ.clone(),
+      }),
+      Term::VariantCase {
+        scrutinee,
+        branches,
+      } => {
+        if !scrutinee.is_value() {
+          //                  t |> t'
+          // -------------------------------------------
+          // case t of { ... } |> case t' of { ... }
+          Some(Term::VariantCase {
+            scrutinee: Box::new(scrutinee.reduce(ev.enter("E-Case", 0))?),
+            branches: branches.clone(),
+          })
+        } else {
+          match &**scrutinee {
+            // ------------------------------------------------------
+            // case (<li = v> as τ) of { ... | <li = xi> => ti | ... } |> ti[v/xi]
+            Term::Tag { label, term, .. } => branches
+              .iter()
+              .find(|branch| branch.label == *label)
+              .map(|branch| ev.contract("E-CaseVariant", || branch.body.instantiate(term))),
+            _ => None,
+          }
+        }
+      }
+      Term::Seq(first, second) => {
+        if !first.is_value() {
+          //      t1 |> t1'
+          // -------------------
+          // t1; t2 |> t1'; t2
+          Some(Term::Seq(
+            Box::new(first.reduce(ev.enter("E-Seq", 0))?),
+            second.clone(),
+          ))
+        } else {
+          // ----------
+          // (); t |> t
+          Some(ev.contract("E-SeqNext", || *second.clone()))
+        }
+      }
+      Term::Let { hint, value, body } if ev.strategy().is_lazy() => {
+        // ----------------------------
+        // let x = t1 in t2 |> t2[t1/x]
+        let rule = if value.is_value() {
+          "E-LetV"
+        } else {
+          "E-LetSubst"
+        };
+        Some(ev.bind_lazily(hint, value, body, rule, "E-LetAlloc"))
+      }
+      Term::Let { hint, value, body } => {
+        if !value.is_value() {
+          //              t1 |> t1'
+          // ------------------------------------
+          // let x = t1 in t2 |> let x = t1' in t2
+          Some(Term::Let {
+            hint: hint.clone(),
+            value: Box::new(value.reduce(ev.enter("E-Let", 0))?),
+            body: body.clone(),
+          })
+        } else {
+          // ------------------------
+          // let x = v in t |> t[v/x]
+          Some(ev.contract("E-LetV", || body.instantiate(value)))
+        }
+      }
+      Term::Fix(term) => {
+        if !term.is_value() {
+          //       t |> t'
+          // -----------------
+          // fix t |> fix t'
+          Some(Term::Fix(Box::new(term.reduce(ev.enter("E-Fix", 0))?)))
+        } else {
+          match &**term {
+            // -------------------------------------
+            // fix (λx: τ. t) |> t[fix (λx: τ. t)/x]
+            Term::Abs { body, .. } => Some(ev.contract("E-FixBeta", || body.instantiate(self))),
+            _ => None,
+          }
+        }
+      }
+      //          t |> t'
+      // -------------------------
+      // fold [τ] t |> fold [τ] t'
+      Term::Fold(typ, term) => Some(Term::Fold(
+        typ.clone(),
+        Box::new(term.reduce(ev.enter("E-Fold", 0))?),
+      )),
+      Term::Unfold(typ, term) => {
+        if !term.is_value() {
+          //           t |> t'
+          // -----------------------------
+          // unfold [τ] t |> unfold [τ] t'
+          Some(Term::Unfold(
+            typ.clone(),
+            Box::new(term.reduce(ev.enter("E-Unfold", 0))?),
+          ))
+        } else {
+          match &**term {
+            // ------------------------------
+            // unfold [σ] (fold [τ] v) |> v
+            Term::Fold(_, value) => Some(ev.contract("E-UnfoldFold", || *value.clone())),
+            _ => None,
+          }
+        }
+      }
+      _ => None,
+    }
+  }
+
+  pub fn eval(&self) -> Result<Term, EvalError> {
+    self.eval_with_limit(STEP_LIMIT)
+  }
+
+  // Evaluates the term, failing if it takes more than `limit` steps.
+  pub fn eval_with_limit(&self, limit: usize) -> Result<Term, EvalError> {
+    Evaluation::new(Strategy::CallByValue).eval(self, limit)
+  }
+
+  // Evaluates the closed term like `eval_with_limit`, recording every step.
+  pub fn trace_with_limit(&self, limit: usize) -> Result<Trace, EvalError> {
+    Evaluation::new(Strategy::CallByValue).trace(self, limit)
+  }
+}
+
+// Typechecks `term` and converts it to a closed term, substituting the
+// definitions of the prelude it refers to.
+pub fn compile(term: &ast::Term) -> Result<Term, TypecheckerError> {
+  let (term, _) = typechecker::elaborate(term)?;
+  let (ctx, definitions) = prelude()?;
+  let term = Term::from_ast(ctx, &term)?;
+  // Each definition only refers to the ones before it, so the innermost
+  // binding is substituted first.
+  Ok(
+    definitions
+      .iter()
+      .rev()
+      .fold(term, |term, definition| term.instantiate(definition)),
+  )
+}
+
+// The context binding the prelude definitions, and their terms in the IR. They
+// are only converted the first time.
+fn prelude() -> Result<&'static (Context, Vec<Term>), TypecheckerError> {
+  static PRELUDE: OnceLock<Result<(Context, Vec<Term>), TypecheckerError>> = OnceLock::new();
+  let prelude = PRELUDE.get_or_init(|| {
+    let mut ctx = Context::new();
+    let mut definitions = Vec::new();
+    for definition in typechecker::elaborate_prelude()? {
+      let typ = Type::from_ast(&ctx, &definition.typ)?;
+      definitions.push(Term::from_ast(&ctx, &definition.term)?);
+      ctx = ctx.assign(definition.name.clone(), typ);
+    }
+    Ok((ctx, definitions))
+  });
+  prelude.as_ref().map_err(Clone::clone)
+}
+
+pub fn eval(term: &ast::Term) -> Result<ast::Term, EvalError> {
+  Ok(compile(term)?.eval()?.to_ast(&Context::new()))
+}
+
+// Like `eval`, returning every step of the evaluation.
+pub fn trace(term: &ast::Term) -> Result<Trace, EvalError> {
+  compile(term)?.trace_with_limit(STEP_LIMIT)
+}
+
+// Like `eval`, reducing by `strategy`.
+pub fn eval_with(term: &ast::Term, strategy: Strategy) -> Result<ast::Term, EvalError> {
+  let value = Evaluation::new(strategy).eval(&compile(term)?, STEP_LIMIT)?;
+  Ok(value.to_ast(&Context::new()))
+}
+
+// Like `trace`, reducing by `strategy`.
+pub fn trace_with(term: &ast::Term, strategy: Strategy) -> Result<Trace, EvalError> {
+  Evaluation::new(strategy).trace(&compile(term)?, STEP_LIMIT)
+}
+
+fn free_type_vars(typ: &ast::Type, bound: &mut Vec<String>, free: &mut Vec<(String, Kind)>) {
+  match typ {
+    ast::Type::Int | ast::Type::Bool | ast::Type::Unit => (),
+    ast::Type::Arrow(left, right)
+    | ast::Type::Product(left, right)
+    | ast::Type::Sum(left, right) => {
+      free_type_vars(left, bound, free);
+      free_type_vars(right, bound, free);
+    }
+    ast::Type::Record(fields) | ast::Type::Variant(fields) => {
+      for (_, typ) in fields {
+        free_type_vars(typ, bound, free);
+      }
+    }
+    ast::Type::TypeVar(_) | ast::Type::App(..) => {
+      // A variable that is applied stands for an alias with that many parameters.
+      let (head, args) = spine(typ);
+      match head {
+        ast::Type::TypeVar(x) => {
+          if !bound.contains(x) && !free.iter().any(|(y, _)| y == x) {
+            free.push((x.clone(), alias_kind(args.len())));
+          }
+        }
+        _ => free_type_vars(head, bound, free),
+      }
+      for arg in args {
+        free_type_vars(arg, bound, free);
+      }
+    }
+    ast::Type::Forall { type_var, typ, .. }
+    | ast::Type::Exists { type_var, typ, .. }
+    | ast::Type::Mu { type_var, typ } => {
+      bound.push(type_var.clone());
+      free_type_vars(typ, bound, free);
+      bound.pop();
+    }
+  }
+}
+
+// Builds a context that binds every type variable occurring free in `types`.
+fn open_context(types: &[&ast::Type]) -> Context {
+  let mut free = Vec::new();
+  for typ in types {
+    free_type_vars(typ, &mut Vec::new(), &mut free);
+  }
+  Context {
+    terms: Vec::new(),
+    types: free,
+  }
+}
+
+// Compares two named types up to renaming of bound type variables.
+pub fn alpha_eq(a: &ast::Type, b: &ast::Type) -> bool {
+  let ctx = open_context(&[a, b]);
+  match (Type::from_ast(&ctx, a), Type::from_ast(&ctx, b)) {
+    (Ok(a), Ok(b)) => a.equiv(&b, ctx.type_depth()),
+    _ => false,
+  }
+}
+
+// Capture avoiding τ[σ/α] on named types.
+//
+// τ and σ must be well-formed in the same scope, which the typechecker
+// ensures before it substitutes.
+pub fn subst_type(type_var: &str, typ: &ast::Type, to: &ast::Type) -> ast::Type {
+  // α takes as many arguments as it is applied to in τ.
+  let mut in_typ = Vec::new();
+  free_type_vars(typ, &mut Vec::new(), &mut in_typ);
+  let kind = in_typ
+    .into_iter()
+    .find(|(x, _)| x == type_var)
+    .map_or(Kind::Star, |(_, kind)| kind);
+  // α itself is not free in the result, so binders in σ may keep its name.
+  let mut free = Vec::new();
+  free_type_vars(typ, &mut vec![String::from(type_var)], &mut free);
+  free_type_vars(to, &mut Vec::new(), &mut free);
+  let ctx = Context {
+    terms: Vec::new(),
+    types: free,
+  };
+  let body_ctx = ctx.assign_type(String::from(type_var), kind);
+  let typ = Type::from_ast(&body_ctx, typ)
+    .unwrap_or_else(|err| panic!("substituting into an ill-formed type: {}", err));
+  let to = Type::from_ast(&ctx, to)
+    .unwrap_or_else(|err| panic!("substituting an ill-formed type: {}", err));
+  typ.instantiate(&to).to_ast(&ctx)
+}
+
+// The type variables free in the body of an alias, which are bound wherever
+// it is defined.
+fn alias_context(params: &[String], alias: &ast::Type, types: &[&ast::Type]) -> (Context, Context) {
+  let mut free = Vec::new();
+  free_type_vars(alias, &mut params.to_vec(), &mut free);
+  for typ in types {
+    free_type_vars(typ, &mut Vec::new(), &mut free);
+  }
+  let ctx = Context {
+    terms: Vec::new(),
+    types: free,
+  };
+  let alias_ctx = params.iter().fold(ctx.clone(), |ctx, param| {
+    ctx.assign_type(param.clone(), Kind::Star)
+  });
+  (ctx, alias_ctx)
+}
+
+// Expands every use of the alias `name` with parameters `params` and body
+// `alias` in `typ`.
+pub fn expand_alias(
+  name: &str,
+  params: &[String],
+  alias: &ast::Type,
+  typ: &ast::Type,
+) -> Result<ast::Type, TypecheckerError> {
+  // `name` is bound around `typ`, so it is not one of its free variables.
+  let typ = ast::Type::Forall {
+    type_var: String::from(name),
+    kind: alias_kind(params.len()),
+    typ: Box::new(typ.clone()),
+  };
+  let (ctx, alias_ctx) = alias_context(params, alias, &[&typ]);
+  let alias = Type::from_ast(&alias_ctx, alias)?;
+  match Type::from_ast(&ctx, &typ)? {
+    Type::Forall { typ, .. } => {
+      Ok(nbe::expand_alias(&typ, &alias, params.len(), ctx.type_depth()).to_ast(&ctx))
+    }
+    _ => unreachable!(),
+  }
+}
+
+// Writes `typ` with the alias `name` wherever it has the shape of the alias
+// body, so that errors can show the types the way they were written.
+//
+// Fails when the free type variables of `alias` and `typ` cannot share a
+// scope, as when an alias declared inside the scope of `name` reuses the name
+// of a type variable the body of `name` applies differently.
+pub fn resugar(
+  name: &str,
+  params: &[String],
+  alias: &ast::Type,
+  typ: &ast::Type,
+) -> Result<ast::Type, TypecheckerError> {
+  // An alias for one of its parameters would match every type.
+  if let ast::Type::TypeVar(x) = alias {
+    if params.contains(x) {
+      return Ok(typ.clone());
+    }
+  }
+  let (ctx, alias_ctx) = alias_context(params, alias, &[typ]);
+  let alias = Type::from_ast(&alias_ctx, alias)?;
+  Ok(
+    Type::from_ast(&ctx, typ)?
+      .shift(1, 0)
+      .fold_alias(0, &alias, params.len())
+      .to_ast(&ctx.assign_type(String::from(name), alias_kind(params.len()))),
+  )
+}
+
+// Expands every use of the alias `name` with parameters `params` and body
+// `alias` in the types written in `term`.
+pub fn expand_alias_in_term(
+  name: &str,
+  params: &[String],
+  alias: &ast::Type,
+  term: &ast::Term,
+) -> Result<ast::Term, TypecheckerError> {
+  let typ = |typ: &ast::Type| expand_alias(name, params, alias, typ);
+  let go = |term: &ast::Term| Ok(Box::new(expand_alias_in_term(name, params, alias, term)?));
+  let mut free = Vec::new();
+  free_type_vars(alias, &mut params.to_vec(), &mut free);
+  // Expands under a binder of the type variable `bound`, an alias itself when
+  // it has `bound_params`. It is renamed first if the alias body mentions it.
+  let under = |bound: &String, bound_params: &[String], body: &ast::Term| {
+    if bound == name {
+      return Ok((bound.clone(), Box::new(body.clone())));
+    }
+    if !free.iter().any(|(x, _)| x == bound) {
+      return Ok((bound.clone(), go(body)?));
+    }
+    // A name occurs in a term exactly when expanding it changes the term.
+    let mut fresh = format!("{}'", bound);
+    while free.iter().any(|(x, _)| *x == fresh)
+      || expand_alias_in_term(&fresh, &[], &ast::Type::Unit, body).as_ref() != Ok(body)
+    {
+      fresh.push('\'');
+    }
+    let renamed = bound_params
+      .iter()
+      .fold(ast::Type::TypeVar(fresh.clone()), |function, param| {
+        ast::Type::App(
+          Box::new(function),
+          Box::new(ast::Type::TypeVar(param.clone())),
+        )
+      });
+    let body = expand_alias_in_term(bound, bound_params, &renamed, body)?;
+    Ok((fresh, go(&body)?))
+  };
+  Ok(match term {
+    ast::Term::Int(_) | ast::Term::Unit | ast::Term::Var(_) => term.clone(),
+    ast::Term::App(f, arg) => ast::Term::App(go(f)?, go(arg)?),
+    ast::Term::Abs {
+      param_name,
+      param_type,
+      body,
+    } => ast::Term::Abs {
+      param_name: param_name.clone(),
+      param_type: param_type.as_ref().map(typ).transpose()?,
+      body: go(body)?,
+    },
+    ast::Term::Ann(term, annotation) => ast::Term::Ann(go(term)?, typ(annotation)?),
+    ast::Term::UniversalAbs {
+      type_var: bound,
+      kind,
+      body,
+    } => {
+      let (bound, body) = under(bound, &[], body)?;
+      ast::Term::UniversalAbs {
+        type_var: bound,
+        kind: kind.clone(),
+        body,
+      }
+    }
+    ast::Term::UniversalApp(term, arg_typ) => ast::Term::UniversalApp(go(term)?, typ(arg_typ)?),
+    ast::Term::Pack {
+      witness,
+      term,
+      typ: package_typ,
+    } => ast::Term::Pack {
+      witness: typ(witness)?,
+      term: go(term)?,
+      typ: typ(package_typ)?,
+    },
+    ast::Term::Unpack {
+      type_var: bound,
+      var,
+      package,
+      body,
+    } => {
+      let (bound, body) = under(bound, &[], body)?;
+      ast::Term::Unpack {
+        type_var: bound,
+        var: var.clone(),
+        package: go(package)?,
+        body,
+      }
+    }
+    ast::Term::Pair(first, second) => ast::Term::Pair(go(first)?, go(second)?),
+    ast::Term::Proj(term, i) => ast::Term::Proj(go(term)?, *i),
+    ast::Term::Inl(term, sum) => ast::Term::Inl(go(term)?, typ(sum)?),
+    ast::Term::Inr(term, sum) => ast::Term::Inr(go(term)?, typ(sum)?),
+    ast::Term::Case {
+      scrutinee,
+      left_var,
+      left,
+      right_var,
+      right,
+    } => ast::Term::Case {
+      scrutinee: go(scrutinee)?,
+      left_var: left_var.clone(),
+      left: go(left)?,
+      right_var: right_var.clone(),
+      right: go(right)?,
+    },
+    ast::Term::Record(fields) => ast::Term::Record(
+      fields
+        .iter()
+        .map(|(label, term)| Ok((label.clone(), *go(term)?)))
+        .collect::<Result<_, TypecheckerError>>()?,
+    ),
+    ast::Term::Field(term, label) => ast::Term::Field(go(term)?, label.clone()),
+    ast::Term::Tag {
+      label,
+      term,
+      typ: variant,
+    } => ast::Term::Tag {
+      label: label.clone(),
+      term: go(term)?,
+      typ: typ(variant)?,
+    },
+    ast::Term::VariantCase {
+      scrutinee,
+      branches,
+    } => ast::Term::VariantCase {
+      scrutinee: go(scrutinee)?,
+      branches: branches
+        .iter()
+        .map(|branch| {
+          Ok(ast::Branch {
+            body: *go(&branch.body)?,
+            ..branch.clone()
+          })
+        })
+        .collect::<Result<_, TypecheckerError>>()?,
+    },
+    ast::Term::Seq(first, second) => ast::Term::Seq(go(first)?, go(second)?),
+    ast::Term::Let { name, value, body } => ast::Term::Let {
+      name: name.clone(),
+      value: go(value)?,
+      body: go(body)?,
+    },
+    ast::Term::TypeLet {
+      type_var: bound,
+      params: bound_params,
+      typ: bound_alias,
+      body,
+    } => {
+      // The parameters are bound in the alias body, which is expanded as if
+      // they were quantified over.
+      let quantified = bound_params
+        .iter()
+        .rev()
+        .fold(bound_alias.clone(), |typ, param| ast::Type::Forall {
+          type_var: param.clone(),
+          kind: Kind::Star,
+          typ: Box::new(typ),
+        });
+      let mut bound_alias = typ(&quantified)?;
+      let mut renamed_params = Vec::new();
+      while let (ast::Type::Forall { type_var, typ, .. }, true) =
+        (&bound_alias, renamed_params.len() < bound_params.len())
+      {
+        renamed_params.push(type_var.clone());
+        bound_alias = *typ.clone();
+      }
+      let (bound, body) = under(bound, bound_params, body)?;
+      ast::Term::TypeLet {
+        type_var: bound,
+        params: renamed_params,
+        typ: bound_alias,
+        body,
+      }
+    }
+    ast::Term::Fix(term) => ast::Term::Fix(go(term)?),
+    ast::Term::Fold(recursive, term) => ast::Term::Fold(typ(recursive)?, go(term)?),
+    ast::Term::Unfold(recursive, term) => ast::Term::Unfold(typ(recursive)?, go(term)?),
+  })
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn from_ast(input: &str) -> Term {
+    Term::from_ast(&Context::new(), &crate::parse(input).unwrap()).unwrap()
+  }
+
+  fn parse_type(input: &str) -> ast::Type {
+    crate::parse_type(input).unwrap()
+  }
+
+  fn eval(input: &str) -> Result<ast::Term, EvalError> {
+    super::eval(&crate::parse(input).unwrap())
+  }
+
+  #[test]
+  fn conversions_round_trip() {
+    for input in [
+      "λx: Int. λy: Int. x",
+      "ΛX: *. λx: X. x",
+      "λp: ∃X: *. X × (X -> Int). unpack p as [Y, q] in q.2 q.1",
+      "λs: Int + Unit. case s of inl x => x | inr u => 0",
+      "let f = λx: Int. x in fold [μL. Unit + L] (inl () as Unit + (μL. Unit + L))",
+    ] {
+      let term = crate::parse(input).unwrap();
+      assert_eq!(from_ast(input).to_ast(&Context::new()), term);
+    }
+    // Terms that only differ in the names of their binders are equal.
+    assert_eq!(from_ast("ΛX: *. λx: X. x"), from_ast("ΛY: *. λy: Y. y"));
+    assert_eq!(
+      Term::from_ast(&Context::new(), &crate::parse("λx: Int. y").unwrap()),
+      Err(TypecheckerError::UndefinedVariable(String::from("y")))
+    );
+    assert_eq!(
+      Term::from_ast(&Context::new(), &crate::parse("λx: Y. x").unwrap()),
+      Err(TypecheckerError::UndefinedTypeVariable(String::from("Y")))
+    );
+  }
+
+  #[test]
+  fn readback_picks_fresh_names() {
+    // The inner binders are renamed so that they do not capture the
+    // variables that refer to the outer ones.
+    assert_eq!(
+      from_ast("λx: Int. λx: Int. x")
+        .to_ast(&Context::new())
+        .to_string(),
+      "λx: Int. λx': Int. x'"
+    );
+    assert_eq!(
+      from_ast("ΛX: *. λx: X. ΛX: *. λy: X. x")
+        .to_ast(&Context::new())
+        .to_string(),
+      "ΛX: *. λx: X. ΛX': *. λy: X'. x"
+    );
+    let ctx = Context::new().assign(String::from("x"), Type::Int);
+    let term = Term::Abs {
+      hint: Hint(String::from("x")),
+      param_type: Type::Int,
+      body: Box::new(Term::Var(1)),
+    };
+    assert_eq!(term.to_ast(&ctx).to_string(), "λx': Int. x");
+  }
+
+  #[test]
+  fn shadowed_binders_are_typed_by_position() {
+    let type_of = |input: &str| {
+      let ctx = Context::new();
+      from_ast(input).type_of(&ctx).map(|typ| typ.to_ast(&ctx))
+    };
+    assert_eq!(
+      type_of("ΛX: *. λx: X. ΛX: *. λy: X. x"),
+      Ok(parse_type("∀X: *. X -> (∀X': *. X' -> X)"))
+    );
+    assert_eq!(
+      type_of("(ΛX: *. λx: X. ΛX: *. λy: X. x) [Int] 1 [Bool -> Bool] (λb: Bool. b)"),
+      Ok(ast::Type::Int)
+    );
+    assert_eq!(
+      type_of("λp: ∃Y: *. Y. unpack p as [X, x] in ΛX: *. λy: X. x"),
+      Err(TypecheckerError::EscapingTypeVariable {
+        type_var: String::from("X"),
+        typ: parse_type("∀X': *. X' -> X"),
+      })
+    );
+  }
+
+  #[test]
+  fn substitution_avoids_capture() {
+    // ∀Y. X -> Y with X := Y renames the bound Y.
+    assert_eq!(
+      subst_type("X", &parse_type("∀Y: *. X -> Y"), &parse_type("Y")),
+      parse_type("∀Y': *. Y -> Y'")
+    );
+    assert_eq!(
+      subst_type("X", &parse_type("∀X: *. X"), &parse_type("Int")),
+      parse_type("∀X: *. X")
+    );
+    assert_eq!(
+      subst_type("X", &parse_type("X × (μL. Unit + X × L)"), &parse_type("L")),
+      parse_type("L × (μL'. Unit + L × L')")
+    );
+    // On the IR, the argument is shifted under the binders it is moved into.
+    let body = Type::Forall {
+      hint: Hint(String::from("Y")),
+      kind: Kind::Star,
+      typ: Box::new(Type::Arrow(
+        Box::new(Type::TypeVar(1)),
+        Box::new(Type::TypeVar(0)),
+      )),
+    };
+    assert_eq!(
+      body.instantiate(&Type::TypeVar(0)),
+      Type::Forall {
+        hint: Hint(String::from("Y")),
+        kind: Kind::Star,
+        typ: Box::new(Type::Arrow(
+          Box::new(Type::TypeVar(1)),
+          Box::new(Type::TypeVar(0)),
+        )),
+      }
+    );
+  }
+
+  #[test]
+  fn packages_are_opened_by_unpack() {
+    let counter = "∃C: *. {get: C -> Int, new: C}";
+    let package = format!("pack [Int, {{new = 5, get = λx: Int. x}}] as {}", counter);
+    assert_eq!(
+      eval(&format!("unpack {} as [C, c] in c.get c.new", package)),
+      Ok(ast::Term::Int(5))
+    );
+    // A package is a value and keeps its hidden type.
+    assert_eq!(
+      eval(&format!("({}, 1).1", package)).map(|value| value.to_string()),
+      Ok(package)
+    );
+  }
+
+  #[test]
+  fn products_and_sums_evaluate() {
+    assert_eq!(eval("(1, (λx: Int. x) 2).2"), Ok(ast::Term::Int(2)));
+    let either = "λs: Int + Int × Int. case s of inl x => x | inr p => p.2";
+    assert_eq!(
+      eval(&format!("({}) (inr (1, 2) as Int + Int × Int)", either)),
+      Ok(ast::Term::Int(2))
+    );
+    // The payload of an injection is evaluated.
+    assert_eq!(
+      eval("inl (λx: Int. x) 1 as Int + Unit").map(|value| value.to_string()),
+      Ok(String::from("inl 1 as Int + Unit"))
+    );
+  }
+
+  #[test]
+  fn records_and_variants_evaluate() {
+    assert_eq!(eval("{x = (λx: Int. x) 1, y = 2}.x"), Ok(ast::Term::Int(1)));
+    let option = "<some: Int, none: Unit>";
+    let get = format!(
+      "λo: {}. case o of {{ <none = u> => 0 | <some = n> => n }}",
+      option
+    );
+    assert_eq!(
+      eval(&format!("({}) (<some = 3> as {})", get, option)),
+      Ok(ast::Term::Int(3))
+    );
+    assert_eq!(
+      eval(&format!("({}) (<none = ()> as {})", get, option)),
+      Ok(ast::Term::Int(0))
+    );
+  }
+
+  #[test]
+  fn sequences_evaluate_from_left_to_right() {
+    assert_eq!(eval("(); 1"), Ok(ast::Term::Int(1)));
+    assert_eq!(
+      eval("(λf: Int -> Unit. f 1; f 2; 3) (λx. ())"),
+      Ok(ast::Term::Int(3))
+    );
+    let trace = super::trace(&crate::parse("(λx: Int. ()) 1; 2").unwrap()).unwrap();
+    let rules: Vec<_> = trace.steps.iter().map(|step| step.rule()).collect();
+    assert_eq!(rules, vec!["E-AppAbs", "E-SeqNext"]);
+  }
+
+  #[test]
+  fn recursion_unfolds_fix() {
+    let double = format!(
+      "let rec double: {nat} -> {nat} = λn. isZero n [Unit -> {nat}] (λu. zero) (λu. succ (succ (double (pred n)))) () in double",
+      nat = format!("({})", crate::prelude::NAT)
+    );
+    let two = "succ (succ zero)";
+    assert_eq!(
+      eval(&format!(
+        "equal (({}) ({})) (succ (succ ({}))) [Int] 1 0",
+        double, two, two
+      )),
+      Ok(ast::Term::Int(1))
+    );
+    let term = compile(&crate::parse("fix (λx: Int. x)").unwrap()).unwrap();
+    assert_eq!(
+      term.eval_with_limit(100),
+      Err(EvalError::StepLimitExceeded(100))
+    );
+  }
+
+  #[test]
+  fn folded_values_are_unfolded() {
+    let list = "μL. <nil: Unit, cons: Int × L>";
+    let unfolded = format!("<nil: Unit, cons: Int × ({})>", list);
+    let nil = format!("fold [{}] (<nil = ()> as {})", list, unfolded);
+    let cons = |head: &str, tail: &str| {
+      format!(
+        "fold [{}] (<cons = ({}, {})> as {})",
+        list, head, tail, unfolded
+      )
+    };
+    let last = format!(
+      "let rec last: ({l}) -> Int = λxs. case unfold [{l}] xs of {{ <nil = u> => 0 | <cons = c> => case unfold [{l}] c.2 of {{ <nil = u> => c.1 | <cons = d> => last c.2 }} }} in last",
+      l = list
+    );
+    assert_eq!(
+      eval(&format!(
+        "({}) ({})",
+        last,
+        cons("1", &cons("2", &cons("3", &nil)))
+      )),
+      Ok(ast::Term::Int(3))
+    );
+    assert_eq!(
+      eval(&format!("({}) ({})", last, nil)),
+      Ok(ast::Term::Int(0))
+    );
+  }
+
+  #[test]
+  fn let_bindings_evaluate_their_bound_term_first() {
+    assert_eq!(eval("let x = (1, ()) in x.1"), Ok(ast::Term::Int(1)));
+    assert_eq!(
+      eval("let f = λx: Int. (x, x) in let x = 2 in f x"),
+      Ok(ast::Term::Pair(
+        Box::new(ast::Term::Int(2)),
+        Box::new(ast::Term::Int(2))
+      ))
+    );
+  }
+
+  #[test]
+  fn traces_record_each_step() {
+    let input = "(λx: Int. x) ((ΛX: *. λy: X. y) [Int] 1)";
+    let trace = super::trace(&crate::parse(input).unwrap()).unwrap();
+    let steps: Vec<_> = trace
+      .steps
+      .iter()
+      .map(|step| (step.redex_text(), step.path.clone(), step.rules.join(", ")))
+      .collect();
+    assert_eq!(
+      steps,
+      vec![
+        (
+          "(ΛX: *. λy: X. y) [Int]",
+          vec![1, 0],
+          String::from("E-App2, E-App1, E-TappTabs")
+        ),
+        (
+          "((λy: Int. y) 1)",
+          vec![1],
+          String::from("E-App2, E-AppAbs")
+        ),
+        ("(λx: Int. x) 1", vec![], String::from("E-AppAbs")),
+      ]
+    );
+    assert_eq!(trace.steps[0].term, input);
+    assert_eq!(trace.result, "1");
+    let input = "let p = ({a = 1}, ()) in p.1.a";
+    let trace = super::trace(&crate::parse(input).unwrap()).unwrap();
+    let rules: Vec<_> = trace.steps.iter().map(|step| step.rule()).collect();
+    assert_eq!(rules, vec!["E-LetV", "E-PairBeta1", "E-ProjRcd"]);
+    assert_eq!(trace.steps[1].redex_text(), "({a = 1}, ()).1");
+  }
+
+  #[test]
+  fn named_type_substitution_avoids_capture() {
+    assert_eq!(
+      subst_type("X", &parse_type("∀Y: *. X -> Y"), &parse_type("Y")),
+      parse_type("∀Y': *. Y -> Y'")
+    );
+    // A variable applied to arguments is substituted by a type constructor.
+    assert_eq!(
+      subst_type("F", &parse_type("F Int -> F Unit"), &parse_type("G")),
+      parse_type("G Int -> G Unit")
+    );
+  }
+
+  #[test]
+  fn resugaring_fails_when_the_scopes_disagree() {
+    let alias = parse_type("F -> Int");
+    assert_eq!(
+      resugar("P", &[], &alias, &parse_type("(F -> Int) × Unit")),
+      Ok(parse_type("P × Unit"))
+    );
+    assert_eq!(
+      resugar("P", &[], &alias, &parse_type("F Int")),
+      Err(TypecheckerError::WrongNumberOfTypeArguments {
+        name: String::from("F"),
+        expected: 0,
+        got: 1,
+      })
+    );
+  }
+}
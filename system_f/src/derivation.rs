@@ -0,0 +1,538 @@
+use crate::ast::{Term, Type};
+use crate::debruijn;
+use crate::spans::subterms;
+use crate::typechecker::{self, prelude_context, TypecheckerError, TypingContext};
+use std::fmt;
+
+pub use crate::typechecker::Binding;
+
+// A typing derivation: the judgement Γ ⊢ t : τ, the rule that concludes it,
+// and the derivations of its premises.
+//
+// Derivations are built for the elaborated term, where every λ carries its
+// parameter type and every type argument is applied explicitly, so each node
+// is an instance of one of the declarative rules in `typechecker.rs`. The
+// premises are in the order of the subterms they type. Γ lists the variables
+// bound inside the term; the prelude is left implicit.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Derivation {
+  pub rule: &'static str,
+  pub context: Vec<Binding>,
+  pub term: Term,
+  pub typ: Type,
+  pub premises: Vec<Derivation>,
+}
+
+// The derivation of the type of `term`, with the prelude in scope.
+pub fn derive(term: &Term) -> Result<Derivation, TypecheckerError> {
+  derive_in(&prelude_context(), term)
+}
+
+// Like `derive`, for a term whose free variables are typed by `ctx`.
+pub fn derive_in(ctx: &TypingContext, term: &Term) -> Result<Derivation, TypecheckerError> {
+  let (elaborated, _) = typechecker::elaborate_in(ctx, term)?;
+  derive_elaborated(ctx, &[], &elaborated)
+}
+
+// Builds the derivation in a single pass over the elaborated term: the type of
+// each node follows from the types of its premises by the rule that concludes
+// it.
+fn derive_elaborated(
+  ctx: &TypingContext,
+  context: &[Binding],
+  term: &Term,
+) -> Result<Derivation, TypecheckerError> {
+  let mut premises: Vec<Derivation> = Vec::new();
+  for (i, subterm) in subterms(term).into_iter().enumerate() {
+    let (mut ctx, mut context) = (ctx.clone(), context.to_vec());
+    for binding in bindings(term, i, &premises) {
+      ctx = match &binding {
+        Binding::Var(x, typ) => ctx.assign(x.clone(), typ.clone()),
+        Binding::TypeVar(type_var, kind) => ctx.assign_type(type_var.clone(), kind.clone()),
+      };
+      context.push(binding);
+    }
+    premises.push(derive_elaborated(&ctx, &context, subterm)?);
+  }
+  Ok(Derivation {
+    rule: rule(term),
+    context: context.to_vec(),
+    term: term.clone(),
+    typ: conclusion(ctx, term, &premises)?,
+    premises,
+  })
+}
+
+// The type the rule for `term` concludes, given the derivations of its
+// premises. The term has already been checked, so the premises have the
+// shapes the rule asks for.
+fn conclusion(
+  ctx: &TypingContext,
+  term: &Term,
+  premises: &[Derivation],
+) -> Result<Type, TypecheckerError> {
+  let premise = |i: usize| premises[i].typ.clone();
+  let unexpected = |expected: &str, got: &Type| TypecheckerError::UnexpectedType {
+    expected: String::from(expected),
+    got: got.clone(),
+  };
+  Ok(match term {
+    Term::Int(_) => Type::Int,
+    Term::Unit => Type::Unit,
+    Term::Var(x) => match ctx.get(x) {
+      Some(typ) => typ.clone(),
+      None => return Err(TypecheckerError::UndefinedVariable(x.clone())),
+    },
+    Term::App(..) => match &premises[0].typ {
+      Type::Arrow(_, return_type) => (**return_type).clone(),
+      typ => return Err(unexpected("function type", typ)),
+    },
+    Term::Abs {
+      param_type: Some(param_type),
+      ..
+    } => Type::Arrow(Box::new(param_type.clone()), Box::new(premise(0))),
+    Term::Abs { .. } => return Err(TypecheckerError::MissingAnnotation(Box::new(term.clone()))),
+    Term::Ann(_, typ)
+    | Term::Pack { typ, .. }
+    | Term::Inl(_, typ)
+    | Term::Inr(_, typ)
+    | Term::Tag { typ, .. }
+    | Term::Fold(typ, _) => typ.clone(),
+    Term::UniversalAbs { type_var, kind, .. } => Type::Forall {
+      type_var: type_var.clone(),
+      kind: kind.clone(),
+      typ: Box::new(premise(0)),
+    },
+    Term::UniversalApp(_, arg_typ) => match &premises[0].typ {
+      Type::Forall { type_var, typ, .. } => debruijn::subst_type(type_var, typ, arg_typ),
+      typ => return Err(unexpected("universal type", typ)),
+    },
+    Term::Pair(..) => Type::Product(Box::new(premise(0)), Box::new(premise(1))),
+    Term::Proj(_, i) => match (&premises[0].typ, i) {
+      (Type::Product(first, _), 1) => (**first).clone(),
+      (Type::Product(_, second), 2) => (**second).clone(),
+      (typ, _) => return Err(unexpected("pair type", typ)),
+    },
+    Term::Record(fields) => Type::Record(
+      fields
+        .iter()
+        .zip(premises)
+        .map(|((label, _), premise)| (label.clone(), premise.typ.clone()))
+        .collect(),
+    ),
+    Term::Field(_, label) => match &premises[0].typ {
+      Type::Record(fields) => match debruijn::field(fields, label) {
+        Some(typ) => typ.clone(),
+        None => return Err(unexpected("record type", &premises[0].typ)),
+      },
+      typ => return Err(unexpected("record type", typ)),
+    },
+    // Every branch has the type of the case, and the body of a let, an unpack
+    // or a sequence has the type of the whole.
+    Term::Case { .. }
+    | Term::VariantCase { .. }
+    | Term::Seq(..)
+    | Term::Let { .. }
+    | Term::Unpack { .. } => premise(1),
+    Term::TypeLet { .. } => premise(0),
+    Term::Fix(_) => match &premises[0].typ {
+      Type::Arrow(_, return_type) => (**return_type).clone(),
+      typ => return Err(unexpected("function type", typ)),
+    },
+    Term::Unfold(typ, _) => match typ {
+      Type::Mu {
+        type_var,
+        typ: body,
+      } => debruijn::subst_type(type_var, body, typ),
+      typ => return Err(unexpected("recursive type", typ)),
+    },
+  })
+}
+
+// What the premise for the subterm `i` of `term` adds to Γ, given the
+// derivations of the premises before it.
+fn bindings(term: &Term, i: usize, premises: &[Derivation]) -> Vec<Binding> {
+  match (term, i) {
+    (
+      Term::Abs {
+        param_name,
+        param_type: Some(typ),
+        ..
+      },
+      0,
+    ) => vec![Binding::Var(param_name.clone(), typ.clone())],
+    (Term::UniversalAbs { type_var, kind, .. }, 0) => {
+      vec![Binding::TypeVar(type_var.clone(), kind.clone())]
+    }
+    (Term::Let { name, .. }, 1) => vec![Binding::Var(name.clone(), premises[0].typ.clone())],
+    (Term::Unpack { type_var, var, .. }, 1) => match &premises[0].typ {
+      Type::Exists {
+        type_var: bound_type_var,
+        kind,
+        typ,
+      } => vec![
+        Binding::TypeVar(type_var.clone(), kind.clone()),
+        Binding::Var(
+          var.clone(),
+          debruijn::subst_type(bound_type_var, typ, &Type::TypeVar(type_var.clone())),
+        ),
+      ],
+      _ => Vec::new(),
+    },
+    (Term::Case { left_var, .. }, 1) => match &premises[0].typ {
+      Type::Sum(left, _) => vec![Binding::Var(left_var.clone(), (**left).clone())],
+      _ => Vec::new(),
+    },
+    (Term::Case { right_var, .. }, 2) => match &premises[0].typ {
+      Type::Sum(_, right) => vec![Binding::Var(right_var.clone(), (**right).clone())],
+      _ => Vec::new(),
+    },
+    (Term::VariantCase { branches, .. }, i) if i > 0 => match &premises[0].typ {
+      Type::Variant(fields) => {
+        let branch = &branches[i - 1];
+        debruijn::field(fields, &branch.label)
+          .map(|typ| vec![Binding::Var(branch.var.clone(), typ.clone())])
+          .unwrap_or_default()
+      }
+      _ => Vec::new(),
+    },
+    _ => Vec::new(),
+  }
+}
+
+fn rule(term: &Term) -> &'static str {
+  match term {
+    Term::Int(_) => "T-Int",
+    Term::Unit => "T-Unit",
+    Term::Var(_) => "T-Var",
+    Term::App(..) => "T-App",
+    Term::Abs { .. } => "T-Abs",
+    Term::Ann(..) => "T-Ann",
+    Term::UniversalAbs { .. } => "T-TAbs",
+    Term::UniversalApp(..) => "T-TApp",
+    Term::Pack { .. } => "T-Pack",
+    Term::Unpack { .. } => "T-Unpack",
+    Term::Pair(..) => "T-Pair",
+    Term::Proj(_, 1) => "T-Proj1",
+    Term::Proj(..) => "T-Proj2",
+    Term::Inl(..) => "T-Inl",
+    Term::Inr(..) => "T-Inr",
+    Term::Case { .. } => "T-Case",
+    Term::Record(_) => "T-Rcd",
+    Term::Field(..) => "T-RcdProj",
+    Term::Tag { .. } => "T-Variant",
+    Term::VariantCase { .. } => "T-VariantCase",
+    Term::Seq(..) => "T-Seq",
+    Term::Let { .. } => "T-Let",
+    Term::TypeLet { .. } => "T-TypeLet",
+    Term::Fix(_) => "T-Fix",
+    Term::Fold(..) => "T-Fold",
+    Term::Unfold(..) => "T-Unfold",
+  }
+}
+
+impl Derivation {
+  // Γ ⊢ t : τ
+  pub fn judgement(&self) -> String {
+    let context: Vec<String> = self.context.iter().map(Binding::to_string).collect();
+    match context.as_slice() {
+      [] => format!("⊢ {} : {}", self.term, self.typ),
+      _ => format!("{} ⊢ {} : {}", context.join(", "), self.term, self.typ),
+    }
+  }
+
+  // The derivation as a proof tree, with the premises of each rule side by
+  // side above its conclusion and the rule name next to the line.
+  //
+  //     ---------------- T-Var
+  //     x: Int ⊢ x : Int
+  //   ------------------------- T-Abs
+  //   ⊢ λx: Int. x : Int -> Int
+  pub fn render_text(&self) -> String {
+    let lines: Vec<String> = self
+      .layout()
+      .iter()
+      .map(|line| String::from(line.trim_end()))
+      .collect();
+    lines.join("\n")
+  }
+
+  // The lines of the proof tree, all padded to the same width.
+  fn layout(&self) -> Vec<String> {
+    let premises: Vec<Vec<String>> = self.premises.iter().map(Derivation::layout).collect();
+    let height = premises.iter().map(Vec::len).max().unwrap_or(0);
+    let mut rows = vec![String::new(); height];
+    for (i, premise) in premises.iter().enumerate() {
+      let width = width(&premise[0]);
+      let padding = height - premise.len();
+      for (j, row) in rows.iter_mut().enumerate() {
+        if i > 0 {
+          row.push_str("    ");
+        }
+        match j.checked_sub(padding) {
+          Some(j) => row.push_str(&premise[j]),
+          None => row.push_str(&" ".repeat(width)),
+        }
+      }
+    }
+    let conclusion = self.judgement();
+    let rule_width = rows
+      .first()
+      .map_or(0, |row| width(row))
+      .max(width(&conclusion));
+    let label = format!(" {}", self.rule);
+    let total = rule_width + width(&label);
+    let mut lines: Vec<String> = rows
+      .iter()
+      .map(|row| center(row, rule_width, total))
+      .collect();
+    lines.push(format!("{}{}", "-".repeat(rule_width), label));
+    lines.push(center(&conclusion, rule_width, total));
+    lines
+  }
+
+  // The derivation as the source of a `bussproofs` proof tree.
+  pub fn render_latex(&self) -> String {
+    let mut lines = vec![String::from("\\begin{prooftree}")];
+    self.latex_lines(&mut lines);
+    lines.push(String::from("\\end{prooftree}"));
+    lines.join("\n")
+  }
+
+  fn latex_lines(&self, lines: &mut Vec<String>) {
+    if self.premises.is_empty() {
+      lines.push(String::from("\\AxiomC{}"));
+    }
+    for premise in &self.premises {
+      premise.latex_lines(lines);
+    }
+    // bussproofs has no inference with more than five premises, so the last
+    // ones are first grouped, without a line, into a single premise.
+    let mut count = self.premises.len().max(1);
+    while count > 5 {
+      let group = (count - 4).min(5);
+      lines.push(String::from("\\noLine"));
+      lines.push(format!("\\{}InfC{{}}", INFERENCES[group - 1]));
+      count -= group - 1;
+    }
+    lines.push(format!("\\RightLabel{{\\scriptsize {}}}", self.rule));
+    lines.push(format!(
+      "\\{}InfC{{${}$}}",
+      INFERENCES[count - 1],
+      latex(&self.judgement())
+    ));
+  }
+}
+
+impl fmt::Display for Derivation {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{}", self.render_text())
+  }
+}
+
+const INFERENCES: [&str; 5] = ["Unary", "Binary", "Trinary", "Quaternary", "Quinary"];
+
+fn width(text: &str) -> usize {
+  text.chars().count()
+}
+
+// `text` centered over a rule of width `rule_width`, padded to `total`.
+fn center(text: &str, rule_width: usize, total: usize) -> String {
+  let left = (rule_width - width(text)) / 2;
+  format!(
+    "{}{}{}",
+    " ".repeat(left),
+    text,
+    " ".repeat(total - left - width(text))
+  )
+}
+
+// A judgement as LaTeX math: names of more than one letter are set upright,
+// spaces are kept, and the symbols of the syntax become math symbols.
+fn latex(text: &str) -> String {
+  let mut out = String::new();
+  let mut chars = text.chars().peekable();
+  while let Some(c) = chars.next() {
+    if c.is_ascii_alphanumeric() || c == '_' {
+      let mut name = String::from(c);
+      while let Some(&c) = chars
+        .peek()
+        .filter(|c| c.is_ascii_alphanumeric() || **c == '_')
+      {
+        name.push(c);
+        chars.next();
+      }
+      let escaped = name.replace('_', "\\_");
+      if name.chars().count() > 1 && !name.chars().all(|c| c.is_ascii_digit()) {
+        out.push_str(&format!("\\mathsf{{{}}}", escaped));
+      } else {
+        out.push_str(&escaped);
+      }
+      continue;
+    }
+    let symbol = match c {
+      '-' if chars.peek() == Some(&'>') => {
+        chars.next();
+        "\\to "
+      }
+      '=' if chars.peek() == Some(&'>') => {
+        chars.next();
+        "\\Rightarrow "
+      }
+      ' ' => "\\ ",
+      '{' => "\\{",
+      '}' => "\\}",
+      '<' => "\\langle ",
+      '>' => "\\rangle ",
+      '|' => "\\mid ",
+      '*' => "\\ast ",
+      '&' | '%' | '$' | '#' => {
+        out.push('\\');
+        out.push(c);
+        continue;
+      }
+      'λ' => "\\lambda ",
+      'Λ' => "\\Lambda ",
+      '∀' => "\\forall ",
+      '∃' => "\\exists ",
+      'μ' => "\\mu ",
+      '×' => "\\times ",
+      '⊢' => "\\vdash ",
+      _ => {
+        out.push(c);
+        continue;
+      }
+    };
+    out.push_str(symbol);
+  }
+  out
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::ast::Kind;
+  use crate::parse;
+
+  fn rules(derivation: &Derivation) -> Vec<&'static str> {
+    std::iter::once(derivation.rule)
+      .chain(derivation.premises.iter().flat_map(rules))
+      .collect()
+  }
+
+  #[test]
+  fn derivations_follow_the_rules() {
+    let term = parse("(ΛX: *. λx: X. x) [Int] 1").unwrap();
+    let derivation = derive(&term).unwrap();
+    assert_eq!(
+      rules(&derivation),
+      vec!["T-App", "T-TApp", "T-TAbs", "T-Abs", "T-Var", "T-Int"]
+    );
+    assert_eq!(derivation.typ, Type::Int);
+    let var = &derivation.premises[0].premises[0].premises[0].premises[0];
+    assert_eq!(var.judgement(), "X: *, x: X ⊢ x : X");
+    // Implicit type arguments appear as T-TApp nodes.
+    let term = parse("let id = ΛX: *. λx: X. x in id 1").unwrap();
+    let derivation = derive(&term).unwrap();
+    assert_eq!(derivation.premises[1].rule, "T-App");
+    assert_eq!(derivation.premises[1].premises[0].rule, "T-TApp");
+    assert_eq!(
+      derivation.premises[1].premises[0].premises[0].judgement(),
+      "id: ∀X: *. X -> X ⊢ id : ∀X: *. X -> X"
+    );
+  }
+
+  #[test]
+  fn contexts_bind_pattern_variables() {
+    let term = parse("unpack pack [Int, 1] as ∃X: *. X as [Y, y] in case inl y as Y + Int of inl a => () | inr b => ()").unwrap();
+    let derivation = derive(&term).unwrap();
+    let case = &derivation.premises[1];
+    assert_eq!(
+      case.context,
+      vec![
+        Binding::TypeVar(String::from("Y"), Kind::Star),
+        Binding::Var(String::from("y"), Type::TypeVar(String::from("Y"))),
+      ]
+    );
+    assert_eq!(
+      case.premises[2].context[2],
+      Binding::Var(String::from("b"), Type::Int)
+    );
+    assert!(derive(&parse("λx: Int. y").unwrap()).is_err());
+  }
+
+  #[test]
+  fn derivations_render_as_proof_trees() {
+    let derivation = derive(&parse("λx: Int. λy: Unit. x").unwrap()).unwrap();
+    assert_eq!(
+      derivation.render_text(),
+      [
+        "  ------------------------- T-Var",
+        "  x: Int, y: Unit ⊢ x : Int",
+        " ---------------------------------- T-Abs",
+        " x: Int ⊢ λy: Unit. x : Unit -> Int",
+        "------------------------------------------- T-Abs",
+        "⊢ λx: Int. λy: Unit. x : Int -> Unit -> Int",
+      ]
+      .join("\n")
+    );
+    let derivation = derive(&parse("(λx: Int. x) 1").unwrap()).unwrap();
+    assert_eq!(
+      derivation.render_latex(),
+      [
+        "\\begin{prooftree}",
+        "\\AxiomC{}",
+        "\\RightLabel{\\scriptsize T-Var}",
+        "\\UnaryInfC{$x:\\ \\mathsf{Int}\\ \\vdash \\ x\\ :\\ \\mathsf{Int}$}",
+        "\\RightLabel{\\scriptsize T-Abs}",
+        "\\UnaryInfC{$\\vdash \\ \\lambda x:\\ \\mathsf{Int}.\\ x\\ :\\ \\mathsf{Int}\\ \\to \\ \\mathsf{Int}$}",
+        "\\AxiomC{}",
+        "\\RightLabel{\\scriptsize T-Int}",
+        "\\UnaryInfC{$\\vdash \\ 1\\ :\\ \\mathsf{Int}$}",
+        "\\RightLabel{\\scriptsize T-App}",
+        "\\BinaryInfC{$\\vdash \\ (\\lambda x:\\ \\mathsf{Int}.\\ x)\\ 1\\ :\\ \\mathsf{Int}$}",
+        "\\end{prooftree}",
+      ]
+      .join("\n")
+    );
+    // The seven fields are typed by the four first premises and a group of
+    // the last three.
+    let derivation = derive(&parse("{a = 1, b = 2, c = 3, d = 4, e = 5, f = 6, g = 7}").unwrap());
+    let latex = derivation.unwrap().render_latex();
+    assert_eq!(latex.matches("\\noLine\n\\TrinaryInfC{}").count(), 1);
+    assert!(latex.contains("\\RightLabel{\\scriptsize T-Rcd}\n\\QuinaryInfC"));
+  }
+  #[test]
+  fn nodes_have_the_types_the_checker_gives_them() {
+    fn assert_agrees(derivation: &Derivation) {
+      let ctx = derivation
+        .context
+        .iter()
+        .fold(prelude_context(), |ctx, binding| match binding {
+          Binding::Var(x, typ) => ctx.assign(x.clone(), typ.clone()),
+          Binding::TypeVar(type_var, kind) => ctx.assign_type(type_var.clone(), kind.clone()),
+        });
+      let typ = typechecker::infer_in(&ctx, &derivation.term).unwrap();
+      assert!(
+        debruijn::alpha_eq(&typ, &derivation.typ),
+        "{}",
+        derivation.judgement()
+      );
+      derivation.premises.iter().for_each(assert_agrees);
+    }
+    for input in [
+      "let r = {a = (1, ()), b = λx: Int. x} in r.b r.a.1",
+      "unpack pack [Int, (1, λx: Int. x)] as ∃X: *. X × (X -> Int) as [Y, p] in p.2 p.1",
+      "case <some = 1> as <some: Int, none: Unit> of { <some = n> => n | <none = u> => 0 }",
+      "unfold [μL. Unit + L] (fold [μL. Unit + L] (inl () as Unit + (μL. Unit + L)))",
+      "(); fix (λf: Int -> Int. λn: Int. n) 1",
+      "map [Int] [Int] (λx. x) (cons 1 (nil [Int]))",
+    ] {
+      assert_agrees(&derive(&parse(input).unwrap()).unwrap());
+    }
+    // Shadowed type variables are renamed in the derivation too.
+    let derivation = derive(&parse("ΛX: *. λx: X. ΛX: *. λy: X. x").unwrap()).unwrap();
+    let var = &derivation.premises[0].premises[0].premises[0].premises[0];
+    assert_eq!(var.judgement(), "X: *, x: X, X': *, y: X' ⊢ x : X");
+  }
+}
@@ -0,0 +1,115 @@
+pub mod ast;
+pub mod bytecode;
+pub mod c;
+pub mod debruijn;
+pub mod derivation;
+mod grammar;
+pub mod machine;
+pub mod nbe;
+pub mod prelude;
+pub mod spans;
+pub mod strategy;
+pub mod typechecker;
+pub mod untyped;
+pub mod vm;
+
+pub use ast::{Kind, Term, Type};
+pub use spans::SpanTree;
+pub use typechecker::{infer, infer_in, prelude_context, TypecheckerError, TypingContext};
+
+pub type ParseError<'input> =
+  lalrpop_util::ParseError<usize, lalrpop_util::lexer::Token<'input>, &'static str>;
+
+pub fn parse(input: &str) -> Result<Term, ParseError<'_>> {
+  grammar::TermParser::new().parse(&mut spans::SpanStack::default(), input)
+}
+
+// Parses a term together with the spans of its subterms in `input`.
+pub fn parse_with_spans(input: &str) -> Result<(Term, SpanTree), ParseError<'_>> {
+  let mut spans = spans::SpanStack::default();
+  let term = grammar::TermParser::new().parse(&mut spans, input)?;
+  Ok((term, spans.finish()))
+}
+
+pub fn parse_type(input: &str) -> Result<Type, ParseError<'_>> {
+  grammar::TypeParser::new().parse(&mut spans::SpanStack::default(), input)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn parses_terms_and_types() {
+    assert_eq!(
+      parse("λx: Int. x"),
+      Ok(Term::Abs {
+        param_name: String::from("x"),
+        param_type: Some(Type::Int),
+        body: Box::new(Term::Var(String::from("x"))),
+      })
+    );
+    assert_eq!(
+      parse_type("Int -> Unit"),
+      Ok(Type::Arrow(Box::new(Type::Int), Box::new(Type::Unit)))
+    );
+    assert!(parse("λx: Int.").is_err());
+    assert!(parse_type("Int ->").is_err());
+    assert_eq!(parse("2147483647"), Ok(Term::Int(i32::MAX)));
+    assert_eq!(
+      parse("99999999999"),
+      Err(ParseError::User {
+        error: "integer literal is too large"
+      })
+    );
+  }
+
+  #[test]
+  fn parses_spans_of_subterms() {
+    let input = "(λx: Int. x) 1";
+    let (term, spans) = parse_with_spans(input).unwrap();
+    assert_eq!(parse(input), Ok(term));
+    assert_eq!(spans.span, lambda_core::Span::new(0, input.len()));
+    assert_eq!(spans.children.len(), 2);
+    assert_eq!(
+      &input[spans.children[1].span.start..spans.children[1].span.end],
+      "1"
+    );
+  }
+
+  #[test]
+  fn infers_in_a_given_context() {
+    let x = parse("x").unwrap();
+    assert_eq!(
+      infer(&x),
+      Err(TypecheckerError::UndefinedVariable(String::from("x")))
+    );
+    let ctx = TypingContext::new()
+      .assign_type(String::from("X"), Kind::Star)
+      .assign(String::from("x"), Type::TypeVar(String::from("X")));
+    assert_eq!(infer_in(&ctx, &x), Ok(Type::TypeVar(String::from("X"))));
+    assert_eq!(
+      infer_in(&ctx, &parse("ΛX: *. λy: X. x").unwrap()),
+      Ok(parse_type("∀X': *. X' -> X").unwrap())
+    );
+    // Types in the context must only mention type variables bound before them.
+    let ctx = TypingContext::new().assign(String::from("x"), Type::TypeVar(String::from("X")));
+    assert_eq!(
+      infer_in(&ctx, &x),
+      Err(TypecheckerError::UndefinedTypeVariable(String::from("X")))
+    );
+  }
+
+  #[test]
+  fn infers_against_the_prelude() {
+    let ctx = prelude_context();
+    assert_eq!(
+      infer_in(&ctx, &parse("plus (succ zero) zero").unwrap()),
+      Ok(parse_type(prelude::NAT).unwrap())
+    );
+    assert_eq!(
+      infer_in(&ctx, &parse("fst [Int] [Unit] (pair 1 ())").unwrap()),
+      Ok(Type::Int)
+    );
+  }
+}
@@ -0,0 +1,589 @@
+use crate::ast;
+use crate::debruijn::{compile, Branch, Context, EvalError, Term, Type, STEP_LIMIT};
+use crate::strategy::Strategy;
+use std::rc::Rc;
+
+// Abstract machines that evaluate the de Bruijn form without substituting.
+//
+// A machine never rewrites the term. It keeps a pointer to the subterm being
+// evaluated, the environment its free variables are bound in, and a stack of
+// frames, the continuation, saying what to do with its value:
+//
+//   ⟨t, E, K⟩     evaluate t in E, then continue with K
+//   ⟨v, K⟩        return the value v to the first frame of K
+//
+// The environment is a list of the entries of term variables, innermost
+// first, so the de Bruijn index of a variable is its position in the list,
+// and a separate list of the closed types bound to type variables. Functions
+// evaluate to closures, their code paired with the environment they were
+// defined in, and applying one extends the environment of the closure
+// instead of substituting into its body.
+//
+// The CEK machine evaluates by value: an application evaluates the function,
+// then the argument, and binds the value.
+//
+// ⟨t1 t2, E, K⟩          ⟶  ⟨t1, E, arg(t2, E) · K⟩
+// ⟨v, arg(t2, E) · K⟩    ⟶  ⟨t2, E, call(v) · K⟩
+// ⟨v, call(λ. t, E') · K⟩ ⟶  ⟨t, v · E', K⟩
+//
+// The Krivine machine evaluates by name: the argument is bound unevaluated,
+// as a thunk holding the argument and its environment, and is evaluated
+// again every time the variable is looked up.
+//
+// ⟨v, arg(t2, E) · K⟩    ⟶  ⟨t, (t2, E) · E', K⟩   where v = (λ. t, E')
+// ⟨n, E, K⟩              ⟶  ⟨t, E', K⟩             where E(n) = (t, E')
+//
+// Both reduce the other constructs like the weak strategies of `strategy.rs`
+// do, so a machine computes the same value as substitution by its strategy.
+// A variable bound by `fix` is bound to the recursive closure itself, which
+// unfolds again when it is looked up, just as `fix (λx. t)` is substituted
+// for x.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Machine {
+  Cek,
+  Krivine,
+}
+
+impl Machine {
+  // The strategy whose values the machine computes.
+  pub fn strategy(self) -> Strategy {
+    match self {
+      Machine::Cek => Strategy::CallByValue,
+      Machine::Krivine => Strategy::CallByName,
+    }
+  }
+
+  // The machine that evaluates by `strategy`, if there is one.
+  pub fn for_strategy(strategy: Strategy) -> Option<Machine> {
+    match strategy {
+      Strategy::CallByValue => Some(Machine::Cek),
+      Strategy::CallByName => Some(Machine::Krivine),
+      _ => None,
+    }
+  }
+
+  // Evaluates the closed, well-typed term, failing if it takes more than
+  // `limit` transitions.
+  pub fn eval(self, term: &Term, limit: usize) -> Result<Term, EvalError> {
+    let mut state = State::Eval(term, Env::default());
+    let mut stack = Vec::new();
+    let mut transitions = 0;
+    loop {
+      state = match state {
+        State::Eval(term, env) => self.eval_term(term, env, &mut stack),
+        State::Return(value) => match stack.pop() {
+          Some(frame) => self.continue_with(frame, value, &mut stack),
+          None => return Ok(value.read_back()),
+        },
+      };
+      if transitions == limit {
+        return Err(EvalError::StepLimitExceeded(limit));
+      }
+      transitions += 1;
+    }
+  }
+
+  fn eval_term<'a>(self, term: &'a Term, env: Env<'a>, stack: &mut Vec<Frame<'a>>) -> State<'a> {
+    match term {
+      Term::Int(n) => State::Return(Rc::new(Value::Int(*n))),
+      Term::Unit => State::Return(Rc::new(Value::Unit)),
+      Term::Var(i) => match env.terms.get(*i) {
+        Some(Entry::Value(value)) => State::Return(value.clone()),
+        Some(Entry::Thunk(term, env)) => State::Eval(term, env.clone()),
+        Some(Entry::Rec(abs, env)) => unfold(abs, env),
+        None => stuck(term),
+      },
+      Term::Abs { .. } | Term::UniversalAbs { .. } => {
+        State::Return(Rc::new(Value::Closure(term, env)))
+      }
+      Term::App(f, arg) => {
+        stack.push(Frame::Arg(arg, env.clone()));
+        State::Eval(f, env)
+      }
+      Term::UniversalApp(term, typ) => {
+        stack.push(Frame::TypeArg(env.close(typ, 0)));
+        State::Eval(term, env)
+      }
+      Term::Pack { witness, term, typ } => {
+        stack.push(Frame::Pack(env.close(witness, 0), env.annotate(typ)));
+        State::Eval(term, env)
+      }
+      Term::Unpack { package, body, .. } => {
+        stack.push(Frame::Unpack(body, env.clone()));
+        State::Eval(package, env)
+      }
+      Term::Pair(first, second) => {
+        stack.push(Frame::Pair1(second, env.clone()));
+        State::Eval(first, env)
+      }
+      Term::Proj(term, i) => {
+        stack.push(Frame::Proj(*i));
+        State::Eval(term, env)
+      }
+      Term::Inl(term, typ) => {
+        stack.push(Frame::Inl(env.annotate(typ)));
+        State::Eval(term, env)
+      }
+      Term::Inr(term, typ) => {
+        stack.push(Frame::Inr(env.annotate(typ)));
+        State::Eval(term, env)
+      }
+      Term::Case {
+        scrutinee,
+        left,
+        right,
+        ..
+      } => {
+        stack.push(Frame::Case(left, right, env.clone()));
+        State::Eval(scrutinee, env)
+      }
+      Term::Record(fields) => match fields.first() {
+        Some((_, first)) => {
+          stack.push(Frame::Record(fields, Vec::new(), env.clone()));
+          State::Eval(first, env)
+        }
+        None => State::Return(Rc::new(Value::Record(Vec::new()))),
+      },
+      Term::Field(term, label) => {
+        stack.push(Frame::Field(label));
+        State::Eval(term, env)
+      }
+      Term::Tag { label, term, typ } => {
+        stack.push(Frame::Tag(label, env.annotate(typ)));
+        State::Eval(term, env)
+      }
+      Term::VariantCase {
+        scrutinee,
+        branches,
+      } => {
+        stack.push(Frame::VariantCase(branches, env.clone()));
+        State::Eval(scrutinee, env)
+      }
+      Term::Seq(first, second) => {
+        stack.push(Frame::Seq(second, env.clone()));
+        State::Eval(first, env)
+      }
+      Term::Let { value, body, .. } => match self {
+        Machine::Cek => {
+          stack.push(Frame::Let(body, env.clone()));
+          State::Eval(value, env)
+        }
+        Machine::Krivine => State::Eval(body, env.bind(Entry::Thunk(value, env.clone()))),
+      },
+      Term::Fix(term) => {
+        stack.push(Frame::Fix);
+        State::Eval(term, env)
+      }
+      Term::Fold(typ, term) => {
+        stack.push(Frame::Fold(env.annotate(typ)));
+        State::Eval(term, env)
+      }
+      Term::Unfold(_, term) => {
+        stack.push(Frame::Unfold);
+        State::Eval(term, env)
+      }
+    }
+  }
+
+  fn continue_with<'a>(
+    self,
+    frame: Frame<'a>,
+    value: Rc<Value<'a>>,
+    stack: &mut Vec<Frame<'a>>,
+  ) -> State<'a> {
+    match (frame, &*value) {
+      (Frame::Arg(arg, env), _) => match self {
+        Machine::Cek => {
+          stack.push(Frame::Call(value));
+          State::Eval(arg, env)
+        }
+        Machine::Krivine => apply(&value, Entry::Thunk(arg, env)),
+      },
+      (Frame::Call(f), _) => apply(&f, Entry::Value(value)),
+      (Frame::TypeArg(typ), Value::Closure(Term::UniversalAbs { body, .. }, env)) => {
+        State::Eval(body, env.bind_type(typ))
+      }
+      (Frame::Pack(witness, typ), _) => State::Return(Rc::new(Value::Pack(witness, value, typ))),
+      (Frame::Unpack(body, env), Value::Pack(witness, term, _)) => State::Eval(
+        body,
+        env
+          .bind_type(witness.clone())
+          .bind(Entry::Value(term.clone())),
+      ),
+      (Frame::Pair1(second, env), _) => {
+        stack.push(Frame::Pair2(value));
+        State::Eval(second, env)
+      }
+      (Frame::Pair2(first), _) => State::Return(Rc::new(Value::Pair(first, value))),
+      (Frame::Proj(1), Value::Pair(first, _)) => State::Return(first.clone()),
+      (Frame::Proj(2), Value::Pair(_, second)) => State::Return(second.clone()),
+      (Frame::Inl(typ), _) => State::Return(Rc::new(Value::Inl(value, typ))),
+      (Frame::Inr(typ), _) => State::Return(Rc::new(Value::Inr(value, typ))),
+      (Frame::Case(left, _, env), Value::Inl(term, _)) => {
+        State::Eval(left, env.bind(Entry::Value(term.clone())))
+      }
+      (Frame::Case(_, right, env), Value::Inr(term, _)) => {
+        State::Eval(right, env.bind(Entry::Value(term.clone())))
+      }
+      (Frame::Record(fields, mut values, env), _) => {
+        values.push((fields[values.len()].0.as_str(), value));
+        match fields.get(values.len()) {
+          Some((_, next)) => {
+            stack.push(Frame::Record(fields, values, env.clone()));
+            State::Eval(next, env)
+          }
+          None => State::Return(Rc::new(Value::Record(values))),
+        }
+      }
+      (Frame::Field(label), Value::Record(values)) => {
+        match values.iter().find(|(l, _)| *l == label) {
+          Some((_, value)) => State::Return(value.clone()),
+          None => stuck(&value.read_back()),
+        }
+      }
+      (Frame::Tag(label, typ), _) => State::Return(Rc::new(Value::Tag(label, value, typ))),
+      (Frame::VariantCase(branches, env), Value::Tag(label, term, _)) => {
+        match branches.iter().find(|branch| branch.label == *label) {
+          Some(branch) => State::Eval(&branch.body, env.bind(Entry::Value(term.clone()))),
+          None => stuck(&value.read_back()),
+        }
+      }
+      (Frame::Seq(second, env), _) => State::Eval(second, env),
+      (Frame::Let(body, env), _) => State::Eval(body, env.bind(Entry::Value(value))),
+      (Frame::Fix, Value::Closure(abs @ Term::Abs { .. }, env)) => unfold(abs, env),
+      (Frame::Fold(typ), _) => State::Return(Rc::new(Value::Fold(typ, value))),
+      (Frame::Unfold, Value::Fold(_, term)) => State::Return(term.clone()),
+      _ => stuck(&value.read_back()),
+    }
+  }
+}
+
+// Evaluates the term by `machine`.
+pub fn eval(term: &ast::Term, machine: Machine) -> Result<ast::Term, EvalError> {
+  let value = machine.eval(&compile(term)?, STEP_LIMIT)?;
+  Ok(value.to_ast(&Context::new()))
+}
+
+enum State<'a> {
+  Eval(&'a Term, Env<'a>),
+  Return(Rc<Value<'a>>),
+}
+
+// What to do with the value of the term being evaluated.
+enum Frame<'a> {
+  Arg(&'a Term, Env<'a>),
+  Call(Rc<Value<'a>>),
+  TypeArg(Type),
+  Pack(Type, Annotation<'a>),
+  Unpack(&'a Term, Env<'a>),
+  Pair1(&'a Term, Env<'a>),
+  Pair2(Rc<Value<'a>>),
+  Proj(usize),
+  Inl(Annotation<'a>),
+  Inr(Annotation<'a>),
+  Case(&'a Term, &'a Term, Env<'a>),
+  // The fields of a record, the values of the ones evaluated so far and the
+  // environment of the rest.
+  Record(&'a [(String, Term)], Vec<(&'a str, Rc<Value<'a>>)>, Env<'a>),
+  Field(&'a str),
+  Tag(&'a str, Annotation<'a>),
+  VariantCase(&'a [Branch], Env<'a>),
+  Seq(&'a Term, Env<'a>),
+  Let(&'a Term, Env<'a>),
+  Fix,
+  Fold(Annotation<'a>),
+  Unfold,
+}
+
+#[derive(Debug)]
+enum Value<'a> {
+  Int(i32),
+  Unit,
+  // A λ or Λ and the environment of its free variables.
+  Closure(&'a Term, Env<'a>),
+  Pack(Type, Rc<Value<'a>>, Annotation<'a>),
+  Pair(Rc<Value<'a>>, Rc<Value<'a>>),
+  Inl(Rc<Value<'a>>, Annotation<'a>),
+  Inr(Rc<Value<'a>>, Annotation<'a>),
+  Record(Vec<(&'a str, Rc<Value<'a>>)>),
+  Tag(&'a str, Rc<Value<'a>>, Annotation<'a>),
+  Fold(Annotation<'a>, Rc<Value<'a>>),
+}
+
+// What a term variable is bound to.
+#[derive(Debug, Clone)]
+enum Entry<'a> {
+  Value(Rc<Value<'a>>),
+  // An argument of the Krivine machine and the environment it is evaluated in.
+  Thunk(&'a Term, Env<'a>),
+  // The λ that `fix` was applied to, and its environment.
+  Rec(&'a Term, Env<'a>),
+}
+
+// A type annotation, kept with the types its type variables are bound to
+// until the value it annotates is read back.
+#[derive(Debug)]
+struct Annotation<'a>(&'a Type, List<Type>);
+
+#[derive(Debug, Clone, Default)]
+struct Env<'a> {
+  terms: List<Entry<'a>>,
+  types: List<Type>,
+}
+
+// A persistent list, shared by every environment it was extended to.
+#[derive(Debug)]
+pub(crate) struct List<T>(Option<Rc<(T, List<T>)>>);
+
+impl<T> Default for List<T> {
+  fn default() -> Self {
+    List(None)
+  }
+}
+
+impl<T> Clone for List<T> {
+  fn clone(&self) -> Self {
+    List(self.0.clone())
+  }
+}
+
+impl<T> List<T> {
+  pub(crate) fn push(&self, item: T) -> Self {
+    List(Some(Rc::new((item, self.clone()))))
+  }
+
+  pub(crate) fn get(&self, i: usize) -> Option<&T> {
+    let mut list = self;
+    for _ in 0..i {
+      list = &list.0.as_ref()?.1;
+    }
+    list.0.as_ref().map(|node| &node.0)
+  }
+
+  pub(crate) fn is_empty(&self) -> bool {
+    self.0.is_none()
+  }
+}
+
+impl<'a> Env<'a> {
+  fn bind(&self, entry: Entry<'a>) -> Env<'a> {
+    Env {
+      terms: self.terms.push(entry),
+      types: self.types.clone(),
+    }
+  }
+
+  fn bind_type(&self, typ: Type) -> Env<'a> {
+    Env {
+      terms: self.terms.clone(),
+      types: self.types.push(typ),
+    }
+  }
+
+  fn annotate(&self, typ: &'a Type) -> Annotation<'a> {
+    Annotation(typ, self.types.clone())
+  }
+
+  // Substitutes the types bound in the environment for the type variables
+  // free above `cutoff` in `typ`. The bound types are closed, so they need no
+  // shifting.
+  fn close(&self, typ: &Type, cutoff: usize) -> Type {
+    close(&self.types, typ, cutoff)
+  }
+
+  // Substitutes the entries and types bound in the environment for the
+  // variables free in `term`.
+  fn read_back(&self, term: &Term) -> Term {
+    term.map_vars(
+      0,
+      0,
+      &|c, _, i| match i.checked_sub(c).and_then(|i| self.terms.get(i)) {
+        Some(entry) => entry.read_back(),
+        None => Term::Var(i),
+      },
+      &|c, typ| self.close(typ, c),
+    )
+  }
+}
+
+fn close(types: &List<Type>, typ: &Type, cutoff: usize) -> Type {
+  if types.is_empty() {
+    return typ.clone();
+  }
+  typ.map_vars(
+    cutoff,
+    &|c, i| match i.checked_sub(c).and_then(|i| types.get(i)) {
+      Some(typ) => typ.clone(),
+      None => Type::TypeVar(i),
+    },
+  )
+}
+
+impl Annotation<'_> {
+  fn read_back(&self) -> Type {
+    close(&self.1, self.0, 0)
+  }
+}
+
+impl Entry<'_> {
+  fn read_back(&self) -> Term {
+    match self {
+      Entry::Value(value) => value.read_back(),
+      Entry::Thunk(term, env) => env.read_back(term),
+      Entry::Rec(abs, env) => Term::Fix(Box::new(env.read_back(abs))),
+    }
+  }
+}
+
+impl Value<'_> {
+  // The closed term the value stands for.
+  fn read_back(&self) -> Term {
+    match self {
+      Value::Int(n) => Term::Int(*n),
+      Value::Unit => Term::Unit,
+      Value::Closure(term, env) => env.read_back(term),
+      Value::Pack(witness, term, typ) => Term::Pack {
+        witness: witness.clone(),
+        term: Box::new(term.read_back()),
+        typ: typ.read_back(),
+      },
+      Value::Pair(first, second) => {
+        Term::Pair(Box::new(first.read_back()), Box::new(second.read_back()))
+      }
+      Value::Inl(term, typ) => Term::Inl(Box::new(term.read_back()), typ.read_back()),
+      Value::Inr(term, typ) => Term::Inr(Box::new(term.read_back()), typ.read_back()),
+      Value::Record(fields) => Term::Record(
+        fields
+          .iter()
+          .map(|(label, term)| (String::from(*label), term.read_back()))
+          .collect(),
+      ),
+      Value::Tag(label, term, typ) => Term::Tag {
+        label: String::from(*label),
+        term: Box::new(term.read_back()),
+        typ: typ.read_back(),
+      },
+      Value::Fold(typ, term) => Term::Fold(typ.read_back(), Box::new(term.read_back())),
+    }
+  }
+}
+
+fn apply<'a>(f: &Value<'a>, entry: Entry<'a>) -> State<'a> {
+  match f {
+    Value::Closure(Term::Abs { body, .. }, env) => State::Eval(body, env.bind(entry)),
+    _ => stuck(&f.read_back()),
+  }
+}
+
+// fix (λx. t) |> t[fix (λx. t)/x]
+fn unfold<'a>(abs: &'a Term, env: &Env<'a>) -> State<'a> {
+  match abs {
+    Term::Abs { body, .. } => State::Eval(body, env.bind(Entry::Rec(abs, env.clone()))),
+    _ => stuck(abs),
+  }
+}
+
+// Only an open or ill-typed term gets stuck.
+fn stuck(term: &Term) -> ! {
+  panic!("the abstract machine is stuck on {:?}", term)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::parse;
+  use crate::prelude::BOOLEAN;
+  use crate::strategy::Evaluation;
+
+  // Church numerals built from the prelude's `zero` and `succ`.
+  fn numeral(n: usize) -> String {
+    (0..n).fold(String::from("zero"), |m, _| format!("succ ({})", m))
+  }
+
+  fn assert_agrees(input: &str, limit: usize) {
+    let term = compile(&parse(input).unwrap()).unwrap();
+    for machine in &[Machine::Cek, Machine::Krivine] {
+      assert_eq!(
+        machine.eval(&term, limit),
+        Evaluation::new(machine.strategy()).eval(&term, limit),
+        "{} on {:?}",
+        input,
+        machine
+      );
+    }
+  }
+
+  #[test]
+  fn machines_agree_with_substitution() {
+    let inputs = [
+      "(λi: ∀X: *. X -> X. i i 1) (ΛX: *. λx. x)",
+      "(λx: Int. λy: Int. x) ((λz: Int. z) 1)",
+      "ΛX: *. λx: X. (λy: X. y) x",
+      "(λx: Int. (x, x)) ((λy: Int. y) 2)",
+      "let p = ({a = 1}, inl () as Unit + Int) in case p.2 of inl u => p.1.a | inr n => n",
+      "unpack pack [Int, {f = λx: Int. x, z = 1}] as ∃X: *. {f: X -> X, z: X} as [X, r] in (λy: X. 2) (r.f r.z)",
+      "unpack pack [Int, λx: Int. x] as ∃X: *. X -> X as [X, f] in pack [X, f] as ∃Y: *. Y -> Y",
+      "case <b = (λx: Int. x) 2> as <a: Unit, b: Int> of { <a = u> => 0 | <b = n> => n }",
+      "(λf: Int -> Int. (); f (f 1)) (λx: Int. x)",
+      "unfold [μL. Unit + L] (fold [μL. Unit + L] (inl () as Unit + (μL. Unit + L)))",
+      "let rec f: Int -> Int = λn: Int. n in f 3",
+      "let rec f: Int -> Int = λn: Int. n in f",
+      "(ΛX: *. λx: X. inl x as X + Bool) [Int -> Int] (λy: Int. y)",
+      "let x = (λy: Int. y) 1 in {b = λz: Unit. x, a = ()}",
+      "(λx: Int. λy: Int. x) ((λz: Int. z) 1) 2",
+    ];
+    for input in &inputs {
+      assert_agrees(input, STEP_LIMIT);
+    }
+  }
+
+  #[test]
+  fn machines_agree_on_church_numerals() {
+    let two = numeral(2);
+    let three = numeral(3);
+    let inputs = [
+      format!("times ({}) ({})", two, three),
+      format!("plus ({}) ({}) [Int] (λx. x) 0", two, three),
+      format!("pred ({})", three),
+      format!(
+        "equal (times ({}) ({})) (plus ({}) ({}))",
+        two, three, three, three
+      ),
+      format!("isZero (minus ({}) ({})) [Int] 1 0", two, three),
+      format!(
+        "foldr [{b}] [{b}] (λx. λr. x) false (map [Int] [{b}] (λx. true) (cons 1 (nil [Int])))",
+        b = BOOLEAN
+      ),
+    ];
+    for input in &inputs {
+      assert_agrees(input, STEP_LIMIT);
+    }
+  }
+
+  #[test]
+  fn machines_give_up_on_divergence() {
+    let diverges = "let rec f: Int -> Int = λn: Int. f n in f 3";
+    let term = compile(&parse(diverges).unwrap()).unwrap();
+    for machine in &[Machine::Cek, Machine::Krivine] {
+      assert_eq!(
+        machine.eval(&term, 1000),
+        Err(EvalError::StepLimitExceeded(1000))
+      );
+    }
+  }
+
+  #[test]
+  fn krivine_machine_delays_arguments() {
+    let unused = "(λx: Int. 1) (let rec f: Int -> Int = λn: Int. f n in f 3)";
+    let term = compile(&parse(unused).unwrap()).unwrap();
+    assert_eq!(Machine::Krivine.eval(&term, 1000), Ok(Term::Int(1)));
+    assert_eq!(
+      Machine::Cek.eval(&term, 1000),
+      Err(EvalError::StepLimitExceeded(1000))
+    );
+    assert_eq!(
+      eval(&parse("(λx: Int. 1) 2").unwrap(), Machine::Krivine).map(|term| term.to_string()),
+      Ok(String::from("1"))
+    );
+  }
+}
@@ -1,14 +1,96 @@
-mod ast;
-mod grammar;
-mod typechecker;
-
-use ast::Term;
+use lambda_core::Diagnostic;
+use system_f::machine::{self, Machine};
+use system_f::strategy::Strategy;
+use system_f::{bytecode, c, debruijn, derivation, nbe, typechecker, untyped, vm, Term};
 
 fn parse(input: &str) -> Term {
-  grammar::TermParser::new().parse(input).unwrap()
+  match system_f::parse(input) {
+    Ok(term) => term,
+    Err(err) => panic!("{}", Diagnostic::from_parse_error(&err).render(input)),
+  }
 }
 
+// Usage: system_f [--strategy cbv|cbn|need|normal|applicative|full] [--machine] [--bytecode] [--c] [TERM]
+//
+// With --machine, the value is computed by the abstract machine of the
+// strategy, which has to be cbv or cbn. With --bytecode, the erased term is
+// also compiled, disassembled and run on the bytecode machine.
+// With --c, only the C program that the term compiles to is printed.
 fn main() {
-  let e = "(ΛX: * . λx: Y. x) [Bool]";
-  dbg!(typechecker::infer(&parse(e)));
+  let mut strategy = Strategy::CallByValue;
+  let mut use_machine = false;
+  let mut use_bytecode = false;
+  let mut emit_c = false;
+  let mut input = String::from("(λi: ∀X: *. X -> X. i i 1) (ΛX: *. λx. x)");
+  let mut args = std::env::args().skip(1);
+  while let Some(arg) = args.next() {
+    match arg.as_str() {
+      "--strategy" | "-s" => match args.next().map(|name| name.parse()) {
+        Some(Ok(chosen)) => strategy = chosen,
+        Some(Err(err)) => panic!("{}", err),
+        None => panic!("--strategy expects the name of a strategy"),
+      },
+      "--machine" | "-m" => use_machine = true,
+      "--bytecode" | "-b" => use_bytecode = true,
+      "--c" => emit_c = true,
+      _ => input = arg,
+    }
+  }
+  let e = parse(&input);
+  if emit_c {
+    match c::compile(&e) {
+      Ok(source) => print!("{}", source),
+      Err(err) => println!("{}", err),
+    }
+    return;
+  }
+  println!("{}", e);
+  match typechecker::elaborate(&e) {
+    Ok((elaborated, _)) => println!("{}", elaborated),
+    Err(err) => println!("{}", err),
+  }
+  match derivation::derive(&e) {
+    Ok(derivation) => println!("{}", derivation),
+    Err(err) => println!("{}", err),
+  }
+  match untyped::erase_ast(&e) {
+    Ok(erased) => match erased.eval() {
+      Ok(value) => println!("{} ~> {}", erased, value),
+      Err(err) => println!("{}", err),
+    },
+    Err(err) => println!("{}", err),
+  }
+  if use_bytecode {
+    match untyped::erase_ast(&e) {
+      Ok(erased) => {
+        print!("{}", bytecode::compile(&erased));
+        match vm::run_erased(&erased) {
+          Ok(value) => println!("vm: {}", value),
+          Err(err) => println!("{}", err),
+        }
+      }
+      Err(err) => println!("{}", err),
+    }
+  }
+  match nbe::normalize(&e) {
+    Ok(normal_form) => println!("normal form: {}", normal_form),
+    Err(err) => println!("{}", err),
+  }
+  match debruijn::trace_with(&e, strategy) {
+    Ok(trace) => println!("{}", trace.render_text()),
+    Err(err) => println!("{}", err),
+  }
+  let value = if use_machine {
+    match Machine::for_strategy(strategy) {
+      Some(chosen) => machine::eval(&e, chosen),
+      None => panic!("there is no abstract machine for the {} strategy", strategy),
+    }
+  } else {
+    debruijn::eval_with(&e, strategy)
+  };
+  match (typechecker::infer(&e), value) {
+    (Ok(typ), Ok(value)) => println!("{} : {}", value, typ),
+    (Err(err), _) => println!("{}", err),
+    (_, Err(err)) => println!("{}", err),
+  }
 }
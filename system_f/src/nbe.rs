@@ -0,0 +1,807 @@
+use crate::ast::{self, Kind};
+use crate::debruijn::{compile, Branch, Context, EvalError, Hint, Term, Type};
+use crate::machine::List;
+use std::rc::Rc;
+
+// Normalization by evaluation.
+//
+// Instead of contracting redexes one at a time, a term is evaluated into a
+// semantic domain where functions are closures, and the value is then read
+// back into a term. Reading back a closure applies it to a fresh variable and
+// reads back the result, so the body is normalized under its binder too.
+// Eliminating a variable cannot reduce, so it builds a neutral term, such as
+// `x v` or `case x of ...`, that is read back as it is.
+//
+//   eval : Term -> Env -> Value          quote : Value -> Term
+//
+// Values refer to variables by level, counted from the outside in, so they
+// can be moved under binders without shifting. Reading back at depth d turns
+// the level l into the index d - 1 - l.
+//
+// The result is the normal form that normal order reduction reaches, when
+// every subterm has one. Arguments are evaluated before they are bound, so a
+// diverging argument makes normalization fail even when it is not used. A
+// recursive function defined with fix only unfolds when it is eliminated, and
+// reads back as `fix (λx. t)` with t in normal form.
+//
+// Types are normalized the same way. The only type-level functions are type
+// aliases, so an alias evaluates to a function over its parameters that is
+// applied by the type applications it heads.
+
+// Evaluation recurses into the term, so normalization gives up when it is
+// nested this deep. It runs on a thread of its own, with a stack large enough
+// for `STACK_PER_EVALUATION` bytes at every level.
+pub const DEPTH_LIMIT: usize = 1_000;
+const STACK_SIZE: usize = 1 << 20;
+const STACK_PER_EVALUATION: usize = 64 << 10;
+
+// A type in the semantic domain.
+#[derive(Debug, Clone)]
+enum VType<'a> {
+  Int,
+  Bool,
+  Unit,
+  Arrow(Rc<VType<'a>>, Rc<VType<'a>>),
+  Product(Rc<VType<'a>>, Rc<VType<'a>>),
+  Sum(Rc<VType<'a>>, Rc<VType<'a>>),
+  Record(Vec<(&'a str, VType<'a>)>),
+  Variant(Vec<(&'a str, VType<'a>)>),
+  Forall(&'a Hint, &'a Kind, TypeClosure<'a>),
+  Exists(&'a Hint, &'a Kind, TypeClosure<'a>),
+  Mu(&'a Hint, TypeClosure<'a>),
+  // An alias waiting for its remaining `n` parameters.
+  Alias(usize, TypeClosure<'a>),
+  // A type variable, by level, applied to arguments.
+  Neutral(usize, Vec<VType<'a>>),
+}
+
+// A type and the types bound to its free type variables.
+#[derive(Debug, Clone)]
+struct TypeClosure<'a> {
+  env: List<VType<'a>>,
+  body: &'a Type,
+}
+
+impl<'a> TypeClosure<'a> {
+  fn eval(&self) -> VType<'a> {
+    eval_type(self.body, &self.env)
+  }
+
+  // The body of a binder with `arg` for its variable.
+  fn instantiate(&self, arg: VType<'a>) -> VType<'a> {
+    eval_type(self.body, &self.env.push(arg))
+  }
+}
+
+fn eval_type<'a>(typ: &'a Type, env: &List<VType<'a>>) -> VType<'a> {
+  let eval = |typ: &'a Type| Rc::new(eval_type(typ, env));
+  let eval_fields = |fields: &'a [(String, Type)]| {
+    fields
+      .iter()
+      .map(|(label, typ)| (label.as_str(), eval_type(typ, env)))
+      .collect()
+  };
+  let closure = |body: &'a Type| TypeClosure {
+    env: env.clone(),
+    body,
+  };
+  match typ {
+    Type::Int => VType::Int,
+    Type::Bool => VType::Bool,
+    Type::Unit => VType::Unit,
+    Type::Arrow(left, right) => VType::Arrow(eval(left), eval(right)),
+    Type::Product(left, right) => VType::Product(eval(left), eval(right)),
+    Type::Sum(left, right) => VType::Sum(eval(left), eval(right)),
+    Type::Record(fields) => VType::Record(eval_fields(fields)),
+    Type::Variant(fields) => VType::Variant(eval_fields(fields)),
+    Type::TypeVar(i) => env
+      .get(*i)
+      .cloned()
+      .unwrap_or_else(|| panic!("type variable {} is not bound", i)),
+    Type::App(function, arg) => apply_type(eval_type(function, env), eval_type(arg, env)),
+    Type::Forall { hint, kind, typ } => VType::Forall(hint, kind, closure(typ)),
+    Type::Exists { hint, kind, typ } => VType::Exists(hint, kind, closure(typ)),
+    Type::Mu { hint, typ } => VType::Mu(hint, closure(typ)),
+  }
+}
+
+fn apply_type<'a>(function: VType<'a>, arg: VType<'a>) -> VType<'a> {
+  match function {
+    VType::Alias(n, closure) => alias(
+      n - 1,
+      TypeClosure {
+        env: closure.env.push(arg),
+        body: closure.body,
+      },
+    ),
+    VType::Neutral(level, mut args) => {
+      args.push(arg);
+      VType::Neutral(level, args)
+    }
+    _ => panic!("{:?} is applied to a type but is not an alias", function),
+  }
+}
+
+// An alias whose body still misses `n` parameters.
+fn alias(n: usize, body: TypeClosure) -> VType {
+  if n == 0 {
+    body.eval()
+  } else {
+    VType::Alias(n, body)
+  }
+}
+
+fn quote_type(typ: &VType, depth: usize) -> Type {
+  let quote = |typ: &VType| Box::new(quote_type(typ, depth));
+  let quote_fields = |fields: &[(&str, VType)]| {
+    fields
+      .iter()
+      .map(|(label, typ)| (String::from(*label), quote_type(typ, depth)))
+      .collect()
+  };
+  let under =
+    |body: &TypeClosure| Box::new(quote_type(&body.instantiate(var_type(depth)), depth + 1));
+  match typ {
+    VType::Int => Type::Int,
+    VType::Bool => Type::Bool,
+    VType::Unit => Type::Unit,
+    VType::Arrow(left, right) => Type::Arrow(quote(left), quote(right)),
+    VType::Product(left, right) => Type::Product(quote(left), quote(right)),
+    VType::Sum(left, right) => Type::Sum(quote(left), quote(right)),
+    VType::Record(fields) => Type::Record(quote_fields(fields)),
+    VType::Variant(fields) => Type::Variant(quote_fields(fields)),
+    VType::Forall(hint, kind, body) => Type::Forall {
+      hint: (*hint).clone(),
+      kind: (*kind).clone(),
+      typ: under(body),
+    },
+    VType::Exists(hint, kind, body) => Type::Exists {
+      hint: (*hint).clone(),
+      kind: (*kind).clone(),
+      typ: under(body),
+    },
+    VType::Mu(hint, body) => Type::Mu {
+      hint: (*hint).clone(),
+      typ: under(body),
+    },
+    VType::Alias(..) => panic!("an alias is read back before it has all its parameters"),
+    VType::Neutral(level, args) => args
+      .iter()
+      .fold(Type::TypeVar(depth - 1 - level), |function, arg| {
+        Type::App(Box::new(function), quote(arg))
+      }),
+  }
+}
+
+fn var_type<'a>(level: usize) -> VType<'a> {
+  VType::Neutral(level, Vec::new())
+}
+
+// Binds the type variables of a type valid under `depth` binders to
+// themselves.
+fn identity<'a>(depth: usize) -> List<VType<'a>> {
+  (0..depth).fold(List::default(), |env, level| env.push(var_type(level)))
+}
+
+impl Type {
+  // The normal form of a type valid under `depth` type variables.
+  pub fn normalize(&self, depth: usize) -> Type {
+    quote_type(&eval_type(self, &identity(depth)), depth)
+  }
+
+  // Whether two types valid under `depth` type variables have the same
+  // normal form.
+  pub fn equiv(&self, other: &Type, depth: usize) -> bool {
+    self == other || self.normalize(depth) == other.normalize(depth)
+  }
+}
+
+// Given the type `typ` under a binder of the alias with `arity` parameters
+// and body `body`, valid under `depth` type variables, replaces every use of
+// the alias by its body.
+pub(crate) fn expand_alias(typ: &Type, body: &Type, arity: usize, depth: usize) -> Type {
+  let env = identity(depth);
+  let alias = alias(
+    arity,
+    TypeClosure {
+      env: env.clone(),
+      body,
+    },
+  );
+  quote_type(&eval_type(typ, &env.push(alias)), depth)
+}
+
+#[derive(Debug)]
+enum Value<'a> {
+  Int(i32),
+  Unit,
+  Abs(&'a Hint, TypeClosure<'a>, Closure<'a>),
+  TypeAbs(&'a Hint, &'a Kind, Closure<'a>),
+  Pack(VType<'a>, Rc<Value<'a>>, TypeClosure<'a>),
+  Pair(Rc<Value<'a>>, Rc<Value<'a>>),
+  Inl(Rc<Value<'a>>, TypeClosure<'a>),
+  Inr(Rc<Value<'a>>, TypeClosure<'a>),
+  Record(Vec<(&'a str, Rc<Value<'a>>)>),
+  Tag(&'a str, Rc<Value<'a>>, TypeClosure<'a>),
+  Fold(TypeClosure<'a>, Rc<Value<'a>>),
+  // `fix` applied to the λ with these parts, unfolded when it is eliminated.
+  Fix(&'a Hint, TypeClosure<'a>, Closure<'a>),
+  Neutral(Neutral<'a>),
+}
+
+// An elimination stuck on a variable. The value it eliminates is neutral
+// itself.
+#[derive(Debug)]
+enum Neutral<'a> {
+  Var(usize),
+  App(Rc<Value<'a>>, Rc<Value<'a>>),
+  TypeApp(Rc<Value<'a>>, VType<'a>),
+  Unpack(&'a Hint, &'a Hint, Rc<Value<'a>>, Closure<'a>),
+  Proj(Rc<Value<'a>>, usize),
+  Case(Rc<Value<'a>>, &'a Hint, Closure<'a>, &'a Hint, Closure<'a>),
+  Field(Rc<Value<'a>>, &'a str),
+  VariantCase(Rc<Value<'a>>, &'a [Branch], Env<'a>),
+  Seq(Rc<Value<'a>>, Rc<Value<'a>>),
+  Fix(Rc<Value<'a>>),
+  Unfold(TypeClosure<'a>, Rc<Value<'a>>),
+}
+
+// A term and the values and types bound to its free variables.
+#[derive(Debug, Clone)]
+struct Closure<'a> {
+  env: Env<'a>,
+  body: &'a Term,
+}
+
+#[derive(Debug, Clone, Default)]
+struct Env<'a> {
+  terms: List<Rc<Value<'a>>>,
+  types: List<VType<'a>>,
+}
+
+impl<'a> Env<'a> {
+  fn bind(&self, value: Rc<Value<'a>>) -> Env<'a> {
+    Env {
+      terms: self.terms.push(value),
+      types: self.types.clone(),
+    }
+  }
+
+  fn bind_type(&self, typ: VType<'a>) -> Env<'a> {
+    Env {
+      terms: self.terms.clone(),
+      types: self.types.push(typ),
+    }
+  }
+
+  fn closure(&self, body: &'a Term) -> Closure<'a> {
+    Closure {
+      env: self.clone(),
+      body,
+    }
+  }
+
+  fn type_closure(&self, body: &'a Type) -> TypeClosure<'a> {
+    TypeClosure {
+      env: self.types.clone(),
+      body,
+    }
+  }
+}
+
+fn var<'a>(level: usize) -> Rc<Value<'a>> {
+  Rc::new(Value::Neutral(Neutral::Var(level)))
+}
+
+fn neutral(neutral: Neutral) -> Result<Rc<Value>, EvalError> {
+  Ok(Rc::new(Value::Neutral(neutral)))
+}
+
+// Only an open or ill-typed term gets stuck.
+fn stuck(value: &Value) -> ! {
+  panic!("normalization is stuck on {:?}", value)
+}
+
+struct Normalizer {
+  depth: usize,
+  limit: usize,
+}
+
+impl Normalizer {
+  fn eval<'a>(&mut self, term: &'a Term, env: &Env<'a>) -> Result<Rc<Value<'a>>, EvalError> {
+    if self.depth == self.limit {
+      return Err(EvalError::DepthLimitExceeded(self.limit));
+    }
+    self.depth += 1;
+    let value = self.eval_nested(term, env);
+    self.depth -= 1;
+    value
+  }
+
+  fn eval_nested<'a>(&mut self, term: &'a Term, env: &Env<'a>) -> Result<Rc<Value<'a>>, EvalError> {
+    match term {
+      Term::Int(n) => Ok(Rc::new(Value::Int(*n))),
+      Term::Unit => Ok(Rc::new(Value::Unit)),
+      Term::Var(i) => Ok(
+        env
+          .terms
+          .get(*i)
+          .cloned()
+          .unwrap_or_else(|| panic!("variable {} is not bound", i)),
+      ),
+      Term::Abs {
+        hint,
+        param_type,
+        body,
+      } => Ok(Rc::new(Value::Abs(
+        hint,
+        env.type_closure(param_type),
+        env.closure(body),
+      ))),
+      Term::UniversalAbs { hint, kind, body } => {
+        Ok(Rc::new(Value::TypeAbs(hint, kind, env.closure(body))))
+      }
+      Term::App(f, arg) => {
+        let f = self.eval(f, env)?;
+        let arg = self.eval(arg, env)?;
+        self.apply(f, arg)
+      }
+      Term::UniversalApp(term, typ) => {
+        let typ = eval_type(typ, &env.types);
+        let function = self.eval_forced(term, env)?;
+        match &*function {
+          Value::TypeAbs(_, _, closure) => self.eval(closure.body, &closure.env.bind_type(typ)),
+          Value::Neutral(_) => neutral(Neutral::TypeApp(function.clone(), typ)),
+          value => stuck(value),
+        }
+      }
+      Term::Pack { witness, term, typ } => Ok(Rc::new(Value::Pack(
+        eval_type(witness, &env.types),
+        self.eval(term, env)?,
+        env.type_closure(typ),
+      ))),
+      Term::Unpack {
+        type_hint,
+        hint,
+        package,
+        body,
+      } => {
+        let package = self.eval_forced(package, env)?;
+        match &*package {
+          Value::Pack(witness, term, _) => {
+            self.eval(body, &env.bind_type(witness.clone()).bind(term.clone()))
+          }
+          Value::Neutral(_) => neutral(Neutral::Unpack(
+            type_hint,
+            hint,
+            package.clone(),
+            env.closure(body),
+          )),
+          value => stuck(value),
+        }
+      }
+      Term::Pair(first, second) => Ok(Rc::new(Value::Pair(
+        self.eval(first, env)?,
+        self.eval(second, env)?,
+      ))),
+      Term::Proj(term, i) => {
+        let pair = self.eval_forced(term, env)?;
+        match (&*pair, i) {
+          (Value::Pair(first, _), 1) => Ok(first.clone()),
+          (Value::Pair(_, second), 2) => Ok(second.clone()),
+          (Value::Neutral(_), _) => neutral(Neutral::Proj(pair.clone(), *i)),
+          (value, _) => stuck(value),
+        }
+      }
+      Term::Inl(term, typ) => Ok(Rc::new(Value::Inl(
+        self.eval(term, env)?,
+        env.type_closure(typ),
+      ))),
+      Term::Inr(term, typ) => Ok(Rc::new(Value::Inr(
+        self.eval(term, env)?,
+        env.type_closure(typ),
+      ))),
+      Term::Case {
+        scrutinee,
+        left_hint,
+        left,
+        right_hint,
+        right,
+      } => {
+        let scrutinee = self.eval_forced(scrutinee, env)?;
+        match &*scrutinee {
+          Value::Inl(value, _) => self.eval(left, &env.bind(value.clone())),
+          Value::Inr(value, _) => self.eval(right, &env.bind(value.clone())),
+          Value::Neutral(_) => neutral(Neutral::Case(
+            scrutinee.clone(),
+            left_hint,
+            env.closure(left),
+            right_hint,
+            env.closure(right),
+          )),
+          value => stuck(value),
+        }
+      }
+      Term::Record(fields) => Ok(Rc::new(Value::Record(
+        fields
+          .iter()
+          .map(|(label, term)| Ok((label.as_str(), self.eval(term, env)?)))
+          .collect::<Result<_, EvalError>>()?,
+      ))),
+      Term::Field(term, label) => {
+        let record = self.eval_forced(term, env)?;
+        match &*record {
+          Value::Record(fields) => match fields.iter().find(|(other, _)| other == label) {
+            Some((_, value)) => Ok(value.clone()),
+            None => stuck(&record),
+          },
+          Value::Neutral(_) => neutral(Neutral::Field(record.clone(), label)),
+          value => stuck(value),
+        }
+      }
+      Term::Tag { label, term, typ } => Ok(Rc::new(Value::Tag(
+        label,
+        self.eval(term, env)?,
+        env.type_closure(typ),
+      ))),
+      Term::VariantCase {
+        scrutinee,
+        branches,
+      } => {
+        let scrutinee = self.eval_forced(scrutinee, env)?;
+        match &*scrutinee {
+          Value::Tag(label, value, _) => {
+            match branches.iter().find(|branch| branch.label == *label) {
+              Some(branch) => self.eval(&branch.body, &env.bind(value.clone())),
+              None => stuck(&scrutinee),
+            }
+          }
+          Value::Neutral(_) => neutral(Neutral::VariantCase(
+            scrutinee.clone(),
+            branches,
+            env.clone(),
+          )),
+          value => stuck(value),
+        }
+      }
+      Term::Seq(first, second) => {
+        let first = self.eval_forced(first, env)?;
+        match &*first {
+          Value::Unit => self.eval(second, env),
+          Value::Neutral(_) => neutral(Neutral::Seq(first.clone(), self.eval(second, env)?)),
+          value => stuck(value),
+        }
+      }
+      Term::Let { value, body, .. } => {
+        let value = self.eval(value, env)?;
+        self.eval(body, &env.bind(value))
+      }
+      Term::Fix(term) => {
+        let f = self.eval_forced(term, env)?;
+        match &*f {
+          Value::Abs(hint, param_type, body) => {
+            Ok(Rc::new(Value::Fix(hint, param_type.clone(), body.clone())))
+          }
+          Value::Neutral(_) => neutral(Neutral::Fix(f.clone())),
+          value => stuck(value),
+        }
+      }
+      Term::Fold(typ, term) => Ok(Rc::new(Value::Fold(
+        env.type_closure(typ),
+        self.eval(term, env)?,
+      ))),
+      Term::Unfold(typ, term) => {
+        let folded = self.eval_forced(term, env)?;
+        match &*folded {
+          Value::Fold(_, value) => Ok(value.clone()),
+          Value::Neutral(_) => neutral(Neutral::Unfold(env.type_closure(typ), folded.clone())),
+          value => stuck(value),
+        }
+      }
+    }
+  }
+
+  // Evaluates a term that is about to be eliminated.
+  fn eval_forced<'a>(&mut self, term: &'a Term, env: &Env<'a>) -> Result<Rc<Value<'a>>, EvalError> {
+    let value = self.eval(term, env)?;
+    self.force(value)
+  }
+
+  // Unfolds a recursive value until it is not one.
+  fn force<'a>(&mut self, mut value: Rc<Value<'a>>) -> Result<Rc<Value<'a>>, EvalError> {
+    while let Value::Fix(_, _, body) = &*value {
+      // fix (λx. t) |> t[fix (λx. t)/x]
+      value = self.eval(body.body, &body.env.bind(value.clone()))?;
+    }
+    Ok(value)
+  }
+
+  fn apply<'a>(
+    &mut self,
+    f: Rc<Value<'a>>,
+    arg: Rc<Value<'a>>,
+  ) -> Result<Rc<Value<'a>>, EvalError> {
+    let f = self.force(f)?;
+    match &*f {
+      Value::Abs(_, _, body) => self.eval(body.body, &body.env.bind(arg)),
+      Value::Neutral(_) => neutral(Neutral::App(f.clone(), arg)),
+      value => stuck(value),
+    }
+  }
+
+  // Reads back a value under `depth` term and `type_depth` type variables.
+  fn quote(&mut self, value: &Value, depth: usize, type_depth: usize) -> Result<Term, EvalError> {
+    Ok(match value {
+      Value::Int(n) => Term::Int(*n),
+      Value::Unit => Term::Unit,
+      Value::Abs(hint, param_type, body) => {
+        self.quote_abs(hint, param_type, body, depth, type_depth)?
+      }
+      Value::TypeAbs(hint, kind, body) => Term::UniversalAbs {
+        hint: (*hint).clone(),
+        kind: (*kind).clone(),
+        body: Box::new(self.quote_under(body, 0, &[var_type(type_depth)], depth, type_depth)?),
+      },
+      Value::Pack(witness, term, typ) => Term::Pack {
+        witness: quote_type(witness, type_depth),
+        term: self.quote_boxed(term, depth, type_depth)?,
+        typ: quote_type(&typ.eval(), type_depth),
+      },
+      Value::Pair(first, second) => Term::Pair(
+        self.quote_boxed(first, depth, type_depth)?,
+        self.quote_boxed(second, depth, type_depth)?,
+      ),
+      Value::Inl(term, typ) => Term::Inl(
+        self.quote_boxed(term, depth, type_depth)?,
+        quote_type(&typ.eval(), type_depth),
+      ),
+      Value::Inr(term, typ) => Term::Inr(
+        self.quote_boxed(term, depth, type_depth)?,
+        quote_type(&typ.eval(), type_depth),
+      ),
+      Value::Record(fields) => Term::Record(
+        fields
+          .iter()
+          .map(|(label, term)| {
+            Ok((
+              String::from(*label),
+              *self.quote_boxed(term, depth, type_depth)?,
+            ))
+          })
+          .collect::<Result<_, EvalError>>()?,
+      ),
+      Value::Tag(label, term, typ) => Term::Tag {
+        label: String::from(*label),
+        term: self.quote_boxed(term, depth, type_depth)?,
+        typ: quote_type(&typ.eval(), type_depth),
+      },
+      Value::Fold(typ, term) => Term::Fold(
+        quote_type(&typ.eval(), type_depth),
+        self.quote_boxed(term, depth, type_depth)?,
+      ),
+      Value::Fix(hint, param_type, body) => Term::Fix(Box::new(
+        self.quote_abs(hint, param_type, body, depth, type_depth)?,
+      )),
+      Value::Neutral(neutral) => self.quote_neutral(neutral, depth, type_depth)?,
+    })
+  }
+
+  fn quote_boxed(
+    &mut self,
+    value: &Value,
+    depth: usize,
+    type_depth: usize,
+  ) -> Result<Box<Term>, EvalError> {
+    Ok(Box::new(self.quote(value, depth, type_depth)?))
+  }
+
+  fn quote_abs(
+    &mut self,
+    hint: &Hint,
+    param_type: &TypeClosure,
+    body: &Closure,
+    depth: usize,
+    type_depth: usize,
+  ) -> Result<Term, EvalError> {
+    Ok(Term::Abs {
+      hint: hint.clone(),
+      param_type: quote_type(&param_type.eval(), type_depth),
+      body: Box::new(self.quote_under(body, 1, &[], depth, type_depth)?),
+    })
+  }
+
+  // Reads back the body of a closure under `terms` fresh term variables and
+  // a type variable for each of `types`.
+  fn quote_under<'a>(
+    &mut self,
+    body: &Closure<'a>,
+    terms: usize,
+    types: &[VType<'a>],
+    depth: usize,
+    type_depth: usize,
+  ) -> Result<Term, EvalError> {
+    let env = (depth..depth + terms).fold(body.env.clone(), |env, level| env.bind(var(level)));
+    let env = types
+      .iter()
+      .fold(env, |env, typ| env.bind_type(typ.clone()));
+    let value = self.eval(body.body, &env)?;
+    self.quote(&value, depth + terms, type_depth + types.len())
+  }
+
+  fn quote_neutral(
+    &mut self,
+    neutral: &Neutral,
+    depth: usize,
+    type_depth: usize,
+  ) -> Result<Term, EvalError> {
+    Ok(match neutral {
+      Neutral::Var(level) => Term::Var(depth - 1 - level),
+      Neutral::App(f, arg) => Term::App(
+        self.quote_boxed(f, depth, type_depth)?,
+        self.quote_boxed(arg, depth, type_depth)?,
+      ),
+      Neutral::TypeApp(term, typ) => Term::UniversalApp(
+        self.quote_boxed(term, depth, type_depth)?,
+        quote_type(typ, type_depth),
+      ),
+      Neutral::Unpack(type_hint, hint, package, body) => Term::Unpack {
+        type_hint: (*type_hint).clone(),
+        hint: (*hint).clone(),
+        package: self.quote_boxed(package, depth, type_depth)?,
+        body: Box::new(self.quote_under(body, 1, &[var_type(type_depth)], depth, type_depth)?),
+      },
+      Neutral::Proj(term, i) => Term::Proj(self.quote_boxed(term, depth, type_depth)?, *i),
+      Neutral::Case(scrutinee, left_hint, left, right_hint, right) => Term::Case {
+        scrutinee: self.quote_boxed(scrutinee, depth, type_depth)?,
+        left_hint: (*left_hint).clone(),
+        left: Box::new(self.quote_under(left, 1, &[], depth, type_depth)?),
+        right_hint: (*right_hint).clone(),
+        right: Box::new(self.quote_under(right, 1, &[], depth, type_depth)?),
+      },
+      Neutral::Field(term, label) => Term::Field(
+        self.quote_boxed(term, depth, type_depth)?,
+        String::from(*label),
+      ),
+      Neutral::VariantCase(scrutinee, branches, env) => Term::VariantCase {
+        scrutinee: self.quote_boxed(scrutinee, depth, type_depth)?,
+        branches: branches
+          .iter()
+          .map(|branch| {
+            Ok(Branch {
+              label: branch.label.clone(),
+              hint: branch.hint.clone(),
+              body: self.quote_under(&env.closure(&branch.body), 1, &[], depth, type_depth)?,
+            })
+          })
+          .collect::<Result<_, EvalError>>()?,
+      },
+      Neutral::Seq(first, second) => Term::Seq(
+        self.quote_boxed(first, depth, type_depth)?,
+        self.quote_boxed(second, depth, type_depth)?,
+      ),
+      Neutral::Fix(term) => Term::Fix(self.quote_boxed(term, depth, type_depth)?),
+      Neutral::Unfold(typ, term) => Term::Unfold(
+        quote_type(&typ.eval(), type_depth),
+        self.quote_boxed(term, depth, type_depth)?,
+      ),
+    })
+  }
+}
+
+impl Term {
+  // The beta-normal form of the closed term, failing if evaluation nests
+  // deeper than `limit`.
+  pub fn normalize_with_limit(&self, limit: usize) -> Result<Term, EvalError> {
+    let normalize = || {
+      let mut normalizer = Normalizer { depth: 0, limit };
+      let value = normalizer.eval(self, &Env::default())?;
+      normalizer.quote(&value, 0, 0)
+    };
+    std::thread::scope(|scope| {
+      std::thread::Builder::new()
+        .stack_size(STACK_SIZE + limit * STACK_PER_EVALUATION)
+        .spawn_scoped(scope, normalize)
+        .expect("failed to spawn the normalization thread")
+        .join()
+        .unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+    })
+  }
+
+  pub fn normalize(&self) -> Result<Term, EvalError> {
+    self.normalize_with_limit(DEPTH_LIMIT)
+  }
+}
+
+// Typechecks `term` and computes its normal form.
+pub fn normalize(term: &ast::Term) -> Result<ast::Term, EvalError> {
+  Ok(compile(term)?.normalize()?.to_ast(&Context::new()))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::strategy::{Evaluation, Strategy};
+  use crate::{parse, parse_type};
+
+  fn numeral(n: usize) -> String {
+    (0..n).fold(String::from("zero"), |m, _| format!("succ ({})", m))
+  }
+
+  fn normal_form(input: &str) -> String {
+    normalize(&parse(input).unwrap()).unwrap().to_string()
+  }
+
+  #[test]
+  fn normalizes_under_binders() {
+    assert_eq!(normal_form("λx: Int. (λy: Int. y) x"), "λx: Int. x");
+    assert_eq!(
+      normal_form("ΛX: *. λf: X -> X. λx: X. (λg: X -> X. g (g x)) f"),
+      "ΛX: *. λf: X -> X. λx: X. f (f x)"
+    );
+    assert_eq!(
+      normal_form("λp: Int × Bool. (λq: Int × Bool. q.1) p"),
+      "λp: Int × Bool. p.1"
+    );
+    assert_eq!(
+      normal_form("λs: Int + Int. case s of inl a => (λy: Int. y) a | inr b => b"),
+      "λs: Int + Int. case s of inl a => a | inr b => b"
+    );
+    assert_eq!(
+      normal_form("let rec f: Int -> Int = λn: Int. (λm: Int. m) n in f"),
+      "fix (λf: Int -> Int. λn: Int. n)"
+    );
+  }
+
+  #[test]
+  fn agrees_with_normal_order() {
+    let inputs = [
+      "(λi: ∀X: *. X -> X. i i 1) (ΛX: *. λx. x)".to_string(),
+      "λx: Int. (λf: Int -> Int. f x) (λy: Int. y)".to_string(),
+      "unpack pack [Int, {f = λx: Int. x, z = 1}] as ∃X: *. {f: X -> X, z: X} as [X, r] in (λy: X. 2) (r.f r.z)".to_string(),
+      "λr: {a: Int, b: Unit}. (); r.a".to_string(),
+      "λv: <a: Unit, b: Int>. case v of { <a = u> => (λx: Int. x) 0 | <b = n> => n }".to_string(),
+      "λx: μL. Unit + L. unfold [μL. Unit + L] (fold [μL. Unit + L] (unfold [μL. Unit + L] x))".to_string(),
+      format!("times ({}) ({})", numeral(2), numeral(3)),
+      format!("plus ({})", numeral(2)),
+      format!("pred ({})", numeral(3)),
+      "map [Int] [Int] (λx. x)".to_string(),
+    ];
+    for input in &inputs {
+      let term = compile(&parse(input).unwrap()).unwrap();
+      let expected = Evaluation::new(Strategy::NormalOrder)
+        .eval(&term, 100_000)
+        .unwrap();
+      assert_eq!(term.normalize(), Ok(expected), "{}", input);
+    }
+  }
+
+  #[test]
+  fn gives_up_on_divergence() {
+    let term = compile(&parse("let rec f: Int -> Int = λn: Int. f n in f 3").unwrap()).unwrap();
+    assert_eq!(
+      term.normalize_with_limit(100),
+      Err(EvalError::DepthLimitExceeded(100))
+    );
+  }
+
+  #[test]
+  fn normalizes_types() {
+    let operator = Kind::Arrow(Box::new(Kind::Star), Box::new(Kind::Star));
+    let ctx = Context::new().assign_type(String::from("F"), operator.clone());
+    let from_ast =
+      |ctx: &Context, input: &str| Type::from_ast(ctx, &parse_type(input).unwrap()).unwrap();
+    let typ = from_ast(&ctx, "∀X: *. F X -> F (F X)");
+    assert_eq!(typ.normalize(1), typ);
+    assert!(typ.equiv(&from_ast(&ctx, "∀Y: *. F Y -> F (F Y)"), 1));
+    // Uses of an alias are applications of a type-level function.
+    let alias = from_ast(&ctx.assign_type(String::from("A"), Kind::Star), "A × F A");
+    let typ = from_ast(
+      &ctx.assign_type(String::from("Twice"), operator),
+      "∀X: *. Twice (Twice X)",
+    );
+    assert_eq!(
+      expand_alias(&typ, &alias, 1, 1).to_ast(&ctx),
+      parse_type("∀X: *. (X × F X) × F (X × F X)").unwrap()
+    );
+  }
+}
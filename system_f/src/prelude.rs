@@ -0,0 +1,379 @@
+use crate::ast::{Term, Type};
+use crate::grammar::{TermParser, TypeParser};
+use crate::spans::SpanStack;
+use std::sync::OnceLock;
+
+// Church encodings of common data types.
+//
+// Every definition is in scope in the initial typing context, so programs can
+// use `succ`, `pair`, `cons`, ... without defining them. A value of an encoded
+// type is its own eliminator: a boolean chooses between two alternatives, a
+// natural number n applies a function n times, a list folds itself, and so on.
+//
+// Definitions may use the ones before them. Their terms are checked against
+// their declared types, so they can rely on bidirectional typechecking and
+// implicit type application to leave types out.
+
+// Named Boolean to keep it apart from the built-in Bool.
+pub const BOOLEAN: &str = "∀X: *. X -> X -> X";
+
+pub const NAT: &str = "∀X: *. (X -> X) -> X -> X";
+
+pub fn pair(a: &str, b: &str) -> String {
+  format!("∀R: *. (({}) -> ({}) -> R) -> R", a, b)
+}
+
+pub fn sum(a: &str, b: &str) -> String {
+  format!("∀R: *. (({}) -> R) -> (({}) -> R) -> R", a, b)
+}
+
+pub fn list(a: &str) -> String {
+  format!("∀R: *. (({}) -> R -> R) -> R -> R", a)
+}
+
+pub fn maybe(a: &str) -> String {
+  format!("∀R: *. R -> (({}) -> R) -> R", a)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Definition {
+  pub name: String,
+  pub typ: Type,
+  pub term: Term,
+}
+
+// The definitions, parsed the first time they are needed.
+pub fn definitions() -> &'static [Definition] {
+  static DEFINITIONS: OnceLock<Vec<Definition>> = OnceLock::new();
+  DEFINITIONS.get_or_init(parse_definitions)
+}
+
+fn parse_definitions() -> Vec<Definition> {
+  // Building a parser compiles its lexer, so both are reused.
+  let (type_parser, term_parser) = (TypeParser::new(), TermParser::new());
+  let define = |name: &str, typ: &str, term: &str| Definition {
+    name: String::from(name),
+    typ: type_parser.parse(&mut SpanStack::default(), typ).unwrap(),
+    term: term_parser.parse(&mut SpanStack::default(), term).unwrap(),
+  };
+  let boolean = format!("({})", BOOLEAN);
+  let nat = format!("({})", NAT);
+  let nat_pair = format!("({})", pair(NAT, NAT));
+  vec![
+    // Booleans
+    define("true", BOOLEAN, "ΛX: *. λt. λf. t"),
+    define("false", BOOLEAN, "ΛX: *. λt. λf. f"),
+    define(
+      "not",
+      &format!("{} -> {}", boolean, boolean),
+      "λb. ΛX: *. λt. λf. b [X] f t",
+    ),
+    define(
+      "and",
+      &format!("{} -> {} -> {}", boolean, boolean, boolean),
+      "λa. λb. ΛX: *. λt. λf. a [X] (b [X] t f) f",
+    ),
+    define(
+      "or",
+      &format!("{} -> {} -> {}", boolean, boolean, boolean),
+      "λa. λb. ΛX: *. λt. λf. a [X] t (b [X] t f)",
+    ),
+    // Pairs
+    define(
+      "pair",
+      &format!("∀A: *. ∀B: *. A -> B -> ({})", pair("A", "B")),
+      "ΛA: *. ΛB: *. λa. λb. ΛR: *. λk. k a b",
+    ),
+    define(
+      "fst",
+      &format!("∀A: *. ∀B: *. ({}) -> A", pair("A", "B")),
+      "ΛA: *. ΛB: *. λp. p [A] (λa. λb. a)",
+    ),
+    define(
+      "snd",
+      &format!("∀A: *. ∀B: *. ({}) -> B", pair("A", "B")),
+      "ΛA: *. ΛB: *. λp. p [B] (λa. λb. b)",
+    ),
+    // Natural numbers
+    define("zero", NAT, "ΛX: *. λs. λz. z"),
+    define(
+      "succ",
+      &format!("{} -> {}", nat, nat),
+      "λn. ΛX: *. λs. λz. s (n [X] s z)",
+    ),
+    define(
+      "plus",
+      &format!("{} -> {} -> {}", nat, nat, nat),
+      "λm. λn. ΛX: *. λs. λz. m [X] s (n [X] s z)",
+    ),
+    define(
+      "times",
+      &format!("{} -> {} -> {}", nat, nat, nat),
+      "λm. λn. ΛX: *. λs. m [X] (n [X] s)",
+    ),
+    define(
+      "isZero",
+      &format!("{} -> {}", nat, boolean),
+      &format!("λn. n [{}] (λb. false) true", boolean),
+    ),
+    // Counts up to n with pairs (i - 1, i) and keeps the first component.
+    define(
+      "pred",
+      &format!("{} -> {}", nat, nat),
+      &format!(
+        "λn. fst [{nat}] [{nat}] (n [{nat_pair}] (λp. pair [{nat}] [{nat}] (snd [{nat}] [{nat}] p) (succ (snd [{nat}] [{nat}] p))) (pair [{nat}] [{nat}] zero zero))",
+        nat = nat,
+        nat_pair = nat_pair,
+      ),
+    ),
+    define(
+      "minus",
+      &format!("{} -> {} -> {}", nat, nat, nat),
+      &format!("λm. λn. n [{}] pred m", nat),
+    ),
+    define(
+      "equal",
+      &format!("{} -> {} -> {}", nat, nat, boolean),
+      "λm. λn. and (isZero (minus m n)) (isZero (minus n m))",
+    ),
+    // Sums
+    define(
+      "left",
+      &format!("∀A: *. ∀B: *. A -> ({})", sum("A", "B")),
+      "ΛA: *. ΛB: *. λa. ΛR: *. λl. λr. l a",
+    ),
+    define(
+      "right",
+      &format!("∀A: *. ∀B: *. B -> ({})", sum("A", "B")),
+      "ΛA: *. ΛB: *. λb. ΛR: *. λl. λr. r b",
+    ),
+    define(
+      "either",
+      &format!(
+        "∀A: *. ∀B: *. ∀R: *. (A -> R) -> (B -> R) -> ({}) -> R",
+        sum("A", "B")
+      ),
+      "ΛA: *. ΛB: *. ΛR: *. λl. λr. λs. s [R] l r",
+    ),
+    // Maybe
+    define(
+      "none",
+      &format!("∀A: *. {}", maybe("A")),
+      "ΛA: *. ΛR: *. λn. λs. n",
+    ),
+    define(
+      "some",
+      &format!("∀A: *. A -> ({})", maybe("A")),
+      "ΛA: *. λx. ΛR: *. λn. λs. s x",
+    ),
+    define(
+      "maybe",
+      &format!("∀A: *. ∀R: *. R -> (A -> R) -> ({}) -> R", maybe("A")),
+      "ΛA: *. ΛR: *. λn. λs. λm. m [R] n s",
+    ),
+    // Lists
+    define(
+      "nil",
+      &format!("∀A: *. {}", list("A")),
+      "ΛA: *. ΛR: *. λc. λn. n",
+    ),
+    define(
+      "cons",
+      &format!("∀A: *. A -> ({}) -> ({})", list("A"), list("A")),
+      "ΛA: *. λx. λxs. ΛR: *. λc. λn. c x (xs [R] c n)",
+    ),
+    define(
+      "foldr",
+      &format!("∀A: *. ∀R: *. (A -> R -> R) -> R -> ({}) -> R", list("A")),
+      "ΛA: *. ΛR: *. λc. λn. λxs. xs [R] c n",
+    ),
+    define(
+      "isNil",
+      &format!("∀A: *. ({}) -> {}", list("A"), boolean),
+      &format!("ΛA: *. λxs. xs [{}] (λx. λb. false) true", boolean),
+    ),
+    define(
+      "length",
+      &format!("∀A: *. ({}) -> {}", list("A"), nat),
+      &format!("ΛA: *. λxs. xs [{}] (λx. succ) zero", nat),
+    ),
+    define(
+      "map",
+      &format!(
+        "∀A: *. ∀B: *. (A -> B) -> ({}) -> ({})",
+        list("A"),
+        list("B")
+      ),
+      &format!(
+        "ΛA: *. ΛB: *. λf. λxs. xs [{}] (λx. cons [B] (f x)) (nil [B])",
+        list("B")
+      ),
+    ),
+    define(
+      "append",
+      &format!(
+        "∀A: *. ({}) -> ({}) -> ({})",
+        list("A"),
+        list("A"),
+        list("A")
+      ),
+      &format!("ΛA: *. λxs. λys. xs [{}] (cons [A]) ys", list("A")),
+    ),
+    define(
+      "head",
+      &format!("∀A: *. ({}) -> ({})", list("A"), maybe("A")),
+      &format!(
+        "ΛA: *. λxs. xs [{}] (λx. λr. some [A] x) (none [A])",
+        maybe("A")
+      ),
+    ),
+  ]
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{debruijn, typechecker};
+
+  fn eval(input: &str) -> Term {
+    debruijn::eval(&crate::parse(input).unwrap()).unwrap()
+  }
+
+  fn numeral(n: usize) -> String {
+    (0..n).fold(String::from("zero"), |m, _| format!("succ ({})", m))
+  }
+
+  fn assert_boolean(input: &str, expected: bool) {
+    let result = eval(&format!("({}) [Int] 1 0", input));
+    assert_eq!(result, Term::Int(expected as i32), "{}", input);
+  }
+
+  fn assert_nat(input: &str, expected: usize) {
+    assert_boolean(&format!("equal ({}) ({})", input, numeral(expected)), true);
+    assert_boolean(
+      &format!("equal ({}) ({})", input, numeral(expected + 1)),
+      false,
+    );
+  }
+
+  #[test]
+  fn definitions_have_their_declared_types() {
+    let definitions = typechecker::elaborate_prelude().unwrap();
+    assert_eq!(definitions.len(), super::definitions().len());
+    // Both are only computed once.
+    assert!(std::ptr::eq(
+      definitions,
+      typechecker::elaborate_prelude().unwrap()
+    ));
+    assert!(std::ptr::eq(super::definitions(), super::definitions()));
+    for definition in definitions.iter() {
+      // The elaborated term is fully annotated, so its type can be synthesized.
+      let typ = typechecker::infer(&Term::Ann(
+        Box::new(definition.term.clone()),
+        definition.typ.clone(),
+      ))
+      .unwrap();
+      assert!(
+        debruijn::alpha_eq(&typ, &definition.typ),
+        "{}",
+        definition.name
+      );
+    }
+  }
+
+  #[test]
+  fn definitions_are_in_scope() {
+    assert_eq!(
+      typechecker::infer(&crate::parse("succ zero").unwrap()),
+      Ok(crate::parse_type(NAT).unwrap())
+    );
+  }
+
+  #[test]
+  fn definitions_can_be_shadowed() {
+    assert_eq!(eval("(λsucc: Int. succ) 1"), Term::Int(1));
+  }
+
+  #[test]
+  fn booleans() {
+    assert_boolean("true", true);
+    assert_boolean("false", false);
+    assert_boolean("not true", false);
+    assert_boolean("not false", true);
+    for (a, b) in [(true, true), (true, false), (false, true), (false, false)] {
+      assert_boolean(&format!("and {} {}", a, b), a && b);
+      assert_boolean(&format!("or {} {}", a, b), a || b);
+    }
+  }
+
+  #[test]
+  fn natural_numbers() {
+    assert_nat("zero", 0);
+    assert_nat(&numeral(3), 3);
+    assert_nat(&format!("plus ({}) ({})", numeral(2), numeral(3)), 5);
+    assert_nat(&format!("times ({}) ({})", numeral(2), numeral(3)), 6);
+    assert_nat(&format!("pred ({})", numeral(4)), 3);
+    assert_nat("pred zero", 0);
+    assert_nat(&format!("minus ({}) ({})", numeral(5), numeral(2)), 3);
+    assert_nat(&format!("minus ({}) ({})", numeral(2), numeral(5)), 0);
+    assert_boolean("isZero zero", true);
+    assert_boolean(&format!("isZero ({})", numeral(1)), false);
+    // Church numerals iterate: 3 applications of λx. x counted from 7.
+    assert_eq!(
+      eval(&format!("({}) [Int] (λx. x) 7", numeral(3))),
+      Term::Int(7)
+    );
+  }
+
+  #[test]
+  fn pairs() {
+    assert_eq!(eval("fst (pair 1 2)"), Term::Int(1));
+    assert_eq!(eval("snd (pair 1 2)"), Term::Int(2));
+    assert_boolean(
+      &format!("snd [Int] [{b}] (pair [Int] [{b}] 1 false)", b = BOOLEAN),
+      false,
+    );
+  }
+
+  #[test]
+  fn sums() {
+    let to_int = format!("either [Int] [{}] [Int] (λx. x) (λb. b [Int] 1 0)", BOOLEAN);
+    assert_eq!(
+      eval(&format!("{} (left [Int] [{}] 5)", to_int, BOOLEAN)),
+      Term::Int(5)
+    );
+    assert_eq!(
+      eval(&format!("{} (right [Int] [{}] true)", to_int, BOOLEAN)),
+      Term::Int(1)
+    );
+  }
+
+  #[test]
+  fn maybes() {
+    assert_eq!(eval("maybe 0 (λx. x) (some 5)"), Term::Int(5));
+    assert_eq!(eval("maybe 0 (λx. x) (none [Int])"), Term::Int(0));
+  }
+
+  #[test]
+  fn lists() {
+    let xs = "cons 1 (cons 2 (cons 3 (nil [Int])))";
+    assert_eq!(eval(&format!("foldr (λx. λr. x) 0 ({})", xs)), Term::Int(1));
+    assert_eq!(
+      eval(&format!("maybe 0 (λx. x) (head ({}))", xs)),
+      Term::Int(1)
+    );
+    assert_eq!(eval("maybe 0 (λx. x) (head (nil [Int]))"), Term::Int(0));
+    assert_nat(&format!("length [Int] ({})", xs), 3);
+    assert_nat(&format!("length [Int] (append [Int] ({}) ({}))", xs, xs), 6);
+    assert_boolean("isNil [Int] (nil [Int])", true);
+    assert_boolean(&format!("isNil [Int] ({})", xs), false);
+    assert_eq!(
+      eval(&format!(
+        "foldr [{b}] [{b}] (λx. λr. x) false (map [Int] [{b}] (λx. true) ({xs}))",
+        b = BOOLEAN,
+        xs = xs
+      )),
+      eval("true"),
+    );
+  }
+}
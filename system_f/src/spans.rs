@@ -0,0 +1,179 @@
+use crate::ast::Term;
+use lambda_core::Span;
+
+// Where each node of a parsed term came from in the source.
+//
+// The tree has the shape of the term: the children of a node are the trees of
+// its `subterms`, in the same order. `names` holds the spans of the term
+// variables the node binds, in the order they are written. Nodes the parser
+// introduces while desugaring, such as the fix and λ of a `let rec`, span the
+// term they wrap.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpanTree {
+  pub span: Span,
+  pub names: Vec<Span>,
+  pub children: Vec<SpanTree>,
+}
+
+impl SpanTree {
+  // The path of child indices to the innermost node containing `offset`.
+  pub fn path_to(&self, offset: usize) -> Vec<usize> {
+    let mut path = Vec::new();
+    let mut tree = self;
+    while let Some((i, child)) = tree
+      .children
+      .iter()
+      .enumerate()
+      .find(|(_, child)| child.span.start <= offset && offset < child.span.end)
+    {
+      path.push(i);
+      tree = child;
+    }
+    path
+  }
+}
+
+// Collects span trees while parsing. Every grammar action that builds a term
+// pops the trees of its subterms, which the parser has just pushed in order,
+// and pushes its own.
+#[derive(Debug, Default)]
+pub struct SpanStack(Vec<SpanTree>);
+
+impl SpanStack {
+  pub fn node(&mut self, start: usize, end: usize, children: usize, names: Vec<Span>) {
+    let children = self.0.split_off(self.0.len() - children);
+    self.0.push(SpanTree {
+      span: Span::new(start, end),
+      names,
+      children,
+    });
+  }
+
+  pub fn pop(&mut self) -> SpanTree {
+    self.0.pop().unwrap()
+  }
+
+  pub fn push(&mut self, tree: SpanTree) {
+    self.0.push(tree)
+  }
+
+  // The tree of the term that was parsed last.
+  pub fn finish(mut self) -> SpanTree {
+    self.pop()
+  }
+}
+
+// The direct subterms of `term`, in the order they are written.
+pub fn subterms(term: &Term) -> Vec<&Term> {
+  match term {
+    Term::Int(_) | Term::Unit | Term::Var(_) => Vec::new(),
+    Term::App(first, second)
+    | Term::Pair(first, second)
+    | Term::Seq(first, second)
+    | Term::Let {
+      value: first,
+      body: second,
+      ..
+    }
+    | Term::Unpack {
+      package: first,
+      body: second,
+      ..
+    } => vec![first, second],
+    Term::Abs { body: term, .. }
+    | Term::Ann(term, _)
+    | Term::UniversalAbs { body: term, .. }
+    | Term::UniversalApp(term, _)
+    | Term::Pack { term, .. }
+    | Term::TypeLet { body: term, .. }
+    | Term::Proj(term, _)
+    | Term::Inl(term, _)
+    | Term::Inr(term, _)
+    | Term::Field(term, _)
+    | Term::Tag { term, .. }
+    | Term::Fix(term)
+    | Term::Fold(_, term)
+    | Term::Unfold(_, term) => vec![term],
+    Term::Case {
+      scrutinee,
+      left,
+      right,
+      ..
+    } => vec![scrutinee, left, right],
+    Term::Record(fields) => fields.iter().map(|(_, term)| term).collect(),
+    Term::VariantCase {
+      scrutinee,
+      branches,
+    } => std::iter::once(&**scrutinee)
+      .chain(branches.iter().map(|branch| &branch.body))
+      .collect(),
+  }
+}
+
+// The term variables bound in the subterm `i` of `term`, each with its index
+// in `names` of the term's span tree.
+pub fn binders(term: &Term, i: usize) -> Vec<(&String, usize)> {
+  match (term, i) {
+    (Term::Abs { param_name, .. }, 0) => vec![(param_name, 0)],
+    (Term::Let { name, .. }, 1) => vec![(name, 0)],
+    (Term::Unpack { var, .. }, 1) => vec![(var, 0)],
+    (Term::Case { left_var, .. }, 1) => vec![(left_var, 0)],
+    (Term::Case { right_var, .. }, 2) => vec![(right_var, 1)],
+    (Term::VariantCase { branches, .. }, i) if i > 0 => vec![(&branches[i - 1].var, i - 1)],
+    _ => Vec::new(),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::prelude;
+
+  // Checks that `tree` has the shape of `term` and that every span lies
+  // within the span of its parent.
+  fn assert_shape(input: &str, term: &Term, tree: &SpanTree) {
+    let subterms = subterms(term);
+    assert_eq!(subterms.len(), tree.children.len(), "{} in {}", term, input);
+    for (subterm, child) in subterms.into_iter().zip(&tree.children) {
+      assert!(tree.span.start <= child.span.start && child.span.end <= tree.span.end);
+      assert_shape(input, subterm, child);
+    }
+  }
+
+  fn text(input: &str, span: Span) -> &str {
+    &input[span.start..span.end]
+  }
+
+  #[test]
+  fn span_trees_have_the_shape_of_terms() {
+    let mut inputs = vec![
+      String::from("let rec f: Int -> Int = λx. f x in (f 1, ())"),
+      String::from("case <a = 1> as <a: Int, b: Unit> of { <a = x> => x | <b = y> => 0 }"),
+      String::from("unpack pack [Int, {f = 1}] as ∃X: *. {f: X} as [X, p] in p.f"),
+      String::from("type T = Int; case inl 1 as T + Unit of inl x => x | inr y => (0 : T)"),
+      String::from("fold [μL. Unit + L] (inl () as Unit + (μL. Unit + L)); unfold [Int] 1"),
+    ];
+    inputs.extend(prelude::definitions().iter().map(|d| d.term.to_string()));
+    for input in &inputs {
+      let (term, tree) = crate::parse_with_spans(input).unwrap();
+      assert_shape(input, &term, &tree);
+    }
+  }
+
+  #[test]
+  fn spans_point_into_the_source() {
+    let input = "let rec f: Int -> Int = λx. f x in f (1)";
+    let (term, tree) = crate::parse_with_spans(input).unwrap();
+    assert_eq!(text(input, tree.span), input);
+    assert_eq!(text(input, tree.names[0]), "f");
+    // The λ and fix of the desugared `let rec` span its value.
+    assert_eq!(text(input, tree.children[0].span), "λx. f x");
+    assert_eq!(text(input, tree.children[0].children[0].names[0]), "f");
+    let body = &tree.children[1];
+    assert_eq!(text(input, body.span), "f (1)");
+    assert_eq!(text(input, body.children[1].span), "1");
+    let offset = input.rfind('1').unwrap();
+    assert_eq!(tree.path_to(offset), vec![1, 1]);
+    assert_eq!(binders(&term, 1), vec![(&String::from("f"), 0)]);
+  }
+}
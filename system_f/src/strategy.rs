@@ -0,0 +1,664 @@
+use crate::debruijn::{field, Context, EvalError, Term};
+use lambda_core::trace::{Redex, Step, Trace, HOLE};
+use lambda_core::Hint;
+use std::cell::RefCell;
+use std::fmt;
+use std::str::FromStr;
+use thiserror::Error;
+
+// Evaluation strategies: which redex of a term is reduced next.
+//
+// Call-by-value, call-by-name and call-by-need are weak: they never reduce
+// under a binder, and stop at a value. They share the rules in
+// `typechecker.rs` for everything but the substitution of arguments and let
+// bindings. Call-by-value substitutes values only, call-by-name substitutes
+// the argument as it is,
+//
+// ------------------------
+// (λx: τ. t) t' |> t[t'/x]
+//
+// and call-by-need puts the argument on a heap, where it is evaluated the
+// first time it is looked up and then shared by every use of x.
+//
+//     ℓ fresh    H' = H, ℓ ↦ t'
+// -----------------------------------
+// H | (λx: τ. t) t' |> H' | t[ℓ/x]
+//
+//
+//   H(ℓ) = v
+// --------------
+// H | ℓ |> H | v
+//
+//
+//         H | t |> H' | t'
+// -------------------------------
+// H, ℓ ↦ t | ℓ |> H', ℓ ↦ t' | ℓ
+//
+// The constructors, such as pairs and injections, evaluate their components
+// to values in all three, so that values can be copied without duplicating
+// work.
+//
+// Normal order, applicative order and full beta are strong: they reduce
+// everywhere, under binders too, until the term is in normal form. Normal
+// order reduces the leftmost outermost redex, applicative order the leftmost
+// innermost one, and full beta reduces by value first, then inside the
+// subterms from left to right, and only then the redexes whose arguments are
+// stuck on a variable, as in (λy. y) x under λx. A
+// recursive function defined with fix has no normal form, since its body
+// unfolds forever.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Strategy {
+  CallByValue,
+  CallByName,
+  CallByNeed,
+  NormalOrder,
+  ApplicativeOrder,
+  FullBeta,
+}
+
+impl Strategy {
+  pub const ALL: [Strategy; 6] = [
+    Strategy::CallByValue,
+    Strategy::CallByName,
+    Strategy::CallByNeed,
+    Strategy::NormalOrder,
+    Strategy::ApplicativeOrder,
+    Strategy::FullBeta,
+  ];
+
+  // Whether arguments are substituted before they are evaluated.
+  pub fn is_lazy(self) -> bool {
+    matches!(self, Strategy::CallByName | Strategy::CallByNeed)
+  }
+
+  // Whether the strategy reduces under binders.
+  pub fn is_strong(self) -> bool {
+    matches!(
+      self,
+      Strategy::NormalOrder | Strategy::ApplicativeOrder | Strategy::FullBeta
+    )
+  }
+
+  fn name(self) -> &'static str {
+    match self {
+      Strategy::CallByValue => "cbv",
+      Strategy::CallByName => "cbn",
+      Strategy::CallByNeed => "need",
+      Strategy::NormalOrder => "normal",
+      Strategy::ApplicativeOrder => "applicative",
+      Strategy::FullBeta => "full",
+    }
+  }
+}
+
+impl fmt::Display for Strategy {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{}", self.name())
+  }
+}
+
+#[derive(Debug, PartialEq, Error)]
+#[error(
+  "unknown evaluation strategy {0}, expected one of cbv, cbn, need, normal, applicative, full"
+)]
+pub struct UnknownStrategy(pub String);
+
+impl FromStr for Strategy {
+  type Err = UnknownStrategy;
+
+  fn from_str(name: &str) -> Result<Strategy, UnknownStrategy> {
+    Strategy::ALL
+      .iter()
+      .copied()
+      .find(|strategy| strategy.name() == name)
+      .ok_or_else(|| UnknownStrategy(String::from(name)))
+  }
+}
+
+// The variable that refers to the cell ℓ of the heap is the free variable
+// HEAP + ℓ. Weak strategies never reduce under a binder, so every variable
+// they look up has this index exactly.
+const HEAP: usize = HOLE / 2;
+
+// The state of an evaluation: its strategy, the heap of call-by-need, with a
+// hint for the name of the variable each cell was bound to, and the redex of
+// the step being taken.
+#[derive(Debug, Clone)]
+pub struct Evaluation {
+  strategy: Strategy,
+  heap: Vec<(Hint, Term)>,
+  redex: Redex,
+}
+
+impl Evaluation {
+  pub fn new(strategy: Strategy) -> Self {
+    Evaluation {
+      strategy,
+      heap: Vec::new(),
+      redex: Redex::default(),
+    }
+  }
+
+  pub fn strategy(&self) -> Strategy {
+    self.strategy
+  }
+
+  // Performs a single step, or returns `None` when the term is in normal form
+  // for the strategy or stuck.
+  pub fn step(&mut self, term: &Term) -> Option<(Term, Redex)> {
+    self.redex = Redex::default();
+    let next = term.reduce(self)?;
+    Some((next, std::mem::take(&mut self.redex)))
+  }
+
+  // Evaluates the closed term, failing if it takes more than `limit` steps.
+  // The cells of the heap that the result refers to are bound around it with
+  // `let`.
+  pub fn eval(&mut self, term: &Term, limit: usize) -> Result<Term, EvalError> {
+    let mut term = term.clone();
+    let mut steps = 0;
+    while let Some((next, _)) = self.step(&term) {
+      if steps == limit {
+        return Err(EvalError::StepLimitExceeded(limit));
+      }
+      term = next;
+      steps += 1;
+    }
+    Ok(self.read_back(&term, &self.cells(&term)))
+  }
+
+  // Evaluates the closed term like `eval`, recording every step. For
+  // call-by-need, each term is shown with the heap it refers to.
+  pub fn trace(&mut self, term: &Term, limit: usize) -> Result<Trace, EvalError> {
+    let ctx = Context::new();
+    let mut term = term.clone();
+    let mut steps = Vec::new();
+    loop {
+      let cells = self.cells(&term);
+      let mut probe = self.clone();
+      probe.redex = Redex::hole();
+      let with_hole = match term.reduce(&mut probe) {
+        Some(with_hole) => with_hole,
+        None => break,
+      };
+      if steps.len() == limit {
+        return Err(EvalError::StepLimitExceeded(limit));
+      }
+      let printed = self.read_back(&term, &cells).to_ast(&ctx).to_string();
+      let (next, redex) = self.step(&term).unwrap();
+      let with_hole = probe.read_back(&with_hole, &cells).to_ast(&ctx);
+      steps.push(Step::new(printed, &with_hole.to_string(), redex));
+      term = next;
+    }
+    let result = self.read_back(&term, &self.cells(&term));
+    Ok(Trace {
+      steps,
+      result: result.to_ast(&ctx).to_string(),
+    })
+  }
+
+  pub(crate) fn enter(&mut self, rule: &'static str, i: usize) -> &mut Self {
+    self.redex.enter(rule, i);
+    self
+  }
+
+  // The contractum of a redex, or a hole in its place when the step is only
+  // looking for where the redex is.
+  pub(crate) fn contract(&mut self, rule: &'static str, contractum: impl FnOnce() -> Term) -> Term {
+    if self.redex.contract(rule) {
+      contractum()
+    } else {
+      Term::Var(HOLE)
+    }
+  }
+
+  // Given the body t of `λx. t`, computes t[value/x] for a lazy strategy.
+  // Call-by-need binds a value that still has to be evaluated on the heap
+  // instead.
+  pub(crate) fn bind_lazily(
+    &mut self,
+    hint: &Hint,
+    value: &Term,
+    body: &Term,
+    rule: &'static str,
+    alloc_rule: &'static str,
+  ) -> Term {
+    if self.strategy != Strategy::CallByNeed || value.is_value() {
+      return self.contract(rule, || body.instantiate(value));
+    }
+    if !self.redex.contract(alloc_rule) {
+      return Term::Var(HOLE);
+    }
+    self.heap.push((hint.clone(), value.clone()));
+    body.instantiate(&Term::Var(HEAP + self.heap.len() - 1))
+  }
+
+  // Steps the variable `i` if it refers to the heap: to its value once the
+  // cell holds one, or else by stepping the cell.
+  pub(crate) fn lookup(&mut self, i: usize) -> Option<Term> {
+    let cell = self.cell(i, 0)?;
+    let term = self.heap[cell].1.clone();
+    if term.is_value() {
+      return Some(self.contract("E-Lookup", || term));
+    }
+    self.redex.rules.push("E-Force");
+    let next = term.reduce(self)?;
+    self.heap[cell].1 = next;
+    Some(Term::Var(i))
+  }
+
+  // The cell the variable `i` refers to under `depth` binders.
+  fn cell(&self, i: usize, depth: usize) -> Option<usize> {
+    i.checked_sub(HEAP + depth)
+      .filter(|cell| *cell < self.heap.len())
+  }
+
+  // The cells `term` refers to, directly or through other cells, each after
+  // the cells it refers to.
+  fn cells(&self, term: &Term) -> Vec<usize> {
+    let mut cells = Vec::new();
+    let mut seen = vec![false; self.heap.len()];
+    self.visit(term, &mut seen, &mut cells);
+    cells
+  }
+
+  fn visit(&self, term: &Term, seen: &mut Vec<bool>, cells: &mut Vec<usize>) {
+    let found = RefCell::new(Vec::new());
+    term.map_vars(
+      0,
+      0,
+      &|c, _, i| {
+        found.borrow_mut().extend(self.cell(i, c));
+        Term::Var(i)
+      },
+      &|_, typ| typ.clone(),
+    );
+    for cell in found.into_inner() {
+      if !seen[cell] {
+        seen[cell] = true;
+        self.visit(&self.heap[cell].1, seen, cells);
+        cells.push(cell);
+      }
+    }
+  }
+
+  // `term` with `cells` bound around it by `let`, outermost first, and the
+  // heap variables replaced by the variables of those bindings.
+  fn read_back(&self, term: &Term, cells: &[usize]) -> Term {
+    let bind = |term: &Term, depth: usize| {
+      term.map_vars(
+        0,
+        0,
+        &|c, _, i| match self.cell(i, c) {
+          Some(cell) => match cells.iter().position(|bound| *bound == cell) {
+            Some(position) => Term::Var(c + depth - 1 - position),
+            None => Term::Var(i),
+          },
+          None => Term::Var(i),
+        },
+        &|_, typ| typ.clone(),
+      )
+    };
+    cells
+      .iter()
+      .enumerate()
+      .rev()
+      .fold(bind(term, cells.len()), |body, (position, cell)| {
+        let (hint, value) = &self.heap[*cell];
+        Term::Let {
+          hint: hint.clone(),
+          value: Box::new(bind(value, position)),
+          body: Box::new(body),
+        }
+      })
+  }
+
+  fn mark(&self) -> (usize, usize) {
+    (self.redex.path.len(), self.redex.rules.len())
+  }
+
+  fn reset(&mut self, (path, rules): (usize, usize)) {
+    self.redex.path.truncate(path);
+    self.redex.rules.truncate(rules);
+  }
+}
+
+impl Term {
+  // A step of a strong strategy.
+  pub(crate) fn reduce_strong(&self, ev: &mut Evaluation) -> Option<Term> {
+    match ev.strategy() {
+      Strategy::NormalOrder => self.contract_redex(ev).or_else(|| self.descend(ev)),
+      Strategy::ApplicativeOrder => self.descend(ev).or_else(|| self.contract_redex(ev)),
+      _ => {
+        let mark = ev.mark();
+        self
+          .reduce_weak(ev)
+          .or_else(|| {
+            ev.reset(mark);
+            self.descend(ev)
+          })
+          .or_else(|| self.contract_redex(ev))
+      }
+    }
+  }
+
+  // Steps the first subterm that can step.
+  fn descend(&self, ev: &mut Evaluation) -> Option<Term> {
+    for (i, child) in self.children().into_iter().enumerate() {
+      let mark = ev.mark();
+      if let Some(next) = child.reduce(ev.enter(self.congruence(i), i)) {
+        let mut term = self.clone();
+        *term.children_mut().swap_remove(i) = next;
+        return Some(term);
+      }
+      ev.reset(mark);
+    }
+    None
+  }
+
+  // Contracts the term if it is a redex, whatever its subterms are.
+  fn contract_redex(&self, ev: &mut Evaluation) -> Option<Term> {
+    match self {
+      Term::App(f, arg) => match &**f {
+        Term::Abs { body, .. } => Some(ev.contract("E-AppAbs", || body.instantiate(arg))),
+        _ => None,
+      },
+      Term::UniversalApp(term, typ) => match &**term {
+        Term::UniversalAbs { body, .. } => {
+          Some(ev.contract("E-TappTabs", || body.instantiate_type(typ)))
+        }
+        _ => None,
+      },
+      Term::Unpack { package, body, .. } => match &**package {
+        Term::Pack { witness, term, .. } => Some(ev.contract("E-UnpackPack", || {
+          body.instantiate_type(witness).instantiate(term)
+        })),
+        _ => None,
+      },
+      Term::Proj(term, i) => match (&**term, i) {
+        (Term::Pair(first, _), 1) => Some(ev.contract("E-PairBeta1", || *first.clone())),
+        (Term::Pair(_, second), 2) => Some(ev.contract("E-PairBeta2", || *second.clone())),
+        _ => None,
+      },
+      Term::Case {
+        scrutinee,
+        left,
+        right,
+        ..
+      } => match &**scrutinee {
+        Term::Inl(value, _) => Some(ev.contract("E-CaseInl", || left.instantiate(value))),
+        Term::Inr(value, _) => Some(ev.contract("E-CaseInr", || right.instantiate(value))),
+        _ => None,
+      },
+      Term::Field(term, label) => match &**term {
+        Term::Record(fields) => {
+          let value = field(fields, label)?;
+          Some(ev.contract("E-ProjRcd", || value.clone()))
+        }
+        _ => None,
+      },
+      Term::VariantCase {
+        scrutinee,
+        branches,
+      } => match &**scrutinee {
+        Term::Tag { label, term, .. } => {
+          let branch = branches.iter().find(|branch| branch.label == *label)?;
+          Some(ev.contract("E-CaseVariant", || branch.body.instantiate(term)))
+        }
+        _ => None,
+      },
+      Term::Seq(first, second) => match &**first {
+        Term::Unit => Some(ev.contract("E-SeqNext", || *second.clone())),
+        _ => None,
+      },
+      Term::Let { value, body, .. } => Some(ev.contract("E-LetSubst", || body.instantiate(value))),
+      Term::Fix(term) => match &**term {
+        Term::Abs { body, .. } => Some(ev.contract("E-FixBeta", || body.instantiate(self))),
+        _ => None,
+      },
+      Term::Unfold(_, term) => match &**term {
+        Term::Fold(_, value) => Some(ev.contract("E-UnfoldFold", || *value.clone())),
+        _ => None,
+      },
+      _ => None,
+    }
+  }
+
+  // The direct subterms, in the order they are written.
+  fn children(&self) -> Vec<&Term> {
+    match self {
+      Term::Int(_) | Term::Unit | Term::Var(_) => Vec::new(),
+      Term::App(first, second)
+      | Term::Pair(first, second)
+      | Term::Seq(first, second)
+      | Term::Let {
+        value: first,
+        body: second,
+        ..
+      }
+      | Term::Unpack {
+        package: first,
+        body: second,
+        ..
+      } => vec![first, second],
+      Term::Abs { body: term, .. }
+      | Term::UniversalAbs { body: term, .. }
+      | Term::UniversalApp(term, _)
+      | Term::Pack { term, .. }
+      | Term::Proj(term, _)
+      | Term::Inl(term, _)
+      | Term::Inr(term, _)
+      | Term::Field(term, _)
+      | Term::Tag { term, .. }
+      | Term::Fix(term)
+      | Term::Fold(_, term)
+      | Term::Unfold(_, term) => vec![term],
+      Term::Case {
+        scrutinee,
+        left,
+        right,
+        ..
+      } => vec![scrutinee, left, right],
+      Term::Record(fields) => fields.iter().map(|(_, term)| term).collect(),
+      Term::VariantCase {
+        scrutinee,
+        branches,
+      } => std::iter::once(&**scrutinee)
+        .chain(branches.iter().map(|branch| &branch.body))
+        .collect(),
+    }
+  }
+
+  fn children_mut(&mut self) -> Vec<&mut Term> {
+    match self {
+      Term::Int(_) | Term::Unit | Term::Var(_) => Vec::new(),
+      Term::App(first, second)
+      | Term::Pair(first, second)
+      | Term::Seq(first, second)
+      | Term::Let {
+        value: first,
+        body: second,
+        ..
+      }
+      | Term::Unpack {
+        package: first,
+        body: second,
+        ..
+      } => vec![first, second],
+      Term::Abs { body: term, .. }
+      | Term::UniversalAbs { body: term, .. }
+      | Term::UniversalApp(term, _)
+      | Term::Pack { term, .. }
+      | Term::Proj(term, _)
+      | Term::Inl(term, _)
+      | Term::Inr(term, _)
+      | Term::Field(term, _)
+      | Term::Tag { term, .. }
+      | Term::Fix(term)
+      | Term::Fold(_, term)
+      | Term::Unfold(_, term) => vec![term],
+      Term::Case {
+        scrutinee,
+        left,
+        right,
+        ..
+      } => vec![scrutinee, left, right],
+      Term::Record(fields) => fields.iter_mut().map(|(_, term)| term).collect(),
+      Term::VariantCase {
+        scrutinee,
+        branches,
+      } => std::iter::once(&mut **scrutinee)
+        .chain(branches.iter_mut().map(|branch| &mut branch.body))
+        .collect(),
+    }
+  }
+
+  // The name of the rule that steps the subterm `i`.
+  fn congruence(&self, i: usize) -> &'static str {
+    match (self, i) {
+      (Term::App(..), 0) => "E-App1",
+      (Term::App(..), _) => "E-App2",
+      (Term::Abs { .. }, _) => "E-Abs",
+      (Term::UniversalAbs { .. }, _) => "E-TAbs",
+      (Term::UniversalApp(..), _) => "E-TApp",
+      (Term::Pack { .. }, _) => "E-Pack",
+      (Term::Unpack { .. }, 0) => "E-Unpack",
+      (Term::Unpack { .. }, _) => "E-UnpackBody",
+      (Term::Pair(..), 0) => "E-Pair1",
+      (Term::Pair(..), _) => "E-Pair2",
+      (Term::Proj(..), _) => "E-Proj",
+      (Term::Inl(..), _) => "E-Inl",
+      (Term::Inr(..), _) => "E-Inr",
+      (Term::Case { .. }, 0) | (Term::VariantCase { .. }, 0) => "E-Case",
+      (Term::Case { .. }, _) | (Term::VariantCase { .. }, _) => "E-CaseBranch",
+      (Term::Record(_), _) => "E-Rcd",
+      (Term::Field(..), _) => "E-RcdProj",
+      (Term::Tag { .. }, _) => "E-Variant",
+      (Term::Seq(..), 0) => "E-Seq",
+      (Term::Seq(..), _) => "E-SeqNext",
+      (Term::Let { .. }, 0) => "E-Let",
+      (Term::Let { .. }, _) => "E-LetBody",
+      (Term::Fix(_), _) => "E-Fix",
+      (Term::Fold(..), _) => "E-Fold",
+      (Term::Unfold(..), _) => "E-Unfold",
+      (Term::Int(_), _) | (Term::Unit, _) | (Term::Var(_), _) => "",
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::debruijn::{eval_with, trace_with};
+  use crate::parse;
+
+  fn eval(input: &str, strategy: Strategy) -> String {
+    eval_with(&parse(input).unwrap(), strategy)
+      .unwrap()
+      .to_string()
+  }
+
+  fn rules(input: &str, strategy: Strategy) -> Vec<Vec<&'static str>> {
+    let trace = trace_with(&parse(input).unwrap(), strategy).unwrap();
+    trace.steps.into_iter().map(|step| step.rules).collect()
+  }
+
+  #[test]
+  fn strategies_agree_on_values() {
+    let inputs = [
+      "(λx: Int. x) ((ΛX: *. λy: X. y) [Int] 1)",
+      "let p = ({a = 1}, inl () as Unit + Int) in case p.2 of inl u => p.1.a | inr n => n",
+      "unpack pack [Int, {f = λx: Int. x, z = 1}] as ∃X: *. {f: X -> X, z: X} as [X, r] in (λy: X. 2) (r.f r.z)",
+      "case <b = (λx: Int. x) 2> as <a: Unit, b: Int> of { <a = u> => 0 | <b = n> => n }",
+      "(λf: Int -> Int. (); f (f 1)) (λx: Int. x)",
+      "unfold [μL. Unit + L] (fold [μL. Unit + L] (inl () as Unit + (μL. Unit + L)))",
+    ];
+    for input in &inputs {
+      let expected = eval(input, Strategy::CallByValue);
+      for strategy in &Strategy::ALL {
+        assert_eq!(
+          eval(input, *strategy),
+          expected,
+          "{} by {}",
+          input,
+          strategy
+        );
+      }
+    }
+    let recursive = "let rec f: Int -> Int = λn: Int. n in f 3";
+    for strategy in &[Strategy::CallByName, Strategy::CallByNeed] {
+      assert_eq!(eval(recursive, *strategy), "3");
+    }
+  }
+
+  #[test]
+  fn lazy_strategies_delay_arguments() {
+    let unused = "(λx: Int. 1) ((λy: Int. y) 2)";
+    assert_eq!(rules(unused, Strategy::CallByValue).len(), 2);
+    assert_eq!(rules(unused, Strategy::CallByName), vec![vec!["E-AppAbs"]]);
+    assert_eq!(
+      rules(unused, Strategy::CallByNeed),
+      vec![vec!["E-AppAlloc"]]
+    );
+    // Call-by-name evaluates the argument once per use, call-by-need once.
+    let shared = "(λx: Int. (x, x)) ((λy: Int. y) 2)";
+    let betas = |strategy| {
+      rules(shared, strategy)
+        .iter()
+        .filter(|rules| rules.last() == Some(&"E-AppAbs"))
+        .count()
+    };
+    assert_eq!(betas(Strategy::CallByName), 3);
+    assert_eq!(betas(Strategy::CallByNeed), 1);
+    assert_eq!(
+      rules(shared, Strategy::CallByNeed)[1],
+      vec!["E-Pair1", "E-Force", "E-AppAbs"]
+    );
+    // The heap a value still refers to is bound around it.
+    assert_eq!(
+      eval(
+        "(λx: Int. λy: Int. x) ((λz: Int. z) 1)",
+        Strategy::CallByNeed
+      ),
+      "let x = (λz: Int. z) 1 in λy: Int. x"
+    );
+    let trace = trace_with(&parse(shared).unwrap(), Strategy::CallByNeed).unwrap();
+    assert_eq!(trace.steps[1].term, "let x = (λy: Int. y) 2 in (x, x)");
+    assert_eq!(trace.steps[1].redex_text(), "(λy: Int. y) 2");
+  }
+
+  #[test]
+  fn strong_strategies_reduce_under_binders() {
+    let input = "λx: Int. (λy: Int. y) x";
+    assert_eq!(eval(input, Strategy::CallByValue), input);
+    assert_eq!(eval(input, Strategy::CallByNeed), input);
+    for strategy in &[
+      Strategy::NormalOrder,
+      Strategy::ApplicativeOrder,
+      Strategy::FullBeta,
+    ] {
+      assert_eq!(eval(input, *strategy), "λx: Int. x");
+    }
+    let input = "(λx: Int. (λy: Int. y) x) ((λz: Int. z) 1)";
+    assert_eq!(rules(input, Strategy::NormalOrder)[0], vec!["E-AppAbs"]);
+    assert_eq!(
+      rules(input, Strategy::ApplicativeOrder)[0],
+      vec!["E-App1", "E-Abs", "E-AppAbs"]
+    );
+    assert_eq!(
+      rules(input, Strategy::FullBeta)[0],
+      vec!["E-App2", "E-AppAbs"]
+    );
+  }
+
+  #[test]
+  fn strategies_have_names() {
+    for strategy in &Strategy::ALL {
+      assert_eq!(strategy.to_string().parse(), Ok(*strategy));
+    }
+    assert_eq!(
+      "lazy".parse::<Strategy>(),
+      Err(UnknownStrategy(String::from("lazy")))
+    );
+  }
+}
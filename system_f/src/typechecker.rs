@@ -1,4 +1,9 @@
-use crate::ast::{Term, Type};
+use crate::ast::{Branch, Kind, Term, Type};
+use crate::debruijn;
+use crate::prelude::{self, Definition};
+use std::borrow::Cow;
+use std::fmt;
+use std::sync::OnceLock;
 use thiserror::Error;
 
 // Γ(x) = τ
@@ -16,10 +21,52 @@ use thiserror::Error;
 // Γ ⊢ (λx: σ. t): σ -> τ
 //
 //
+// Γ ⊢ t ⇒ τ synthesizes τ from t, Γ ⊢ t ⇐ τ checks t against a known τ.
+// Unannotated abstractions can only be checked.
+//
+//    Γ, x: σ ⊢ t ⇐ τ
+// ---------------------
+// Γ ⊢ (λx. t) ⇐ σ -> τ
+//
+//
+//    Γ ⊢ t ⇐ τ
+// ---------------
+// Γ ⊢ (t : τ) ⇒ τ
+//
+//
+// Γ ⊢ t ⇒ σ    σ = τ
+// ------------------
+//     Γ ⊢ t ⇐ τ
+//
+//
+// Γ ⊢ t1 ⇒ σ    Γ, x: σ ⊢ t2 ⇒ τ
+// ------------------------------
+//  Γ ⊢ let x = t1 in t2 ⇒ τ
+//
+//
+//          Γ ⊢ t[σ/α] ⇒ τ
+// --------------------------------
+// Γ ⊢ type α β1 ... βn = σ; t ⇒ τ
+//
+// A type alias is transparent: the body is checked with every use α τ1 ... τn
+// replaced by σ[τ1/β1, ..., τn/βn], so it can be used wherever its expansion
+// can. An alias has to be given all of its arguments. Errors in the body show
+// the alias again wherever a type has the shape of its expansion.
+//
+//
 // ------------------------
 // (λx: τ. t) t' |> t[t'/x]
 //
 //
+// ------------------------
+// let x = v in t |> t[v/x]
+//
+//
+//              t1 |> t1'
+// ------------------------------------
+// let x = t1 in t2 |> let x = t1' in t2
+//
+//
 // Γ(α) = κ
 // ---------
 // Γ ⊢ α : κ
@@ -44,6 +91,97 @@ use thiserror::Error;
 // ----------------------------
 //      Γ ⊢ t σ : τ[σ/α]
 //
+//
+//           Γ ⊢ t : τ[σ/α]
+// ----------------------------------------
+// Γ ⊢ pack [σ, t] as ∃α : κ. τ : ∃α : κ. τ
+//
+//
+// Γ ⊢ t1 : ∃α : κ. τ1    Γ, α : κ, x: τ1 ⊢ t2 : τ2    α ∉ FV(τ2)
+// -------------------------------------------------------------
+//            Γ ⊢ unpack t1 as [α, x] in t2 : τ2
+//
+// α is only known to the body of the unpack, which is what keeps the
+// representation chosen by the pack abstract.
+//
+//
+// Γ ⊢ t1 : τ1    Γ ⊢ t2 : τ2
+// --------------------------
+// Γ ⊢ (t1, t2) : τ1 × τ2
+//
+//
+// Γ ⊢ t : τ1 × τ2
+// ---------------
+//  Γ ⊢ t.i : τi
+//
+//
+//          Γ ⊢ t : τ1
+// -------------------------------
+// Γ ⊢ inl t as τ1 + τ2 : τ1 + τ2
+//
+//
+//          Γ ⊢ t : τ2
+// -------------------------------
+// Γ ⊢ inr t as τ1 + τ2 : τ1 + τ2
+//
+//
+// Γ ⊢ t : τ1 + τ2    Γ, x1: τ1 ⊢ t1 : τ    Γ, x2: τ2 ⊢ t2 : τ
+// ----------------------------------------------------------
+//    Γ ⊢ case t of inl x1 => t1 | inr x2 => t2 : τ
+//
+//
+// Γ ⊢ ti : τi for each i    the labels li are distinct
+// ---------------------------------------------------
+//   Γ ⊢ {l1 = t1, ..., ln = tn} : {l1: τ1, ..., ln: τn}
+//
+//
+// Γ ⊢ t : {l1: τ1, ..., ln: τn}
+// -----------------------------
+//        Γ ⊢ t.li : τi
+//
+//
+//                       Γ ⊢ t : τi
+// -----------------------------------------------------------
+// Γ ⊢ <li = t> as <l1: τ1, ..., ln: τn> : <l1: τ1, ..., ln: τn>
+//
+//
+// Γ ⊢ t : <l1: τ1, ..., ln: τn>    Γ, xi: τi ⊢ ti : τ for each i
+// ---------------------------------------------------------------
+//   Γ ⊢ case t of { <l1 = x1> => t1 | ... | <ln = xn> => tn } : τ
+//
+// Record and variant types are equal when they have the same fields in any
+// order. A case must handle every label of the variant exactly once.
+//
+//
+// -------------
+// Γ ⊢ () : Unit
+//
+//
+// Γ ⊢ t1 : Unit    Γ ⊢ t2 : τ
+// ---------------------------
+//      Γ ⊢ t1; t2 : τ
+//
+//
+// Γ ⊢ t : τ -> τ
+// --------------
+// Γ ⊢ fix t : τ
+//
+// `let rec x: τ = t1 in t2` is sugar for `let x = fix (λx: τ. t1) in t2`.
+// With fix, terms no longer have to terminate.
+//
+//
+//       Γ ⊢ t : τ[μα. τ/α]
+// ---------------------------
+// Γ ⊢ fold [μα. τ] t : μα. τ
+//
+//
+//         Γ ⊢ t : μα. τ
+// -------------------------------
+// Γ ⊢ unfold [μα. τ] t : τ[μα. τ/α]
+//
+// Recursive types are iso-recursive: μα. τ and its unfolding τ[μα. τ/α] are
+// different types, and fold and unfold convert between them explicitly.
+//
 // -------------------------
 // (Λα : κ . t) σ |> t[σ/α]
 //
@@ -65,173 +203,1846 @@ use thiserror::Error;
 // t1 |> t2   t2 |> t3
 // -------------------
 //      t1 |> t3
-#[derive(Debug, PartialEq, Error)]
+//
+//
+// ---------------------------------------------------------
+// unpack (pack [σ, v] as τ) as [α, x] in t |> t[σ/α][v/x]
+//
+//
+// (v1, v2).i |> vi
+//
+//
+// case (inl v as τ) of inl x1 => t1 | inr x2 => t2 |> t1[v/x1]
+//
+//
+// case (inr v as τ) of inl x1 => t1 | inr x2 => t2 |> t2[v/x2]
+//
+//
+// {l1 = v1, ..., ln = vn}.li |> vi
+//
+//
+// case (<li = v> as τ) of { ... | <li = xi> => ti | ... } |> ti[v/xi]
+//
+//
+//      t1 |> t1'
+// -------------------
+// t1; t2 |> t1'; t2
+//
+//
+// (); t |> t
+//
+//
+//       t |> t'
+// -----------------
+// fix t |> fix t'
+//
+//
+// fix (λx: τ. t) |> t[fix (λx: τ. t)/x]
+//
+//
+// ------------------------------
+// unfold [σ] (fold [τ] v) |> v
+#[derive(Debug, Clone, PartialEq, Error)]
 pub enum TypecheckerError {
   #[error("variable {0} is not defined")]
   UndefinedVariable(String),
-  #[error("expected term {term:?} to have type {expected:?} but it has type {got:?}")]
+  #[error("type variable {0} is not defined")]
+  UndefinedTypeVariable(String),
+  #[error("expected term {term} to have type {expected} but it has type {got}")]
   TypeMismatch {
-    term: Term,
+    term: Box<Term>,
     expected: Type,
     got: Type,
   },
-  #[error("expected {expected:?} but got {got:?}")]
-  UnexpectedTerm { expected: String, got: Term },
+  #[error("expected {expected} but got {got}")]
+  UnexpectedTerm { expected: String, got: Box<Term> },
+  #[error("expected {expected} but got {got}")]
+  UnexpectedType { expected: String, got: Type },
+  #[error("cannot infer the type of {0}, add a type annotation")]
+  MissingAnnotation(Box<Term>),
+  #[error("cannot infer the type argument for {type_var} in {term}, apply it explicitly")]
+  AmbiguousTypeArgument { term: Box<Term>, type_var: String },
+  #[error("type variable {type_var} escapes its scope in type {typ}")]
+  EscapingTypeVariable { type_var: String, typ: Type },
+  #[error("type {typ} has no label {label}")]
+  MissingLabel { label: String, typ: Type },
+  #[error("label {0} appears more than once")]
+  DuplicateLabel(String),
+  #[error("case does not handle label {label} of type {typ}")]
+  NonExhaustiveMatch { label: String, typ: Type },
+  #[error("type {name} expects {expected} arguments but got {got}")]
+  WrongNumberOfTypeArguments {
+    name: String,
+    expected: usize,
+    got: usize,
+  },
 }
 
-#[derive(Debug, Clone)]
-enum List<T> {
-  Cons(T, Box<List<T>>),
-  Nil,
+impl TypecheckerError {
+  // Writes the types in the error with the alias `name` where possible, for
+  // errors from its scope. When a type cannot be written with the alias, the
+  // reason it cannot is returned instead.
+  fn resugar(self, name: &str, params: &[String], alias: &Type) -> TypecheckerError {
+    self
+      .try_resugar(name, params, alias)
+      .unwrap_or_else(|err| err)
+  }
+
+  fn try_resugar(
+    self,
+    name: &str,
+    params: &[String],
+    alias: &Type,
+  ) -> Result<TypecheckerError, TypecheckerError> {
+    let resugar = |typ: Type| debruijn::resugar(name, params, alias, &typ);
+    Ok(match self {
+      TypecheckerError::TypeMismatch {
+        term,
+        expected,
+        got,
+      } => TypecheckerError::TypeMismatch {
+        term,
+        expected: resugar(expected)?,
+        got: resugar(got)?,
+      },
+      TypecheckerError::UnexpectedType { expected, got } => TypecheckerError::UnexpectedType {
+        expected,
+        got: resugar(got)?,
+      },
+      TypecheckerError::EscapingTypeVariable { type_var, typ } => {
+        TypecheckerError::EscapingTypeVariable {
+          type_var,
+          typ: resugar(typ)?,
+        }
+      }
+      TypecheckerError::MissingLabel { label, typ } => TypecheckerError::MissingLabel {
+        label,
+        typ: resugar(typ)?,
+      },
+      TypecheckerError::NonExhaustiveMatch { label, typ } => TypecheckerError::NonExhaustiveMatch {
+        label,
+        typ: resugar(typ)?,
+      },
+      err => err,
+    })
+  }
+}
+
+// An entry of Γ.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Binding {
+  // x: τ
+  Var(String, Type),
+  // α: κ
+  TypeVar(String, Kind),
+}
+
+impl fmt::Display for Binding {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Binding::Var(x, typ) => write!(f, "{}: {}", x, typ),
+      Binding::TypeVar(type_var, kind) => write!(f, "{}: {}", type_var, kind),
+    }
+  }
 }
 
-#[derive(Debug)]
-struct TypingContext {
-  type_assignments: List<(String, Type)>,
+// Γ, the variables and type variables in scope. Term and type variables live
+// in separate namespaces, but the order of all bindings is kept so that Γ can
+// be converted to the de Bruijn representation.
+#[derive(Debug, Clone, Default)]
+pub struct TypingContext {
+  bindings: lambda_core::TypingContext<Binding>,
 }
 
 impl TypingContext {
   pub fn new() -> Self {
-    Self {
-      type_assignments: List::Nil,
-    }
+    Self::default()
   }
 
+  // Γ, x: τ
   pub fn assign(&self, var: String, typ: Type) -> TypingContext {
     TypingContext {
-      type_assignments: List::Cons((var, typ), Box::new(self.type_assignments.clone())),
+      bindings: self.bindings.assign(var.clone(), Binding::Var(var, typ)),
     }
   }
 
-  fn get_var(xs: &List<(String, Type)>, var: &String) -> Option<Type> {
-    match xs {
-      List::Nil => None,
-      List::Cons((x, typ), tail) => {
-        if x == var {
-          Some(typ.clone())
-        } else {
-          Self::get_var(tail, var)
-        }
-      }
+  // Γ, α: κ
+  pub fn assign_type(&self, type_var: String, kind: Kind) -> TypingContext {
+    TypingContext {
+      bindings: self
+        .bindings
+        .assign(type_var.clone(), Binding::TypeVar(type_var, kind)),
     }
   }
 
-  pub fn get(&self, var: &String) -> Option<Type> {
-    Self::get_var(&self.type_assignments, var)
+  // The type of the innermost binding of the variable `var`.
+  pub fn get(&self, var: &str) -> Option<&Type> {
+    self.bindings.iter().find_map(|(_, binding)| match binding {
+      Binding::Var(x, typ) if x == var => Some(typ),
+      _ => None,
+    })
+  }
+
+  // The kind of the innermost binding of the type variable `type_var`.
+  pub fn get_kind(&self, type_var: &str) -> Option<&Kind> {
+    self.bindings.iter().find_map(|(_, binding)| match binding {
+      Binding::TypeVar(x, kind) if x == type_var => Some(kind),
+      _ => None,
+    })
+  }
+
+  // The bindings, outermost first.
+  pub fn bindings(&self) -> Vec<Binding> {
+    let mut bindings: Vec<Binding> = self.bindings.iter().map(|(_, b)| b.clone()).collect();
+    bindings.reverse();
+    bindings
+  }
+
+  // The type variables of Γ in the de Bruijn representation.
+  fn type_context(&self) -> debruijn::Context {
+    self
+      .bindings()
+      .into_iter()
+      .fold(debruijn::Context::new(), |ctx, binding| match binding {
+        Binding::Var(..) => ctx,
+        Binding::TypeVar(type_var, kind) => ctx.assign_type(type_var, kind),
+      })
+  }
+
+  // Γ in the de Bruijn representation. Fails if a type in Γ mentions a type
+  // variable that is not bound before it.
+  pub fn to_debruijn(&self) -> Result<debruijn::Context, TypecheckerError> {
+    self
+      .bindings()
+      .into_iter()
+      .try_fold(debruijn::Context::new(), |ctx, binding| match binding {
+        Binding::Var(x, typ) => {
+          let typ = debruijn::Type::from_ast(&ctx, &typ)?;
+          Ok(ctx.assign(x, typ))
+        }
+        Binding::TypeVar(type_var, kind) => Ok(ctx.assign_type(type_var, kind)),
+      })
   }
 }
 
-fn subst(type_var: &String, from: &Type, to: Type) -> Type {
-  match from {
-    Type::Bool => Type::Bool,
-    Type::Int => Type::Int,
-    Type::Arrow(param_type, return_type) => Type::Arrow(
-      Box::new(subst(type_var, param_type, to.clone())),
-      Box::new(subst(type_var, return_type, to)),
-    ),
-    Type::TypeVar(x) => {
-      // TODO: use capture avoiding substitution.
-      if x == type_var {
-        to
-      } else {
-        from.clone()
-      }
+// Γ with every prelude definition bound to its declared type.
+pub fn prelude_context() -> TypingContext {
+  prelude::definitions()
+    .iter()
+    .fold(TypingContext::new(), |ctx, definition| {
+      ctx.assign(definition.name.clone(), definition.typ.clone())
+    })
+}
+
+// Γ ⊢ τ
+//
+// Fails unless every type variable in `typ` is bound in Γ and applied to as
+// many arguments as its kind takes.
+fn check_type(ctx: &TypingContext, typ: &Type) -> Result<(), TypecheckerError> {
+  debruijn::Type::from_ast(&ctx.type_context(), typ).map(|_| ())
+}
+
+// The types written on `term` itself, rather than on its subterms.
+fn annotations(term: &Term) -> Vec<&Type> {
+  match term {
+    Term::Abs {
+      param_type: Some(typ),
+      ..
     }
-    Type::Forall {
-      type_var,
-      typ,
-      kind,
-    } => Type::Forall {
-      type_var: type_var.clone(),
-      kind: kind.clone(),
-      typ: Box::new(subst(&type_var, &typ, to)),
-    },
+    | Term::Ann(_, typ)
+    | Term::UniversalApp(_, typ)
+    | Term::Inl(_, typ)
+    | Term::Inr(_, typ)
+    | Term::Tag { typ, .. }
+    | Term::Fold(typ, _)
+    | Term::Unfold(typ, _) => vec![typ],
+    Term::Pack { witness, typ, .. } => vec![witness, typ],
+    _ => Vec::new(),
+  }
+}
+
+// Renames the type variable `type_var` bound around `body` when Γ already
+// binds a type variable of that name. The types in Γ keep referring to the
+// outer one, so the two must not be confused.
+fn freshen<'a>(
+  ctx: &TypingContext,
+  type_var: &str,
+  body: &'a Term,
+) -> Result<(String, Cow<'a, Term>), TypecheckerError> {
+  if ctx.get_kind(type_var).is_none() {
+    return Ok((String::from(type_var), Cow::Borrowed(body)));
   }
+  // A name occurs in a term exactly when expanding it changes the term.
+  let mut fresh = format!("{}'", type_var);
+  while ctx.get_kind(&fresh).is_some()
+    || debruijn::expand_alias_in_term(&fresh, &[], &Type::Unit, body).as_ref() != Ok(body)
+  {
+    fresh.push('\'');
+  }
+  let renamed = Type::TypeVar(fresh.clone());
+  let body = debruijn::expand_alias_in_term(type_var, &[], &renamed, body)?;
+  Ok((fresh, Cow::Owned(body)))
 }
 
-fn type_of(ctx: &TypingContext, term: &Term) -> Result<Type, TypecheckerError> {
+// Γ ⊢ t ⇒ τ
+//
+// Synthesizes the type of `term`, returning it together with the elaborated
+// term, where every λ carries the parameter type that was inferred for it.
+fn type_of(ctx: &TypingContext, term: &Term) -> Result<(Term, Type), TypecheckerError> {
+  for typ in annotations(term) {
+    check_type(ctx, typ)?;
+  }
   match term {
-    Term::Int(_) => Ok(Type::Int),
+    Term::Int(_) => Ok((term.clone(), Type::Int)),
+    // -------------
+    // Γ ⊢ () ⇒ Unit
+    Term::Unit => Ok((term.clone(), Type::Unit)),
     // Γ(x) = τ
     // --------
     // Γ ⊢ x: τ
     Term::Var(x) => match ctx.get(x) {
       None => Err(TypecheckerError::UndefinedVariable(x.clone())),
-      Some(typ) => Ok(typ.clone()),
-    },
-    // Γ ⊢ t1: σ -> τ    Γ ⊢ t2: σ
-    // ---------------------------
-    //       Γ ⊢ (t1 t2): τ
-    Term::App(f, arg) => match &**f {
-      Term::Abs {
-        param_name,
-        param_type,
-        body,
-      } => {
-        let arg_type = type_of(ctx, arg)?;
-
-        if param_type != &arg_type {
-          return Err(TypecheckerError::TypeMismatch {
-            term: *arg.clone(),
-            expected: param_type.clone(),
-            got: arg_type,
-          });
-        }
-
-        let ctx = ctx.assign(param_name.clone(), param_type.clone());
-
-        let body_typ = type_of(&ctx, body)?;
-
-        Ok(body_typ)
-      }
-      _ => Err(TypecheckerError::UnexpectedTerm {
-        expected: String::from("abstraction"),
-        got: *f.clone(),
-      }),
+      Some(typ) => Ok((term.clone(), typ.clone())),
     },
-    //    Γ, x: σ ⊢ t: τ
+    // Γ ⊢ t1 ⇒ σ -> τ    Γ ⊢ t2 ⇐ σ
+    // -----------------------------
+    //       Γ ⊢ (t1 t2) ⇒ τ
+    Term::App(..) => type_of_app(ctx, term, None),
+    //    Γ, x: σ ⊢ t ⇒ τ
     // ----------------------
-    // Γ ⊢ (λx: σ. t): σ -> τ
+    // Γ ⊢ (λx: σ. t) ⇒ σ -> τ
     Term::Abs {
       param_name,
-      param_type,
+      param_type: Some(param_type),
       body,
     } => {
       let ctx = ctx.assign(param_name.clone(), param_type.clone());
-      let body_typ = type_of(&ctx, body)?;
-      Ok(Type::Arrow(
-        Box::new(param_type.clone()),
-        Box::new(body_typ),
+      let (body, body_typ) = type_of(&ctx, body)?;
+      Ok((
+        Term::Abs {
+          param_name: param_name.clone(),
+          param_type: Some(param_type.clone()),
+          body: Box::new(body),
+        },
+        Type::Arrow(Box::new(param_type.clone()), Box::new(body_typ)),
+      ))
+    }
+    Term::Abs {
+      param_type: None, ..
+    } => Err(TypecheckerError::MissingAnnotation(Box::new(term.clone()))),
+    //    Γ ⊢ t ⇐ τ
+    // ---------------
+    // Γ ⊢ (t : τ) ⇒ τ
+    Term::Ann(term, typ) => Ok((check(ctx, term, typ)?, typ.clone())),
+    // Γ ⊢ t1 ⇒ σ    Γ, x: σ ⊢ t2 ⇒ τ
+    // ------------------------------
+    //  Γ ⊢ let x = t1 in t2 ⇒ τ
+    Term::Let { name, value, body } => {
+      let (value, value_typ) = type_of(ctx, value)?;
+      let ctx = ctx.assign(name.clone(), value_typ);
+      let (body, body_typ) = type_of(&ctx, body)?;
+      Ok((
+        Term::Let {
+          name: name.clone(),
+          value: Box::new(value),
+          body: Box::new(body),
+        },
+        body_typ,
       ))
     }
+    //          Γ ⊢ t[σ/α] ⇒ τ
+    // --------------------------------
+    // Γ ⊢ type α β1 ... βn = σ; t ⇒ τ
+    Term::TypeLet {
+      type_var,
+      params,
+      typ,
+      body,
+    } => {
+      check_alias(ctx, params, typ)?;
+      let body = debruijn::expand_alias_in_term(type_var, params, typ, body)?;
+      type_of(ctx, &body).map_err(|err| err.resugar(type_var, params, typ))
+    }
+    //      Γ, α : κ ⊢ t ⇒ τ
+    // ----------------------------
+    // Γ ⊢ (Λα : κ. t) ⇒ (∀α : κ . τ)
     Term::UniversalAbs {
       type_var,
       kind,
       body,
-    } => Ok(Type::Forall {
-      type_var: type_var.clone(),
-      kind: kind.clone(),
-      typ: Box::new(type_of(ctx, body)?),
-    }),
-    // Γ ⊢ t : (∀α : κ . τ)   Γ ⊢ σ
+    } => {
+      let (type_var, body) = freshen(ctx, type_var, body)?;
+      let ctx = ctx.assign_type(type_var.clone(), kind.clone());
+      let (body, body_typ) = type_of(&ctx, &body)?;
+      Ok((
+        Term::UniversalAbs {
+          type_var: type_var.clone(),
+          kind: kind.clone(),
+          body: Box::new(body),
+        },
+        Type::Forall {
+          type_var,
+          kind: kind.clone(),
+          typ: Box::new(body_typ),
+        },
+      ))
+    }
+    // Γ ⊢ t ⇒ (∀α : κ . τ)   Γ ⊢ σ
     // ----------------------------
-    //      Γ ⊢ t σ : τ[σ/α]
+    //      Γ ⊢ t σ ⇒ τ[σ/α]
     Term::UniversalApp(term, arg_typ) => match type_of(ctx, term)? {
-      Type::Forall { typ, type_var, .. } => {
+      (term, Type::Forall { typ, type_var, .. }) => {
         // (\x. e')[v/x] = \x. e' -- we do not substitute because x is bound by the lambda
         // (\y. e')[v/x] = (\y. e'[v/x]) -- recursively substitute lambda body
         // (\y. x)[z/x] = (\y. z) -- replace x with z
-        // (\z. x)[z/x] = (\z. x) -- do not substitute x because it would change the function behaviour
-        Ok(subst(&type_var, &typ, arg_typ.clone()))
+        // (\z. x)[z/x] = (\z'. z) -- rename z so it does not capture the free z
+        Ok((
+          Term::UniversalApp(Box::new(term), arg_typ.clone()),
+          debruijn::subst_type(&type_var, &typ, arg_typ),
+        ))
       }
       _ => Err(TypecheckerError::UnexpectedTerm {
         expected: String::from("type abstraction"),
-        got: *term.clone(),
+        got: term.clone(),
+      }),
+    },
+    //            Γ ⊢ t ⇐ τ[σ/α]
+    // -----------------------------------------
+    // Γ ⊢ pack [σ, t] as ∃α : κ. τ ⇒ ∃α : κ. τ
+    Term::Pack { witness, term, typ } => match typ {
+      Type::Exists {
+        type_var,
+        typ: body,
+        ..
+      } => {
+        let term = check(ctx, term, &debruijn::subst_type(type_var, body, witness))?;
+        Ok((
+          Term::Pack {
+            witness: witness.clone(),
+            term: Box::new(term),
+            typ: typ.clone(),
+          },
+          typ.clone(),
+        ))
+      }
+      _ => Err(TypecheckerError::UnexpectedType {
+        expected: String::from("existential type"),
+        got: typ.clone(),
+      }),
+    },
+    // Γ ⊢ t1 ⇒ ∃β : κ. τ1    Γ, α : κ, x: τ1[α/β] ⊢ t2 ⇒ τ2    α ∉ FV(τ2)
+    // -------------------------------------------------------------------
+    //              Γ ⊢ unpack t1 as [α, x] in t2 ⇒ τ2
+    Term::Unpack {
+      type_var,
+      var,
+      package,
+      body,
+    } => match type_of(ctx, package)? {
+      (
+        package,
+        Type::Exists {
+          type_var: bound_type_var,
+          kind,
+          typ,
+        },
+      ) => {
+        let (type_var, body) = freshen(ctx, type_var, body)?;
+        let typ = debruijn::subst_type(&bound_type_var, &typ, &Type::TypeVar(type_var.clone()));
+        let ctx = ctx
+          .assign_type(type_var.clone(), kind)
+          .assign(var.clone(), typ);
+        let (body, body_typ) = type_of(&ctx, &body)?;
+
+        if mentions(&body_typ, &[&type_var]) {
+          return Err(TypecheckerError::EscapingTypeVariable {
+            type_var: type_var.clone(),
+            typ: body_typ,
+          });
+        }
+
+        Ok((
+          Term::Unpack {
+            type_var,
+            var: var.clone(),
+            package: Box::new(package),
+            body: Box::new(body),
+          },
+          body_typ,
+        ))
+      }
+      _ => Err(TypecheckerError::UnexpectedTerm {
+        expected: String::from("package"),
+        got: package.clone(),
+      }),
+    },
+    // Γ ⊢ t1 ⇒ τ1    Γ ⊢ t2 ⇒ τ2
+    // --------------------------
+    // Γ ⊢ (t1, t2) ⇒ τ1 × τ2
+    Term::Pair(first, second) => {
+      let (first, first_typ) = type_of(ctx, first)?;
+      let (second, second_typ) = type_of(ctx, second)?;
+      Ok((
+        Term::Pair(Box::new(first), Box::new(second)),
+        Type::Product(Box::new(first_typ), Box::new(second_typ)),
+      ))
+    }
+    // Γ ⊢ t ⇒ τ1 × τ2
+    // ---------------
+    //  Γ ⊢ t.i ⇒ τi
+    Term::Proj(term, i) => {
+      let (term, typ) = type_of(ctx, term)?;
+      let component_typ = match (typ, i) {
+        (Type::Product(first, _), 1) => *first,
+        (Type::Product(_, second), 2) => *second,
+        (typ, _) => {
+          return Err(TypecheckerError::UnexpectedType {
+            expected: format!("a pair type with a component {}", i),
+            got: typ,
+          })
+        }
+      };
+      Ok((Term::Proj(Box::new(term), *i), component_typ))
+    }
+    //           Γ ⊢ t ⇐ τ1
+    // -------------------------------
+    // Γ ⊢ inl t as τ1 + τ2 ⇒ τ1 + τ2
+    //
+    //           Γ ⊢ t ⇐ τ2
+    // -------------------------------
+    // Γ ⊢ inr t as τ1 + τ2 ⇒ τ1 + τ2
+    Term::Inl(injected, typ) | Term::Inr(injected, typ) => match typ {
+      Type::Sum(left, right) => {
+        let elaborated = if let Term::Inl(..) = term {
+          Term::Inl(Box::new(check(ctx, injected, left)?), typ.clone())
+        } else {
+          Term::Inr(Box::new(check(ctx, injected, right)?), typ.clone())
+        };
+        Ok((elaborated, typ.clone()))
+      }
+      _ => Err(TypecheckerError::UnexpectedType {
+        expected: String::from("sum type"),
+        got: typ.clone(),
+      }),
+    },
+    // Γ ⊢ t ⇒ τ1 + τ2    Γ, x1: τ1 ⊢ t1 ⇒ τ    Γ, x2: τ2 ⊢ t2 ⇐ τ
+    // ----------------------------------------------------------
+    //    Γ ⊢ case t of inl x1 => t1 | inr x2 => t2 ⇒ τ
+    Term::Case {
+      scrutinee,
+      left_var,
+      left,
+      right_var,
+      right,
+    } => {
+      let (scrutinee, left_typ, right_typ) = type_of_scrutinee(ctx, scrutinee)?;
+      let (left, typ) = type_of(&ctx.assign(left_var.clone(), left_typ), left)?;
+      let right = check(&ctx.assign(right_var.clone(), right_typ), right, &typ)?;
+      Ok((
+        Term::Case {
+          scrutinee: Box::new(scrutinee),
+          left_var: left_var.clone(),
+          left: Box::new(left),
+          right_var: right_var.clone(),
+          right: Box::new(right),
+        },
+        typ,
+      ))
+    }
+    // Γ ⊢ ti ⇒ τi for each i    the labels li are distinct
+    // ---------------------------------------------------
+    //   Γ ⊢ {l1 = t1, ..., ln = tn} ⇒ {l1: τ1, ..., ln: τn}
+    Term::Record(fields) => {
+      debruijn::check_distinct(&fields.iter().map(|(label, _)| label).collect::<Vec<_>>())?;
+      let mut elaborated = Vec::new();
+      let mut field_types = Vec::new();
+      for (label, term) in fields {
+        let (term, typ) = type_of(ctx, term)?;
+        elaborated.push((label.clone(), term));
+        field_types.push((label.clone(), typ));
+      }
+      Ok((Term::Record(elaborated), Type::Record(field_types)))
+    }
+    // Γ ⊢ t ⇒ {l1: τ1, ..., ln: τn}
+    // -----------------------------
+    //        Γ ⊢ t.li ⇒ τi
+    Term::Field(term, label) => match type_of(ctx, term)? {
+      (term, Type::Record(fields)) => match debruijn::field(&fields, label) {
+        Some(typ) => Ok((Term::Field(Box::new(term), label.clone()), typ.clone())),
+        None => Err(TypecheckerError::MissingLabel {
+          label: label.clone(),
+          typ: Type::Record(fields),
+        }),
+      },
+      (_, typ) => Err(TypecheckerError::UnexpectedType {
+        expected: String::from("record type"),
+        got: typ,
+      }),
+    },
+    //                       Γ ⊢ t ⇐ τi
+    // -----------------------------------------------------------
+    // Γ ⊢ <li = t> as <l1: τ1, ..., ln: τn> ⇒ <l1: τ1, ..., ln: τn>
+    Term::Tag {
+      label,
+      term: tagged,
+      typ,
+    } => match typ {
+      Type::Variant(fields) => match debruijn::field(fields, label) {
+        Some(field_typ) => Ok((
+          Term::Tag {
+            label: label.clone(),
+            term: Box::new(check(ctx, tagged, field_typ)?),
+            typ: typ.clone(),
+          },
+          typ.clone(),
+        )),
+        None => Err(TypecheckerError::MissingLabel {
+          label: label.clone(),
+          typ: typ.clone(),
+        }),
+      },
+      _ => Err(TypecheckerError::UnexpectedType {
+        expected: String::from("variant type"),
+        got: typ.clone(),
+      }),
+    },
+    // Γ ⊢ t ⇒ <l1: τ1, ..., ln: τn>    Γ, x1: τ1 ⊢ t1 ⇒ τ    Γ, xi: τi ⊢ ti ⇐ τ for i > 1
+    // -----------------------------------------------------------------------------------
+    //         Γ ⊢ case t of { <l1 = x1> => t1 | ... | <ln = xn> => tn } ⇒ τ
+    Term::VariantCase {
+      scrutinee,
+      branches,
+    } => {
+      let (scrutinee, var_types) = type_of_variant_scrutinee(ctx, scrutinee, branches)?;
+      let mut elaborated = Vec::new();
+      let mut typ = None;
+      for (branch, var_typ) in branches.iter().zip(var_types) {
+        let ctx = ctx.assign(branch.var.clone(), var_typ);
+        let body = match &typ {
+          None => {
+            let (body, body_typ) = type_of(&ctx, &branch.body)?;
+            typ = Some(body_typ);
+            body
+          }
+          Some(typ) => check(&ctx, &branch.body, typ)?,
+        };
+        elaborated.push(Branch {
+          body,
+          ..branch.clone()
+        });
+      }
+      Ok((
+        Term::VariantCase {
+          scrutinee: Box::new(scrutinee),
+          branches: elaborated,
+        },
+        typ.expect("the grammar requires at least one branch"),
+      ))
+    }
+    // Γ ⊢ t1 ⇐ Unit    Γ ⊢ t2 ⇒ τ
+    // ---------------------------
+    //      Γ ⊢ t1; t2 ⇒ τ
+    Term::Seq(first, second) => {
+      let first = check(ctx, first, &Type::Unit)?;
+      let (second, typ) = type_of(ctx, second)?;
+      Ok((Term::Seq(Box::new(first), Box::new(second)), typ))
+    }
+    //    Γ, x: τ ⊢ t ⇐ τ
+    // ----------------------
+    // Γ ⊢ fix (λx: τ. t) ⇒ τ
+    //
+    //  Γ ⊢ t ⇒ τ -> τ
+    // ---------------
+    //  Γ ⊢ fix t ⇒ τ
+    Term::Fix(term) => match &**term {
+      // The parameter type is all that is needed, so the body can be checked.
+      Term::Abs {
+        param_type: Some(param_type),
+        ..
+      } => {
+        let arrow = Type::Arrow(Box::new(param_type.clone()), Box::new(param_type.clone()));
+        Ok((
+          Term::Fix(Box::new(check(ctx, term, &arrow)?)),
+          param_type.clone(),
+        ))
+      }
+      _ => match type_of(ctx, term)? {
+        (term, Type::Arrow(param_type, return_type))
+          if debruijn::alpha_eq(&param_type, &return_type) =>
+        {
+          Ok((Term::Fix(Box::new(term)), *return_type))
+        }
+        (_, typ) => Err(TypecheckerError::UnexpectedType {
+          expected: String::from("function type of the form T -> T"),
+          got: typ,
+        }),
+      },
+    },
+    //       Γ ⊢ t ⇐ τ[μα. τ/α]
+    // ---------------------------
+    // Γ ⊢ fold [μα. τ] t ⇒ μα. τ
+    Term::Fold(typ, term) => match typ {
+      Type::Mu {
+        type_var,
+        typ: body,
+      } => {
+        let term = check(ctx, term, &debruijn::subst_type(type_var, body, typ))?;
+        Ok((Term::Fold(typ.clone(), Box::new(term)), typ.clone()))
+      }
+      _ => Err(TypecheckerError::UnexpectedType {
+        expected: String::from("recursive type"),
+        got: typ.clone(),
+      }),
+    },
+    //         Γ ⊢ t ⇐ μα. τ
+    // -------------------------------
+    // Γ ⊢ unfold [μα. τ] t ⇒ τ[μα. τ/α]
+    Term::Unfold(typ, term) => match typ {
+      Type::Mu {
+        type_var,
+        typ: body,
+      } => {
+        let term = check(ctx, term, typ)?;
+        Ok((
+          Term::Unfold(typ.clone(), Box::new(term)),
+          debruijn::subst_type(type_var, body, typ),
+        ))
+      }
+      _ => Err(TypecheckerError::UnexpectedType {
+        expected: String::from("recursive type"),
+        got: typ.clone(),
       }),
     },
   }
 }
 
+// Checks that the body of an alias only mentions its parameters and the type
+// variables in Γ.
+fn check_alias(ctx: &TypingContext, params: &[String], typ: &Type) -> Result<(), TypecheckerError> {
+  let ctx = params.iter().fold(ctx.clone(), |ctx, param| {
+    ctx.assign_type(param.clone(), Kind::Star)
+  });
+  check_type(&ctx, typ)
+}
+
+// Synthesizes the type of the scrutinee of a variant case and returns the type
+// each of the branches binds.
+fn type_of_variant_scrutinee(
+  ctx: &TypingContext,
+  scrutinee: &Term,
+  branches: &[Branch],
+) -> Result<(Term, Vec<Type>), TypecheckerError> {
+  match type_of(ctx, scrutinee)? {
+    (scrutinee, Type::Variant(fields)) => {
+      let labels: Vec<&String> = branches.iter().map(|branch| &branch.label).collect();
+      let typ = || Type::Variant(fields.clone());
+      let var_types = debruijn::branch_types(&labels, &fields, &typ)?;
+      Ok((scrutinee, var_types.into_iter().cloned().collect()))
+    }
+    (_, typ) => Err(TypecheckerError::UnexpectedType {
+      expected: String::from("variant type"),
+      got: typ,
+    }),
+  }
+}
+
+// Synthesizes the type of the scrutinee of a case, which must be a sum.
+fn type_of_scrutinee(
+  ctx: &TypingContext,
+  scrutinee: &Term,
+) -> Result<(Term, Type, Type), TypecheckerError> {
+  match type_of(ctx, scrutinee)? {
+    (scrutinee, Type::Sum(left, right)) => Ok((scrutinee, *left, *right)),
+    (_, typ) => Err(TypecheckerError::UnexpectedType {
+      expected: String::from("sum type"),
+      got: typ,
+    }),
+  }
+}
+
+// Γ ⊢ t ⇐ τ
+//
+// Checks `term` against the `expected` type, returning the elaborated term.
+fn check(ctx: &TypingContext, term: &Term, expected: &Type) -> Result<Term, TypecheckerError> {
+  match (term, expected) {
+    //      Γ, x: σ ⊢ t ⇐ τ
+    // -------------------------
+    // Γ ⊢ (λx[: σ]. t) ⇐ σ -> τ
+    (
+      Term::Abs {
+        param_name,
+        param_type,
+        body,
+      },
+      Type::Arrow(expected_param_type, return_type),
+    ) => {
+      if let Some(param_type) = param_type {
+        check_type(ctx, param_type)?;
+        if !debruijn::alpha_eq(param_type, expected_param_type) {
+          return Err(TypecheckerError::TypeMismatch {
+            term: Box::new(Term::Var(param_name.clone())),
+            expected: *expected_param_type.clone(),
+            got: param_type.clone(),
+          });
+        }
+      }
+      let ctx = ctx.assign(param_name.clone(), *expected_param_type.clone());
+      Ok(Term::Abs {
+        param_name: param_name.clone(),
+        param_type: Some(*expected_param_type.clone()),
+        body: Box::new(check(&ctx, body, return_type)?),
+      })
+    }
+    (Term::Abs { .. }, _) => Err(TypecheckerError::UnexpectedType {
+      expected: String::from("function type"),
+      got: expected.clone(),
+    }),
+    //        Γ, α : κ ⊢ t ⇐ τ[α/β]
+    // --------------------------------
+    // Γ ⊢ (Λα : κ. t) ⇐ (∀β : κ . τ)
+    (
+      Term::UniversalAbs {
+        type_var,
+        kind,
+        body,
+      },
+      Type::Forall {
+        type_var: expected_type_var,
+        typ,
+        ..
+      },
+    ) => {
+      let (type_var, body) = freshen(ctx, type_var, body)?;
+      let typ = debruijn::subst_type(expected_type_var, typ, &Type::TypeVar(type_var.clone()));
+      let ctx = ctx.assign_type(type_var.clone(), kind.clone());
+      Ok(Term::UniversalAbs {
+        type_var,
+        kind: kind.clone(),
+        body: Box::new(check(&ctx, &body, &typ)?),
+      })
+    }
+    // Γ ⊢ t1 ⇐ τ1    Γ ⊢ t2 ⇐ τ2
+    // --------------------------
+    // Γ ⊢ (t1, t2) ⇐ τ1 × τ2
+    (Term::Pair(first, second), Type::Product(first_typ, second_typ)) => Ok(Term::Pair(
+      Box::new(check(ctx, first, first_typ)?),
+      Box::new(check(ctx, second, second_typ)?),
+    )),
+    // Γ ⊢ t ⇒ τ1 + τ2    Γ, x1: τ1 ⊢ t1 ⇐ τ    Γ, x2: τ2 ⊢ t2 ⇐ τ
+    // ----------------------------------------------------------
+    //    Γ ⊢ case t of inl x1 => t1 | inr x2 => t2 ⇐ τ
+    (
+      Term::Case {
+        scrutinee,
+        left_var,
+        left,
+        right_var,
+        right,
+      },
+      _,
+    ) => {
+      let (scrutinee, left_typ, right_typ) = type_of_scrutinee(ctx, scrutinee)?;
+      Ok(Term::Case {
+        scrutinee: Box::new(scrutinee),
+        left_var: left_var.clone(),
+        left: Box::new(check(
+          &ctx.assign(left_var.clone(), left_typ),
+          left,
+          expected,
+        )?),
+        right_var: right_var.clone(),
+        right: Box::new(check(
+          &ctx.assign(right_var.clone(), right_typ),
+          right,
+          expected,
+        )?),
+      })
+    }
+    // Γ ⊢ ti ⇐ τi for each i
+    // ------------------------------------------------
+    // Γ ⊢ {l1 = t1, ..., ln = tn} ⇐ {l1: τ1, ..., ln: τn}
+    (Term::Record(fields), Type::Record(field_types))
+      if fields.len() == field_types.len()
+        && fields
+          .iter()
+          .all(|(label, _)| debruijn::field(field_types, label).is_some()) =>
+    {
+      debruijn::check_distinct(&fields.iter().map(|(label, _)| label).collect::<Vec<_>>())?;
+      let fields = fields
+        .iter()
+        .map(|(label, term)| {
+          let typ = debruijn::field(field_types, label).unwrap();
+          Ok((label.clone(), check(ctx, term, typ)?))
+        })
+        .collect::<Result<_, TypecheckerError>>()?;
+      Ok(Term::Record(fields))
+    }
+    // Γ ⊢ t ⇒ <l1: τ1, ..., ln: τn>    Γ, xi: τi ⊢ ti ⇐ τ for each i
+    // ---------------------------------------------------------------
+    //   Γ ⊢ case t of { <l1 = x1> => t1 | ... | <ln = xn> => tn } ⇐ τ
+    (
+      Term::VariantCase {
+        scrutinee,
+        branches,
+      },
+      _,
+    ) => {
+      let (scrutinee, var_types) = type_of_variant_scrutinee(ctx, scrutinee, branches)?;
+      let branches = branches
+        .iter()
+        .zip(var_types)
+        .map(|(branch, var_typ)| {
+          let ctx = ctx.assign(branch.var.clone(), var_typ);
+          Ok(Branch {
+            body: check(&ctx, &branch.body, expected)?,
+            ..branch.clone()
+          })
+        })
+        .collect::<Result<_, TypecheckerError>>()?;
+      Ok(Term::VariantCase {
+        scrutinee: Box::new(scrutinee),
+        branches,
+      })
+    }
+    // Γ ⊢ t1 ⇒ σ    Γ, x: σ ⊢ t2 ⇐ τ
+    // ------------------------------
+    //  Γ ⊢ let x = t1 in t2 ⇐ τ
+    (Term::Let { name, value, body }, _) => {
+      let (value, value_typ) = type_of(ctx, value)?;
+      let ctx = ctx.assign(name.clone(), value_typ);
+      Ok(Term::Let {
+        name: name.clone(),
+        value: Box::new(value),
+        body: Box::new(check(&ctx, body, expected)?),
+      })
+    }
+    //          Γ ⊢ t[σ/α] ⇐ τ
+    // --------------------------------
+    // Γ ⊢ type α β1 ... βn = σ; t ⇐ τ
+    (
+      Term::TypeLet {
+        type_var,
+        params,
+        typ,
+        body,
+      },
+      _,
+    ) => {
+      check_alias(ctx, params, typ)?;
+      let body = debruijn::expand_alias_in_term(type_var, params, typ, body)?;
+      check(ctx, &body, expected).map_err(|err| err.resugar(type_var, params, typ))
+    }
+    // Γ ⊢ t1 ⇐ Unit    Γ ⊢ t2 ⇐ τ
+    // ---------------------------
+    //      Γ ⊢ t1; t2 ⇐ τ
+    (Term::Seq(first, second), _) => Ok(Term::Seq(
+      Box::new(check(ctx, first, &Type::Unit)?),
+      Box::new(check(ctx, second, expected)?),
+    )),
+    // Γ ⊢ t ⇐ τ -> τ
+    // --------------
+    // Γ ⊢ fix t ⇐ τ
+    (Term::Fix(term), _) => Ok(Term::Fix(Box::new(check(
+      ctx,
+      term,
+      &Type::Arrow(Box::new(expected.clone()), Box::new(expected.clone())),
+    )?))),
+    (Term::App(..), _) => {
+      let (term, typ) = type_of_app(ctx, term, Some(expected))?;
+
+      if !debruijn::alpha_eq(&typ, expected) {
+        return Err(TypecheckerError::TypeMismatch {
+          term: Box::new(term),
+          expected: expected.clone(),
+          got: typ,
+        });
+      }
+
+      Ok(term)
+    }
+    // Γ ⊢ t ⇒ σ    σ = τ
+    // ------------------
+    //     Γ ⊢ t ⇐ τ
+    _ => {
+      let (term, typ) = type_of(ctx, term)?;
+
+      if !debruijn::alpha_eq(&typ, expected) {
+        return Err(TypecheckerError::TypeMismatch {
+          term: Box::new(term),
+          expected: expected.clone(),
+          got: typ,
+        });
+      }
+
+      Ok(term)
+    }
+  }
+}
+
+// A type argument that has not been given explicitly. It stands for the type
+// variable `type_var` of a polymorphic type and is written `?n` while unsolved.
+struct Meta {
+  name: String,
+  type_var: String,
+  solution: Option<Type>,
+}
+
+fn is_meta(metas: &[Meta], x: &str) -> bool {
+  metas.iter().any(|meta| meta.name == x)
+}
+
+fn mentions(typ: &Type, names: &[&String]) -> bool {
+  match typ {
+    Type::Int | Type::Bool | Type::Unit => false,
+    Type::Arrow(left, right)
+    | Type::Product(left, right)
+    | Type::Sum(left, right)
+    | Type::App(left, right) => mentions(left, names) || mentions(right, names),
+    Type::Record(fields) | Type::Variant(fields) => {
+      fields.iter().any(|(_, typ)| mentions(typ, names))
+    }
+    Type::TypeVar(x) => names.contains(&x),
+    Type::Forall { type_var, typ, .. }
+    | Type::Exists { type_var, typ, .. }
+    | Type::Mu { type_var, typ } => {
+      let names: Vec<&String> = names.iter().copied().filter(|x| *x != type_var).collect();
+      mentions(typ, &names)
+    }
+  }
+}
+
+fn has_unsolved_metas(metas: &[Meta], typ: &Type) -> bool {
+  let unsolved: Vec<&String> = metas
+    .iter()
+    .filter(|meta| meta.solution.is_none())
+    .map(|meta| &meta.name)
+    .collect();
+  mentions(&resolve(metas, typ), &unsolved)
+}
+
+// Replaces every solved metavariable in `typ` by its solution.
+fn resolve(metas: &[Meta], typ: &Type) -> Type {
+  let mut typ = typ.clone();
+  loop {
+    let solved: Vec<&Meta> = metas
+      .iter()
+      .filter(|meta| meta.solution.is_some() && mentions(&typ, &[&meta.name]))
+      .collect();
+    if solved.is_empty() {
+      return typ;
+    }
+    for meta in solved {
+      typ = debruijn::subst_type(&meta.name, &typ, meta.solution.as_ref().unwrap());
+    }
+  }
+}
+
+// Solves metavariables so that `a` and `b` become equal, returning false when
+// that is impossible. `bound` pairs up the type variables bound by the foralls
+// crossed so far on each side.
+//
+// Metavariables are only ever solved with types that are not polymorphic
+// themselves, which keeps the inference predicative.
+fn unify(metas: &mut Vec<Meta>, bound: &mut Vec<(String, String)>, a: &Type, b: &Type) -> bool {
+  match (resolve(metas, a), resolve(metas, b)) {
+    (Type::Int, Type::Int) | (Type::Bool, Type::Bool) | (Type::Unit, Type::Unit) => true,
+    (Type::Arrow(left_a, right_a), Type::Arrow(left_b, right_b))
+    | (Type::Product(left_a, right_a), Type::Product(left_b, right_b))
+    | (Type::Sum(left_a, right_a), Type::Sum(left_b, right_b))
+    | (Type::App(left_a, right_a), Type::App(left_b, right_b)) => {
+      unify(metas, bound, &left_a, &left_b) && unify(metas, bound, &right_a, &right_b)
+    }
+    (Type::Record(fields_a), Type::Record(fields_b))
+    | (Type::Variant(fields_a), Type::Variant(fields_b)) => {
+      fields_a.len() == fields_b.len()
+        && fields_a
+          .iter()
+          .all(|(label, typ_a)| match debruijn::field(&fields_b, label) {
+            Some(typ_b) => unify(metas, bound, typ_a, typ_b),
+            None => false,
+          })
+    }
+    (Type::TypeVar(x), Type::TypeVar(y)) if x == y && is_meta(metas, &x) => true,
+    (Type::TypeVar(x), typ) | (typ, Type::TypeVar(x)) if is_meta(metas, &x) => {
+      let mut escaping: Vec<&String> = bound.iter().flat_map(|(a, b)| [a, b]).collect();
+      escaping.push(&x);
+      if matches!(typ, Type::Forall { .. }) || mentions(&typ, &escaping) {
+        return false;
+      }
+      let meta = metas.iter_mut().find(|meta| meta.name == x).unwrap();
+      meta.solution = Some(typ);
+      true
+    }
+    (Type::TypeVar(x), Type::TypeVar(y)) => {
+      match bound.iter().rev().find(|(a, b)| a == &x || b == &y) {
+        Some((a, b)) => a == &x && b == &y,
+        None => x == y,
+      }
+    }
+    (
+      Type::Forall {
+        type_var: a,
+        typ: typ_a,
+        ..
+      },
+      Type::Forall {
+        type_var: b,
+        typ: typ_b,
+        ..
+      },
+    )
+    | (
+      Type::Exists {
+        type_var: a,
+        typ: typ_a,
+        ..
+      },
+      Type::Exists {
+        type_var: b,
+        typ: typ_b,
+        ..
+      },
+    )
+    | (
+      Type::Mu {
+        type_var: a,
+        typ: typ_a,
+      },
+      Type::Mu {
+        type_var: b,
+        typ: typ_b,
+      },
+    ) => {
+      bound.push((a, b));
+      let unified = unify(metas, bound, &typ_a, &typ_b);
+      bound.pop();
+      unified
+    }
+    _ => false,
+  }
+}
+
+// Replaces the leading foralls of `typ` by fresh metavariables, returning the
+// instantiated type and the metavariables in order.
+fn instantiate(metas: &mut Vec<Meta>, mut typ: Type) -> (Type, Vec<usize>) {
+  let mut instantiated = Vec::new();
+  while let Type::Forall {
+    type_var,
+    typ: body,
+    ..
+  } = typ
+  {
+    let name = format!("?{}", metas.len());
+    typ = debruijn::subst_type(&type_var, &body, &Type::TypeVar(name.clone()));
+    instantiated.push(metas.len());
+    metas.push(Meta {
+      name,
+      type_var,
+      solution: None,
+    });
+  }
+  (typ, instantiated)
+}
+
+// Applies `term` to the solutions of the metavariables `instantiated`.
+fn apply_solutions(
+  metas: &[Meta],
+  term: Term,
+  instantiated: &[usize],
+  spine: &Term,
+) -> Result<Term, TypecheckerError> {
+  instantiated.iter().try_fold(term, |term, i| {
+    let solution = resolve(metas, &Type::TypeVar(metas[*i].name.clone()));
+    if has_unsolved_metas(metas, &solution) {
+      return Err(TypecheckerError::AmbiguousTypeArgument {
+        term: Box::new(spine.clone()),
+        type_var: metas[*i].type_var.clone(),
+      });
+    }
+    Ok(Term::UniversalApp(Box::new(term), solution))
+  })
+}
+
+enum Arg {
+  Checked(Term),
+  // A polymorphic argument passed where a monomorphic type is expected,
+  // waiting for its type arguments to be solved.
+  Instantiated(Term, Vec<usize>),
+  // An unannotated abstraction waiting for its parameter type to be solved.
+  Deferred(Type),
+}
+
+// Typechecks an unannotated abstraction passed where `param_type` is
+// expected, or returns `None` while the type of its parameter is unknown.
+// Once it is known, the type of the body can solve the metavariables that
+// remain.
+fn type_of_deferred(
+  ctx: &TypingContext,
+  metas: &mut Vec<Meta>,
+  arg: &Term,
+  param_type: &Type,
+) -> Result<Option<Term>, TypecheckerError> {
+  let param_type = resolve(metas, param_type);
+  if !has_unsolved_metas(metas, &param_type) {
+    return check(ctx, arg, &param_type).map(Some);
+  }
+  match (arg, &param_type) {
+    (
+      Term::Abs {
+        param_name, body, ..
+      },
+      Type::Arrow(abs_param_type, _),
+    ) if !has_unsolved_metas(metas, abs_param_type) => {
+      let annotated = Term::Abs {
+        param_name: param_name.clone(),
+        param_type: Some(*abs_param_type.clone()),
+        body: body.clone(),
+      };
+      let (elaborated, typ) = type_of(ctx, &annotated)?;
+      if !unify(metas, &mut Vec::new(), &param_type, &typ) {
+        return Err(TypecheckerError::TypeMismatch {
+          term: Box::new(arg.clone()),
+          expected: resolve(metas, &param_type),
+          got: typ,
+        });
+      }
+      Ok(Some(elaborated))
+    }
+    _ => Ok(None),
+  }
+}
+
+// Γ ⊢ f t1 ... tn ⇒ τ
+//
+// Applications are typed as a whole spine so that type arguments can be left
+// implicit. When f: ∀α. σ, α is replaced by a metavariable that is solved by
+// unifying the parameter types with the types of the arguments and, in
+// checking mode, the result type with the expected type. The solutions are
+// then inserted as explicit type applications, so `id 1` elaborates to
+// `id [Int] 1`. Polymorphic arguments are instantiated the same way when
+// their parameter type is not polymorphic, so `id id 1` elaborates to
+// `id [Int -> Int] (id [Int]) 1`. A forall reached in the middle of the spine
+// is instantiated the same way before the next argument is applied, so with
+// f: ∀X. X -> ∀Y. Y -> X, `f 1 ()` elaborates to `f [Int] 1 [Unit] ()`.
+//
+// Arguments whose parameter type is already known are checked against it.
+// Unannotated abstractions are deferred until the other arguments have solved
+// the metavariables in their parameter type.
+fn type_of_app(
+  ctx: &TypingContext,
+  term: &Term,
+  expected: Option<&Type>,
+) -> Result<(Term, Type), TypecheckerError> {
+  let mut args = Vec::new();
+  let mut head = term;
+  while let Term::App(f, arg) = head {
+    args.push(&**arg);
+    head = f;
+  }
+  args.reverse();
+
+  let (head, mut typ) = type_of(ctx, head)?;
+  let mut metas = Vec::new();
+
+  // The metavariables that stand for the type arguments applied before each
+  // argument.
+  let mut instantiated = Vec::new();
+  let mut elaborated_args = Vec::new();
+  for (i, arg) in args.iter().enumerate() {
+    let resolved = resolve(&metas, &typ);
+    let (instantiated_typ, type_args) = instantiate(&mut metas, resolved);
+    instantiated.push(type_args);
+    let (param_type, return_type) = match instantiated_typ {
+      Type::Arrow(param_type, return_type) => (*param_type, *return_type),
+      _ => {
+        return Err(TypecheckerError::UnexpectedTerm {
+          expected: String::from("abstraction"),
+          got: Box::new(args[..i].iter().fold(head, |f, arg| {
+            Term::App(Box::new(f), Box::new((*arg).clone()))
+          })),
+        })
+      }
+    };
+    typ = return_type;
+
+    if !has_unsolved_metas(&metas, &param_type) {
+      let param_type = resolve(&metas, &param_type);
+      elaborated_args.push(Arg::Checked(check(ctx, arg, &param_type)?));
+    } else if let Term::Abs {
+      param_type: None, ..
+    } = arg
+    {
+      elaborated_args.push(Arg::Deferred(param_type));
+    } else {
+      let (arg_elaborated, arg_typ) = type_of(ctx, arg)?;
+      let (arg_typ, arg_instantiated) = match resolve(&metas, &param_type) {
+        Type::Forall { .. } => (arg_typ, Vec::new()),
+        _ => instantiate(&mut metas, arg_typ),
+      };
+      if !unify(&mut metas, &mut Vec::new(), &param_type, &arg_typ) {
+        return Err(TypecheckerError::TypeMismatch {
+          term: Box::new((*arg).clone()),
+          expected: resolve(&metas, &param_type),
+          got: resolve(&metas, &arg_typ),
+        });
+      }
+      elaborated_args.push(Arg::Instantiated(arg_elaborated, arg_instantiated));
+    }
+  }
+
+  if let Some(expected) = expected {
+    // A mismatch is reported by the caller once the type is fully known.
+    unify(&mut metas, &mut Vec::new(), &typ, expected);
+  }
+
+  // Each deferred abstraction whose parameter type is known can solve the
+  // metavariables another one is waiting on, so keep going while that helps.
+  let mut progress = true;
+  while progress {
+    progress = false;
+    for (arg, elaborated_arg) in args.iter().zip(elaborated_args.iter_mut()) {
+      if let Arg::Deferred(param_type) = elaborated_arg {
+        if let Some(elaborated) = type_of_deferred(ctx, &mut metas, arg, param_type)? {
+          *elaborated_arg = Arg::Checked(elaborated);
+          progress = true;
+        }
+      }
+    }
+  }
+  for (arg, elaborated_arg) in args.iter().zip(&elaborated_args) {
+    if let Arg::Deferred(_) = elaborated_arg {
+      return Err(TypecheckerError::MissingAnnotation(Box::new(
+        (*arg).clone(),
+      )));
+    }
+  }
+
+  let mut elaborated = head;
+  for (elaborated_arg, type_args) in elaborated_args.into_iter().zip(&instantiated) {
+    elaborated = apply_solutions(&metas, elaborated, type_args, term)?;
+    let arg = match elaborated_arg {
+      Arg::Instantiated(arg, instantiated) => apply_solutions(&metas, arg, &instantiated, term)?,
+      Arg::Checked(arg) => arg,
+      Arg::Deferred(_) => unreachable!("deferred arguments are checked above"),
+    };
+    elaborated = Term::App(Box::new(elaborated), Box::new(arg));
+  }
+
+  Ok((elaborated, resolve(&metas, &typ)))
+}
+
+// Typechecks `term`, returning it with every λ annotated with its parameter
+// type and every implicit type argument applied explicitly.
+pub fn elaborate(term: &Term) -> Result<(Term, Type), TypecheckerError> {
+  elaborate_in(&prelude_context(), term)
+}
+
+// Like `elaborate`, for a term whose free variables are typed by `ctx`. Start
+// from `prelude_context()` to keep the prelude in scope.
+//
+// The elaborated term is checked again on the de Bruijn representation, whose
+// rules do not depend on names, and the type it gets there is the one
+// returned. It is written the way the named checker wrote it, which keeps the
+// fields of records and variants in their order in the source, when the two
+// agree.
+pub fn elaborate_in(ctx: &TypingContext, term: &Term) -> Result<(Term, Type), TypecheckerError> {
+  let (term, named_typ) = type_of(ctx, term)?;
+  let ctx = ctx.to_debruijn()?;
+  let typ = debruijn::Term::from_ast(&ctx, &term)?
+    .type_of(&ctx)?
+    .to_ast(&ctx);
+  if debruijn::alpha_eq(&named_typ, &typ) {
+    Ok((term, named_typ))
+  } else {
+    Ok((term, typ))
+  }
+}
+
+// Checks every prelude definition against its declared type, with the
+// definitions before it in scope, and returns them elaborated. The prelude is
+// only checked the first time.
+pub fn elaborate_prelude() -> Result<&'static [Definition], TypecheckerError> {
+  static ELABORATED: OnceLock<Result<Vec<Definition>, TypecheckerError>> = OnceLock::new();
+  ELABORATED
+    .get_or_init(check_prelude)
+    .as_deref()
+    .map_err(Clone::clone)
+}
+
+fn check_prelude() -> Result<Vec<Definition>, TypecheckerError> {
+  let mut ctx = TypingContext::new();
+  let mut definitions = Vec::new();
+  for definition in prelude::definitions() {
+    let term = check(&ctx, &definition.term, &definition.typ)?;
+    ctx = ctx.assign(definition.name.clone(), definition.typ.clone());
+    definitions.push(Definition {
+      term,
+      ..definition.clone()
+    });
+  }
+  Ok(definitions)
+}
+
 pub fn infer(term: &Term) -> Result<Type, TypecheckerError> {
-  type_of(&TypingContext::new(), term)
+  let (_, typ) = elaborate(term)?;
+  Ok(typ)
+}
+
+pub fn infer_in(ctx: &TypingContext, term: &Term) -> Result<Type, TypecheckerError> {
+  let (_, typ) = elaborate_in(ctx, term)?;
+  Ok(typ)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn infer(input: &str) -> Result<Type, TypecheckerError> {
+    super::infer(&crate::parse(input).unwrap())
+  }
+
+  fn parse_type(input: &str) -> Type {
+    crate::parse_type(input).unwrap()
+  }
+
+  #[test]
+  fn type_variables_must_be_in_scope() {
+    let undefined = |x: &str| Err(TypecheckerError::UndefinedTypeVariable(String::from(x)));
+    assert_eq!(infer("λx: Y. x"), undefined("Y"));
+    assert_eq!(infer("(ΛX: *. λx: X. x) [Y]"), undefined("Y"));
+    assert_eq!(infer("pack [Y, ()] as ∃X: *. Unit"), undefined("Y"));
+    assert_eq!(infer("fix (λx: Y. x)"), undefined("Y"));
+    assert_eq!(infer("type A = Y; 1"), undefined("Y"));
+    assert_eq!(
+      infer("(λf: Y -> Int. 1 : (Int -> Int) -> Int)"),
+      undefined("Y")
+    );
+    assert_eq!(
+      infer("type F A = A -> A; λf: F Int. f"),
+      Ok(parse_type("(Int -> Int) -> Int -> Int"))
+    );
+  }
+
+  #[test]
+  fn shadowed_type_variables_are_renamed() {
+    let program = "(ΛX: *. λx: X. ΛX: *. λy: X. x) [Int] 1 [Bool -> Bool] (λb: Bool. b)";
+    assert_eq!(infer(program), Ok(Type::Int));
+    assert_eq!(
+      debruijn::eval(&crate::parse(program).unwrap()),
+      Ok(Term::Int(1))
+    );
+    assert_eq!(
+      infer("ΛX: *. λx: X. ΛX: *. λy: X. x"),
+      Ok(parse_type("∀X: *. X -> (∀X': *. X' -> X)"))
+    );
+    assert_eq!(
+      infer("(ΛX: *. λx: X. ΛX: *. λy: X. x : ∀A: *. A -> (∀B: *. B -> A))"),
+      Ok(parse_type("∀A: *. A -> (∀B: *. B -> A)"))
+    );
+    assert_eq!(
+      infer("(ΛX: *. λx: X. ΛX: *. λy: X. y : ∀A: *. A -> (∀B: *. B -> A))"),
+      Err(TypecheckerError::TypeMismatch {
+        term: Box::new(Term::Var(String::from("y"))),
+        expected: Type::TypeVar(String::from("X")),
+        got: Type::TypeVar(String::from("X'")),
+      })
+    );
+  }
+  #[test]
+  fn unpacked_type_variables_are_renamed() {
+    // The X of the unpack is a new abstract type, not the X of the Λ.
+    assert_eq!(
+      infer("ΛX: *. λp: ∃Y: *. Y. λf: X -> Int. unpack p as [X, x] in f x"),
+      Err(TypecheckerError::TypeMismatch {
+        term: Box::new(Term::Var(String::from("x"))),
+        expected: Type::TypeVar(String::from("X")),
+        got: Type::TypeVar(String::from("X'")),
+      })
+    );
+    assert_eq!(
+      infer("ΛX: *. λp: ∃Y: *. Y. unpack p as [X, x] in x"),
+      Err(TypecheckerError::EscapingTypeVariable {
+        type_var: String::from("X'"),
+        typ: Type::TypeVar(String::from("X'")),
+      })
+    );
+    assert_eq!(
+      infer("ΛX: *. λx: X. λp: ∃Y: *. Y -> Int. unpack p as [X, f] in x"),
+      Ok(parse_type("∀X: *. X -> (∃Y: *. Y -> Int) -> X"))
+    );
+  }
+  #[test]
+  fn implicit_type_arguments_are_elaborated() {
+    let elaborate = |input: &str| {
+      super::elaborate(&crate::parse(input).unwrap()).map(|(term, typ)| (term.to_string(), typ))
+    };
+    let id = "let id = ΛX: *. λx: X. x in";
+    assert_eq!(
+      elaborate(&format!("{} id 1", id)),
+      Ok((format!("{} id [Int] 1", id), Type::Int))
+    );
+    assert_eq!(
+      elaborate(&format!("{} id id 1", id)),
+      Ok((format!("{} id [Int -> Int] (id [Int]) 1", id), Type::Int))
+    );
+    // A forall in the middle of the spine is instantiated before the argument
+    // that follows it.
+    let f = "let f = ΛX: *. λx: X. ΛY: *. λy: Y. x in";
+    assert_eq!(
+      elaborate(&format!("{} f 1 ()", f)),
+      Ok((format!("{} f [Int] 1 [Unit] ()", f), Type::Int))
+    );
+    assert_eq!(
+      elaborate(&format!("{} f 1 (true [Int])", f)),
+      Ok((
+        format!("{} f [Int] 1 [Int -> Int -> Int] (true [Int])", f),
+        Type::Int
+      ))
+    );
+    assert_eq!(
+      elaborate(&format!("{} f 1", f)),
+      Ok((format!("{} f [Int] 1", f), parse_type("∀Y: *. Y -> Int")))
+    );
+  }
+
+  #[test]
+  fn type_arguments_that_cannot_be_inferred_are_reported() {
+    assert_eq!(
+      infer("let g = ΛX: *. λx: Int. x in g 1"),
+      Err(TypecheckerError::AmbiguousTypeArgument {
+        term: Box::new(crate::parse("g 1").unwrap()),
+        type_var: String::from("X"),
+      })
+    );
+    // Arguments are instantiated predicatively, so the type argument of
+    // `true` is not determined by anything.
+    assert_eq!(
+      infer("let f = ΛX: *. λx: X. ΛY: *. λy: Y. x in f 1 true"),
+      Err(TypecheckerError::AmbiguousTypeArgument {
+        term: Box::new(crate::parse("f 1 true").unwrap()),
+        type_var: String::from("Y"),
+      })
+    );
+    assert_eq!(
+      infer("(λx. x) 1"),
+      Err(TypecheckerError::MissingAnnotation(Box::new(
+        crate::parse("λx. x").unwrap()
+      )))
+    );
+    assert_eq!(
+      infer("let apply = ΛX: *. ΛY: *. λf: X -> Y. f in apply (λx. x)"),
+      Err(TypecheckerError::MissingAnnotation(Box::new(
+        crate::parse("λx. x").unwrap()
+      )))
+    );
+  }
+
+  #[test]
+  fn hidden_types_cannot_escape() {
+    let package = "pack [Int, 1] as ∃X: *. X";
+    assert_eq!(
+      infer(&format!("unpack {} as [X, x] in x", package)),
+      Err(TypecheckerError::EscapingTypeVariable {
+        type_var: String::from("X"),
+        typ: Type::TypeVar(String::from("X")),
+      })
+    );
+    assert_eq!(
+      infer(&format!("unpack {} as [X, x] in λy: X. 1", package)),
+      Err(TypecheckerError::EscapingTypeVariable {
+        type_var: String::from("X"),
+        typ: parse_type("X -> Int"),
+      })
+    );
+    assert_eq!(
+      infer("pack [Int, ()] as ∃X: *. X"),
+      Err(TypecheckerError::TypeMismatch {
+        term: Box::new(Term::Unit),
+        expected: Type::Int,
+        got: Type::Unit,
+      })
+    );
+    assert_eq!(
+      infer("pack [Int, 1] as Int"),
+      Err(TypecheckerError::UnexpectedType {
+        expected: String::from("existential type"),
+        got: Type::Int,
+      })
+    );
+  }
+
+  #[test]
+  fn products_and_sums() {
+    assert_eq!(infer("(1, ())"), Ok(parse_type("Int × Unit")));
+    assert_eq!(
+      infer("λp: Int × Unit. p.2"),
+      Ok(parse_type("Int × Unit -> Unit"))
+    );
+    assert_eq!(
+      infer("(1, ()).3"),
+      Err(TypecheckerError::UnexpectedType {
+        expected: String::from("a pair type with a component 3"),
+        got: parse_type("Int × Unit"),
+      })
+    );
+    assert_eq!(
+      infer("inl () as Int + Unit"),
+      Err(TypecheckerError::TypeMismatch {
+        term: Box::new(Term::Unit),
+        expected: Type::Int,
+        got: Type::Unit,
+      })
+    );
+    assert_eq!(
+      infer("inr 1 as Int"),
+      Err(TypecheckerError::UnexpectedType {
+        expected: String::from("sum type"),
+        got: Type::Int,
+      })
+    );
+    // Both branches of a case have the same type.
+    assert_eq!(
+      infer("λs: Int + Unit. case s of inl x => x | inr u => u"),
+      Err(TypecheckerError::TypeMismatch {
+        term: Box::new(Term::Var(String::from("u"))),
+        expected: Type::Int,
+        got: Type::Unit,
+      })
+    );
+  }
+
+  #[test]
+  fn labels_are_checked() {
+    let error = |input: &str| infer(input).unwrap_err();
+    let option = Type::Variant(vec![
+      (String::from("some"), Type::Int),
+      (String::from("none"), Type::Unit),
+    ]);
+    assert_eq!(
+      error("{x = 1, y = 2}.z"),
+      TypecheckerError::MissingLabel {
+        label: String::from("z"),
+        typ: Type::Record(vec![
+          (String::from("x"), Type::Int),
+          (String::from("y"), Type::Int),
+        ]),
+      }
+    );
+    assert_eq!(
+      error("{x = 1, x = 2}"),
+      TypecheckerError::DuplicateLabel(String::from("x"))
+    );
+    assert_eq!(
+      error("<all = 1> as <some: Int, none: Unit>"),
+      TypecheckerError::MissingLabel {
+        label: String::from("all"),
+        typ: option.clone(),
+      }
+    );
+    assert_eq!(
+      error("case <some = 1> as <some: Int, none: Unit> of { <some = n> => n }"),
+      TypecheckerError::NonExhaustiveMatch {
+        label: String::from("none"),
+        typ: option.clone(),
+      }
+    );
+    assert_eq!(
+      error("case <some = 1> as <some: Int, none: Unit> of { <some = n> => n | <some = m> => m }"),
+      TypecheckerError::DuplicateLabel(String::from("some"))
+    );
+  }
+
+  #[test]
+  fn records_and_variants() {
+    // Fields can be given in any order.
+    assert_eq!(
+      infer("(λp: {y: Int, x: Unit}. p.y) {x = (), y = 2}"),
+      Ok(Type::Int)
+    );
+    assert_eq!(
+      infer("<none = ()> as <some: Int, none: Unit>"),
+      Ok(parse_type("<some: Int, none: Unit>"))
+    );
+    assert_eq!(
+      infer("case <some = 1> as <some: Int, none: Unit> of { <none = u> => u | <some = n> => n }"),
+      Err(TypecheckerError::TypeMismatch {
+        term: Box::new(Term::Var(String::from("n"))),
+        expected: Type::Unit,
+        got: Type::Int,
+      })
+    );
+  }
+
+  #[test]
+  fn sequencing() {
+    assert_eq!(infer("(); 1"), Ok(Type::Int));
+    assert_eq!(infer("λu: Unit. u; u"), Ok(parse_type("Unit -> Unit")));
+    assert_eq!(
+      infer("1; 2"),
+      Err(TypecheckerError::TypeMismatch {
+        term: Box::new(Term::Int(1)),
+        expected: Type::Unit,
+        got: Type::Int,
+      })
+    );
+  }
+
+  #[test]
+  fn recursion() {
+    assert_eq!(
+      infer("let rec f: Int -> Int = λx. f x in f"),
+      Ok(parse_type("Int -> Int"))
+    );
+    assert_eq!(infer("fix (λx: Int. x)"), Ok(Type::Int));
+    assert_eq!(
+      infer("fix (λx: Int. ())"),
+      Err(TypecheckerError::TypeMismatch {
+        term: Box::new(Term::Unit),
+        expected: Type::Int,
+        got: Type::Unit,
+      })
+    );
+    assert_eq!(
+      infer("fix ((λx: Int. ()) : Int -> Unit)"),
+      Err(TypecheckerError::UnexpectedType {
+        expected: String::from("function type of the form T -> T"),
+        got: parse_type("Int -> Unit"),
+      })
+    );
+  }
+
+  #[test]
+  fn recursive_types() {
+    let list = "μL. <nil: Unit, cons: Int × L>";
+    let unfolded = format!("<nil: Unit, cons: Int × ({})>", list);
+    let nil = format!("fold [{}] (<nil = ()> as {})", list, unfolded);
+    let xs = format!("fold [{}] (<cons = (1, {})> as {})", list, nil, unfolded);
+    assert_eq!(infer(&xs), Ok(parse_type(list)));
+    // The elaborated type lists the labels of the unfolded variant in order.
+    assert_eq!(
+      infer(&format!("λxs: {}. unfold [{}] xs", list, list)),
+      Ok(parse_type(&format!(
+        "({}) -> <cons: Int × (μL. <cons: Int × L, nil: Unit>), nil: Unit>",
+        list
+      )))
+    );
+  }
+
+  #[test]
+  fn fold_and_unfold_are_checked() {
+    assert_eq!(
+      infer("fold [μL. Unit + L] 1"),
+      Err(TypecheckerError::TypeMismatch {
+        term: Box::new(Term::Int(1)),
+        expected: parse_type("Unit + (μL. Unit + L)"),
+        got: Type::Int,
+      })
+    );
+    assert_eq!(
+      infer("unfold [Int] 1"),
+      Err(TypecheckerError::UnexpectedType {
+        expected: String::from("recursive type"),
+        got: Type::Int,
+      })
+    );
+  }
+
+  #[test]
+  fn type_aliases() {
+    assert_eq!(
+      infer("let P = Int × Unit in λp: P. p.1"),
+      Ok(parse_type("Int × Unit -> Int"))
+    );
+    // The alias refers to the outer Y, not the one bound inside its scope.
+    assert_eq!(
+      infer("ΛY: *. let A = Y -> Y in ΛY: *. λf: A. λy: Y. y"),
+      Ok(parse_type("∀Y: *. ∀Y': *. (Y -> Y) -> Y' -> Y'"))
+    );
+    // An alias can be shadowed by a type variable.
+    assert_eq!(
+      infer("let A = Int in ΛA: *. λa: A. a"),
+      Ok(parse_type("∀A: *. A -> A"))
+    );
+  }
+
+  #[test]
+  fn type_declarations() {
+    let nat = format!("type Nat = {};", crate::prelude::NAT);
+    let pair = "type Pair A B = {fst: A, snd: B};";
+    assert_eq!(
+      infer(&format!("{} λp: Pair Int (Pair Unit Int). p.snd.fst", pair)),
+      Ok(parse_type("{fst: Int, snd: {fst: Unit, snd: Int}} -> Unit"))
+    );
+    assert_eq!(
+      infer(&format!("{} λp: Pair Int. p", pair)),
+      Err(TypecheckerError::WrongNumberOfTypeArguments {
+        name: String::from("Pair"),
+        expected: 2,
+        got: 1,
+      })
+    );
+    // Errors show the alias rather than its expansion.
+    assert_eq!(
+      infer(&format!(
+        "{} {} λp: Pair Nat Int. (p : Pair Int Nat)",
+        nat, pair
+      )),
+      Err(TypecheckerError::TypeMismatch {
+        term: Box::new(Term::Var(String::from("p"))),
+        expected: parse_type("Pair Int Nat"),
+        got: parse_type("Pair Nat Int"),
+      })
+    );
+  }
+
+  #[test]
+  fn types_are_compared_by_their_normal_forms() {
+    // An applied alias is equal to its expansion, in both directions. The
+    // alias is out of scope in the type of the whole program, so it is
+    // expanded there.
+    let pair = "type Pair A = A × A;";
+    assert_eq!(
+      infer(&format!("{} λp: Pair Int. (p : Int × Int)", pair)),
+      Ok(parse_type("Int × Int -> Int × Int"))
+    );
+    assert_eq!(
+      infer(&format!("{} λp: Int × Int. (p : Pair Int)", pair)),
+      Ok(parse_type("Int × Int -> Int × Int"))
+    );
+    assert_eq!(
+      infer(&format!("{} λp: Pair Int. (p : Int × Unit)", pair)),
+      Err(TypecheckerError::TypeMismatch {
+        term: Box::new(Term::Var(String::from("p"))),
+        expected: parse_type("Int × Unit"),
+        got: parse_type("Pair Int"),
+      })
+    );
+    // The arguments of a higher-kinded type variable are compared by their
+    // normal forms too.
+    let ctx = TypingContext::new().assign_type(
+      String::from("F"),
+      Kind::Arrow(Box::new(Kind::Star), Box::new(Kind::Star)),
+    );
+    let infer_in = |input: &str| super::infer_in(&ctx, &crate::parse(input).unwrap());
+    assert_eq!(
+      infer_in(&format!("{} λx: F (Pair Int). (x : F (Int × Int))", pair)),
+      Ok(parse_type("F (Int × Int) -> F (Int × Int)"))
+    );
+    let twice = "type Twice A = F (F A);";
+    assert_eq!(
+      infer_in(&format!(
+        "{} {} λx: Twice (Pair Int). (x : F (F (Int × Int)))",
+        pair, twice
+      )),
+      Ok(parse_type("F (F (Int × Int)) -> F (F (Int × Int))"))
+    );
+    assert_eq!(
+      infer_in(&format!("{} λx: Twice Int. (x : F Int)", twice)),
+      Err(TypecheckerError::TypeMismatch {
+        term: Box::new(Term::Var(String::from("x"))),
+        expected: parse_type("F Int"),
+        got: parse_type("Twice Int"),
+      })
+    );
+  }
 }
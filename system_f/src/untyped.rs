@@ -0,0 +1,817 @@
+use crate::debruijn::{self, EvalError, Hint};
+use crate::{ast, typechecker::TypecheckerError};
+use lambda_core::names::shift_index;
+use std::fmt;
+
+// Untyped lambda calculus, the target of type erasure.
+//
+// Term ::=
+//  | Int
+//  | ()
+//  | n          -- term variable, as a de Bruijn index
+//  | Term Term
+//  | λ. Term
+//  | (Term, Term)
+//  | Term.n
+//  | inl Term
+//  | inr Term
+//  | case Term of inl => Term | inr => Term
+//  | {Label = Term, ...}
+//  | Term.Label
+//  | <Label = Term>
+//  | case Term of { <Label = > => Term | ... }
+//  | Term; Term
+//  | fix Term
+//
+// Types only constrain which programs can be written, so a well-typed System F
+// term computes the same result as its erasure:
+//
+//   erase(x)            = x
+//   erase(λx: τ. t)     = λx. erase(t)
+//   erase(t1 t2)        = erase(t1) erase(t2)
+//   erase(Λα : κ. t)    = λ_. erase(t)
+//   erase(t [τ])        = erase(t) ()
+//   erase(pack [σ, t] as τ)          = erase(t)
+//   erase(unpack t1 as [α, x] in t2) = (λx. erase(t2)) erase(t1)
+//   erase(let x = t1 in t2)          = (λx. erase(t2)) erase(t1)
+//   erase(inl t as τ)   = inl erase(t)
+//   erase(inr t as τ)   = inr erase(t)
+//   erase(<l = t> as τ) = <l = erase(t)>
+//   erase(fold [τ] t)   = erase(t)
+//   erase(unfold [τ] t) = erase(t)
+//
+// This is what makes a polymorphic function parametric: a function of type
+// ∀α. α -> α cannot inspect its argument, since there is no α left at runtime
+// to do it with.
+//
+// A type abstraction is a value, so its body is only evaluated once it is
+// applied to a type. Its erasure is a λ waiting for a dummy argument, which
+// keeps it a value too: `ΛX: *. fix (λx: Int. x)` does not diverge, and
+// neither does `λ_. fix (λx. x)`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Term {
+  Int(i32),
+  Unit,
+  Var(usize),
+  App(Box<Term>, Box<Term>),
+  Abs {
+    hint: Hint,
+    body: Box<Term>,
+  },
+  Pair(Box<Term>, Box<Term>),
+  Proj(Box<Term>, usize),
+  Inl(Box<Term>),
+  Inr(Box<Term>),
+  Case {
+    scrutinee: Box<Term>,
+    left_hint: Hint,
+    left: Box<Term>,
+    right_hint: Hint,
+    right: Box<Term>,
+  },
+  Record(Vec<(String, Term)>),
+  Field(Box<Term>, String),
+  Tag(String, Box<Term>),
+  VariantCase {
+    scrutinee: Box<Term>,
+    branches: Vec<Branch>,
+  },
+  Seq(Box<Term>, Box<Term>),
+  Fix(Box<Term>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Branch {
+  pub label: String,
+  pub hint: Hint,
+  pub body: Term,
+}
+
+pub fn erase(term: &debruijn::Term) -> Term {
+  match term {
+    debruijn::Term::Int(n) => Term::Int(*n),
+    debruijn::Term::Unit => Term::Unit,
+    debruijn::Term::Var(i) => Term::Var(*i),
+    debruijn::Term::App(f, arg) => Term::App(Box::new(erase(f)), Box::new(erase(arg))),
+    debruijn::Term::Abs { hint, body, .. } => Term::Abs {
+      hint: hint.clone(),
+      body: Box::new(erase(body)),
+    },
+    // Term and type variables have separate de Bruijn indices, so the λ that
+    // replaces a type binder shifts the term variables below it.
+    debruijn::Term::UniversalAbs { body, .. } => Term::Abs {
+      hint: Hint(String::from("_")),
+      body: Box::new(erase(body).shift(1, 0)),
+    },
+    debruijn::Term::UniversalApp(term, _) => Term::App(Box::new(erase(term)), Box::new(Term::Unit)),
+    debruijn::Term::Pack { term, .. } => erase(term),
+    debruijn::Term::Fold(_, term) | debruijn::Term::Unfold(_, term) => erase(term),
+    debruijn::Term::Unpack {
+      hint,
+      package,
+      body,
+      ..
+    } => Term::App(
+      Box::new(Term::Abs {
+        hint: hint.clone(),
+        body: Box::new(erase(body)),
+      }),
+      Box::new(erase(package)),
+    ),
+    debruijn::Term::Pair(first, second) => {
+      Term::Pair(Box::new(erase(first)), Box::new(erase(second)))
+    }
+    debruijn::Term::Proj(term, i) => Term::Proj(Box::new(erase(term)), *i),
+    debruijn::Term::Inl(term, _) => Term::Inl(Box::new(erase(term))),
+    debruijn::Term::Inr(term, _) => Term::Inr(Box::new(erase(term))),
+    debruijn::Term::Case {
+      scrutinee,
+      left_hint,
+      left,
+      right_hint,
+      right,
+    } => Term::Case {
+      scrutinee: Box::new(erase(scrutinee)),
+      left_hint: left_hint.clone(),
+      left: Box::new(erase(left)),
+      right_hint: right_hint.clone(),
+      right: Box::new(erase(right)),
+    },
+    debruijn::Term::Record(fields) => Term::Record(
+      fields
+        .iter()
+        .map(|(label, term)| (label.clone(), erase(term)))
+        .collect(),
+    ),
+    debruijn::Term::Field(term, label) => Term::Field(Box::new(erase(term)), label.clone()),
+    debruijn::Term::Tag { label, term, .. } => Term::Tag(label.clone(), Box::new(erase(term))),
+    debruijn::Term::VariantCase {
+      scrutinee,
+      branches,
+    } => Term::VariantCase {
+      scrutinee: Box::new(erase(scrutinee)),
+      branches: branches
+        .iter()
+        .map(|branch| Branch {
+          label: branch.label.clone(),
+          hint: branch.hint.clone(),
+          body: erase(&branch.body),
+        })
+        .collect(),
+    },
+    debruijn::Term::Seq(first, second) => {
+      Term::Seq(Box::new(erase(first)), Box::new(erase(second)))
+    }
+    debruijn::Term::Let { hint, value, body } => Term::App(
+      Box::new(Term::Abs {
+        hint: hint.clone(),
+        body: Box::new(erase(body)),
+      }),
+      Box::new(erase(value)),
+    ),
+    debruijn::Term::Fix(term) => Term::Fix(Box::new(erase(term))),
+  }
+}
+
+impl Term {
+  pub(crate) fn map_vars(&self, cutoff: usize, on_var: &dyn Fn(usize, usize) -> Term) -> Term {
+    match self {
+      Term::Int(n) => Term::Int(*n),
+      Term::Unit => Term::Unit,
+      Term::Var(i) => on_var(cutoff, *i),
+      Term::App(f, arg) => Term::App(
+        Box::new(f.map_vars(cutoff, on_var)),
+        Box::new(arg.map_vars(cutoff, on_var)),
+      ),
+      Term::Abs { hint, body } => Term::Abs {
+        hint: hint.clone(),
+        body: Box::new(body.map_vars(cutoff + 1, on_var)),
+      },
+      Term::Pair(first, second) => Term::Pair(
+        Box::new(first.map_vars(cutoff, on_var)),
+        Box::new(second.map_vars(cutoff, on_var)),
+      ),
+      Term::Proj(term, i) => Term::Proj(Box::new(term.map_vars(cutoff, on_var)), *i),
+      Term::Inl(term) => Term::Inl(Box::new(term.map_vars(cutoff, on_var))),
+      Term::Inr(term) => Term::Inr(Box::new(term.map_vars(cutoff, on_var))),
+      Term::Case {
+        scrutinee,
+        left_hint,
+        left,
+        right_hint,
+        right,
+      } => Term::Case {
+        scrutinee: Box::new(scrutinee.map_vars(cutoff, on_var)),
+        left_hint: left_hint.clone(),
+        left: Box::new(left.map_vars(cutoff + 1, on_var)),
+        right_hint: right_hint.clone(),
+        right: Box::new(right.map_vars(cutoff + 1, on_var)),
+      },
+      Term::Record(fields) => Term::Record(
+        fields
+          .iter()
+          .map(|(label, term)| (label.clone(), term.map_vars(cutoff, on_var)))
+          .collect(),
+      ),
+      Term::Field(term, label) => {
+        Term::Field(Box::new(term.map_vars(cutoff, on_var)), label.clone())
+      }
+      Term::Tag(label, term) => Term::Tag(label.clone(), Box::new(term.map_vars(cutoff, on_var))),
+      Term::VariantCase {
+        scrutinee,
+        branches,
+      } => Term::VariantCase {
+        scrutinee: Box::new(scrutinee.map_vars(cutoff, on_var)),
+        branches: branches
+          .iter()
+          .map(|branch| Branch {
+            label: branch.label.clone(),
+            hint: branch.hint.clone(),
+            body: branch.body.map_vars(cutoff + 1, on_var),
+          })
+          .collect(),
+      },
+      Term::Seq(first, second) => Term::Seq(
+        Box::new(first.map_vars(cutoff, on_var)),
+        Box::new(second.map_vars(cutoff, on_var)),
+      ),
+      Term::Fix(term) => Term::Fix(Box::new(term.map_vars(cutoff, on_var))),
+    }
+  }
+
+  // ↑d,c(t)
+  pub fn shift(&self, d: isize, cutoff: usize) -> Term {
+    self.map_vars(cutoff, &|c, i| {
+      if i >= c {
+        Term::Var(shift_index(i, d))
+      } else {
+        Term::Var(i)
+      }
+    })
+  }
+
+  // t[s/j]
+  pub fn subst(&self, j: usize, s: &Term) -> Term {
+    self.map_vars(0, &|c, i| {
+      if i == j + c {
+        s.shift(c as isize, 0)
+      } else {
+        Term::Var(i)
+      }
+    })
+  }
+
+  // Given the body t of `λx. t`, computes t[v/x].
+  pub fn instantiate(&self, v: &Term) -> Term {
+    self.subst(0, &v.shift(1, 0)).shift(-1, 0)
+  }
+
+  pub fn is_value(&self) -> bool {
+    match self {
+      Term::Int(_) | Term::Unit | Term::Abs { .. } => true,
+      Term::Pair(first, second) => first.is_value() && second.is_value(),
+      Term::Inl(term) | Term::Inr(term) | Term::Tag(_, term) => term.is_value(),
+      Term::Record(fields) => fields.iter().all(|(_, term)| term.is_value()),
+      _ => false,
+    }
+  }
+
+  // Performs a single call-by-value reduction step, or returns `None` when
+  // the term is a value or stuck.
+  pub fn step(&self) -> Option<Term> {
+    match self {
+      Term::App(f, arg) => {
+        if !f.is_value() {
+          Some(Term::App(Box::new(f.step()?), arg.clone()))
+        } else if !arg.is_value() {
+          Some(Term::App(f.clone(), Box::new(arg.step()?)))
+        } else {
+          match &**f {
+            Term::Abs { body, .. } => Some(body.instantiate(arg)),
+            _ => None,
+          }
+        }
+      }
+      Term::Pair(first, second) => {
+        if !first.is_value() {
+          Some(Term::Pair(Box::new(first.step()?), second.clone()))
+        } else {
+          Some(Term::Pair(first.clone(), Box::new(second.step()?)))
+        }
+      }
+      Term::Proj(term, i) => {
+        if !term.is_value() {
+          Some(Term::Proj(Box::new(term.step()?), *i))
+        } else {
+          match (&**term, i) {
+            (Term::Pair(first, _), 1) => Some(*first.clone()),
+            (Term::Pair(_, second), 2) => Some(*second.clone()),
+            _ => None,
+          }
+        }
+      }
+      Term::Inl(term) => Some(Term::Inl(Box::new(term.step()?))),
+      Term::Inr(term) => Some(Term::Inr(Box::new(term.step()?))),
+      Term::Case {
+        scrutinee,
+        left_hint,
+        left,
+        right_hint,
+        right,
+      } => {
+        if !scrutinee.is_value() {
+          Some(Term::Case {
+            scrutinee: Box::new(scrutinee.step()?),
+            left_hint: left_hint.clone(),
+            left: left.clone(),
+            right_hint: right_hint.clone(),
+            right: right.clone(),
+          })
+        } else {
+          match &**scrutinee {
+            Term::Inl(value) => Some(left.instantiate(value)),
+            Term::Inr(value) => Some(right.instantiate(value)),
+            _ => None,
+          }
+        }
+      }
+      Term::Record(fields) => {
+        let i = fields.iter().position(|(_, term)| !term.is_value())?;
+        let mut fields = fields.clone();
+        fields[i].1 = fields[i].1.step()?;
+        Some(Term::Record(fields))
+      }
+      Term::Field(term, label) => {
+        if !term.is_value() {
+          Some(Term::Field(Box::new(term.step()?), label.clone()))
+        } else {
+          match &**term {
+            Term::Record(fields) => debruijn::field(fields, label).cloned(),
+            _ => None,
+          }
+        }
+      }
+      Term::Tag(label, term) => Some(Term::Tag(label.clone(), Box::new(term.step()?))),
+      Term::VariantCase {
+        scrutinee,
+        branches,
+      } => {
+        if !scrutinee.is_value() {
+          Some(Term::VariantCase {
+            scrutinee: Box::new(scrutinee.step()?),
+            branches: branches.clone(),
+          })
+        } else {
+          match &**scrutinee {
+            Term::Tag(label, value) => branches
+              .iter()
+              .find(|branch| branch.label == *label)
+              .map(|branch| branch.body.instantiate(value)),
+            _ => None,
+          }
+        }
+      }
+      Term::Seq(first, second) => {
+        if !first.is_value() {
+          Some(Term::Seq(Box::new(first.step()?), second.clone()))
+        } else {
+          Some(*second.clone())
+        }
+      }
+      Term::Fix(term) => {
+        if !term.is_value() {
+          Some(Term::Fix(Box::new(term.step()?)))
+        } else {
+          match &**term {
+            Term::Abs { body, .. } => Some(body.instantiate(self)),
+            _ => None,
+          }
+        }
+      }
+      _ => None,
+    }
+  }
+
+  pub fn eval(&self) -> Result<Term, EvalError> {
+    self.eval_with_limit(debruijn::STEP_LIMIT)
+  }
+
+  // Evaluates the term, failing if it takes more than `limit` steps.
+  pub fn eval_with_limit(&self, limit: usize) -> Result<Term, EvalError> {
+    let mut term = self.clone();
+    let mut steps = 0;
+    while let Some(next) = term.step() {
+      if steps == limit {
+        return Err(EvalError::StepLimitExceeded(limit));
+      }
+      term = next;
+      steps += 1;
+    }
+    Ok(term)
+  }
+
+  fn is_atom(&self) -> bool {
+    matches!(
+      self,
+      Term::Int(_)
+        | Term::Unit
+        | Term::Var(_)
+        | Term::Pair(..)
+        | Term::Proj(..)
+        | Term::Record(_)
+        | Term::Field(..)
+        | Term::Tag(..)
+        | Term::VariantCase { .. }
+    )
+  }
+
+  fn fmt_parenthesized(&self, names: &mut Vec<String>, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "(")?;
+    self.fmt_with(names, f)?;
+    write!(f, ")")
+  }
+
+  // Writes `name => body` or similar, where `name` is bound in `body`.
+  fn fmt_binder(
+    hint: &Hint,
+    body: &Term,
+    separator: &str,
+    names: &mut Vec<String>,
+    f: &mut fmt::Formatter<'_>,
+  ) -> fmt::Result {
+    let mut name = hint.0.clone();
+    while names.contains(&name) {
+      name.push('\'');
+    }
+    write!(f, "{}{}", name, separator)?;
+    names.push(name);
+    let result = body.fmt_with(names, f);
+    names.pop();
+    result
+  }
+
+  fn fmt_with(&self, names: &mut Vec<String>, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Term::Int(n) => write!(f, "{}", n),
+      Term::Unit => write!(f, "()"),
+      Term::Var(i) => match names.len().checked_sub(i + 1) {
+        Some(position) => write!(f, "{}", names[position]),
+        None => write!(f, "#{}", i),
+      },
+      Term::App(function, arg) => {
+        if function.is_atom() || matches!(**function, Term::App(..)) {
+          function.fmt_with(names, f)?;
+        } else {
+          function.fmt_parenthesized(names, f)?;
+        }
+        write!(f, " ")?;
+        if arg.is_atom() {
+          arg.fmt_with(names, f)
+        } else {
+          arg.fmt_parenthesized(names, f)
+        }
+      }
+      Term::Abs { hint, body } => {
+        write!(f, "λ")?;
+        Term::fmt_binder(hint, body, ". ", names, f)
+      }
+      Term::Pair(first, second) => {
+        write!(f, "(")?;
+        first.fmt_with(names, f)?;
+        write!(f, ", ")?;
+        second.fmt_with(names, f)?;
+        write!(f, ")")
+      }
+      Term::Proj(term, i) => {
+        if term.is_atom() {
+          term.fmt_with(names, f)?;
+        } else {
+          term.fmt_parenthesized(names, f)?;
+        }
+        write!(f, ".{}", i)
+      }
+      Term::Inl(term) | Term::Inr(term) => {
+        write!(
+          f,
+          "{} ",
+          if let Term::Inl(_) = self {
+            "inl"
+          } else {
+            "inr"
+          }
+        )?;
+        if term.is_atom() {
+          term.fmt_with(names, f)
+        } else {
+          term.fmt_parenthesized(names, f)
+        }
+      }
+      Term::Case {
+        scrutinee,
+        left_hint,
+        left,
+        right_hint,
+        right,
+      } => {
+        write!(f, "case ")?;
+        scrutinee.fmt_with(names, f)?;
+        write!(f, " of inl ")?;
+        Term::fmt_binder(left_hint, left, " => ", names, f)?;
+        write!(f, " | inr ")?;
+        Term::fmt_binder(right_hint, right, " => ", names, f)
+      }
+      Term::Record(fields) => {
+        write!(f, "{{")?;
+        for (i, (label, term)) in fields.iter().enumerate() {
+          if i > 0 {
+            write!(f, ", ")?;
+          }
+          write!(f, "{} = ", label)?;
+          term.fmt_with(names, f)?;
+        }
+        write!(f, "}}")
+      }
+      Term::Field(term, label) => {
+        if term.is_atom() {
+          term.fmt_with(names, f)?;
+        } else {
+          term.fmt_parenthesized(names, f)?;
+        }
+        write!(f, ".{}", label)
+      }
+      Term::Tag(label, term) => {
+        write!(f, "<{} = ", label)?;
+        term.fmt_with(names, f)?;
+        write!(f, ">")
+      }
+      Term::VariantCase {
+        scrutinee,
+        branches,
+      } => {
+        write!(f, "case ")?;
+        scrutinee.fmt_with(names, f)?;
+        write!(f, " of {{ ")?;
+        for (i, branch) in branches.iter().enumerate() {
+          if i > 0 {
+            write!(f, " | ")?;
+          }
+          write!(f, "<{} = ", branch.label)?;
+          Term::fmt_binder(&branch.hint, &branch.body, "> => ", names, f)?;
+        }
+        write!(f, " }}")
+      }
+      Term::Seq(first, second) => {
+        if matches!(
+          **first,
+          Term::Abs { .. } | Term::Case { .. } | Term::Seq(..)
+        ) {
+          first.fmt_parenthesized(names, f)?;
+        } else {
+          first.fmt_with(names, f)?;
+        }
+        write!(f, "; ")?;
+        second.fmt_with(names, f)
+      }
+      Term::Fix(term) => {
+        write!(f, "fix ")?;
+        if term.is_atom() {
+          term.fmt_with(names, f)
+        } else {
+          term.fmt_parenthesized(names, f)
+        }
+      }
+    }
+  }
+}
+
+impl fmt::Display for Term {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    self.fmt_with(&mut Vec::new(), f)
+  }
+}
+
+// Typechecks `term` and returns its erasure.
+pub fn erase_ast(term: &ast::Term) -> Result<Term, TypecheckerError> {
+  Ok(erase(&debruijn::compile(term)?))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::prelude;
+
+  fn typed(input: &str) -> debruijn::Term {
+    debruijn::compile(&crate::parse(input).unwrap()).unwrap()
+  }
+
+  // Evaluating a term and then erasing the result gives the same value as
+  // erasing the term and then evaluating it.
+  fn assert_erasure_agrees(input: &str) {
+    let term = typed(input);
+    assert_eq!(
+      erase(&term.eval().unwrap()),
+      erase(&term).eval().unwrap(),
+      "evaluation of {} and its erasure disagree",
+      input
+    );
+    assert_eq!(
+      crate::vm::run_erased(&erase(&term)).unwrap(),
+      erase(&term).eval().unwrap(),
+      "the bytecode machine and the evaluator disagree on {}",
+      input
+    );
+  }
+
+  #[test]
+  fn erase_drops_types() {
+    assert_eq!(
+      erase_ast(&crate::parse("ΛX: *. λx: X. x").unwrap())
+        .unwrap()
+        .to_string(),
+      "λ_. λx. x"
+    );
+    assert_eq!(
+      erase_ast(&crate::parse("(ΛX: *. λx: X. x) [Int] 1").unwrap())
+        .unwrap()
+        .to_string(),
+      "(λ_. λx. x) () 1"
+    );
+  }
+
+  #[test]
+  fn erase_rejects_ill_typed_terms() {
+    assert_eq!(
+      erase_ast(&crate::parse("1 2").unwrap()),
+      Err(TypecheckerError::UnexpectedTerm {
+        expected: String::from("abstraction"),
+        got: Box::new(ast::Term::Int(1)),
+      })
+    );
+  }
+
+  #[test]
+  fn identity() {
+    assert_erasure_agrees("(ΛX: *. λx: X. x) [Int] 1");
+    assert_erasure_agrees("(ΛX: *. λx: X. x) 1");
+    assert_erasure_agrees("(ΛX: *. λx: X. x) (ΛX: *. λx: X. x) 1");
+    assert_erasure_agrees("(ΛX: *. λx: X. x) [Int -> Int] (λx: Int. x)");
+  }
+
+  #[test]
+  fn higher_rank_arguments() {
+    assert_erasure_agrees("(λf: ∀X: *. X -> X. f [Int] 3) (ΛY: *. λy: Y. y)");
+    assert_erasure_agrees(
+      "(λf: ∀X: *. X -> X. (λg: Int -> Int. g 1) (f [Int -> Int] (f [Int]))) (ΛY: *. λy. y)",
+    );
+  }
+
+  #[test]
+  fn church_booleans() {
+    let tru = "(ΛX: *. λt: X. λf: X. t)";
+    let fls = "(ΛX: *. λt: X. λf: X. f)";
+    let not = "(λb: ∀X: *. X -> X -> X. ΛX: *. λt: X. λf: X. b [X] f t)";
+    assert_erasure_agrees(&format!("{} [Int] 1 2", tru));
+    assert_erasure_agrees(&format!("{} [Int] 1 2", fls));
+    assert_erasure_agrees(&format!("{} {} [Int] 1 2", not, tru));
+    assert_erasure_agrees(&format!("{} ({} {}) [Int] 1 2", not, not, fls));
+  }
+
+  #[test]
+  fn church_numerals() {
+    let nat = "∀X: *. (X -> X) -> X -> X";
+    let two = "(ΛX: *. λs: X -> X. λz: X. s (s z))";
+    let succ = format!("(λn: {}. ΛX: *. λs: X -> X. λz: X. s (n [X] s z))", nat);
+    let plus = format!(
+      "(λm: {}. λn: {}. ΛX: *. λs: X -> X. λz: X. m [X] s (n [X] s z))",
+      nat, nat
+    );
+    // Instantiates the numeral so that the whole program has type Int.
+    let apply = "[Int -> Int] (λf: Int -> Int. λx: Int. f x) (λx: Int. x) 7";
+    assert_erasure_agrees(&format!("{} {}", two, apply));
+    assert_erasure_agrees(&format!("{} {} {}", succ, two, apply));
+    assert_erasure_agrees(&format!("{} {} ({} {}) {}", plus, two, succ, two, apply));
+  }
+
+  #[test]
+  fn church_pairs() {
+    let pair = "(ΛA: *. ΛB: *. λa: A. λb: B. ΛR: *. λk: A -> B -> R. k a b)";
+    let fst = "(ΛA: *. ΛB: *. λp: ∀R: *. (A -> B -> R) -> R. p [A] (λa: A. λb: B. a))";
+    let snd = "(ΛA: *. ΛB: *. λp: ∀R: *. (A -> B -> R) -> R. p [B] (λa: A. λb: B. b))";
+    assert_erasure_agrees(&format!("{} [Int] [Int] ({} [Int] [Int] 1 2)", fst, pair));
+    assert_erasure_agrees(&format!("{} [Int] [Int] ({} [Int] [Int] 1 2)", snd, pair));
+  }
+
+  #[test]
+  fn existential_packages() {
+    let counter = "∃C: *. ∀R: *. (C -> (C -> Int) -> R) -> R";
+    let package = format!(
+      "pack [Int, pair [Int] [Int -> Int] 5 (λx: Int. x)] as {}",
+      counter
+    );
+    assert_erasure_agrees(&package);
+    assert_erasure_agrees(&format!(
+      "unpack {} as [C, c] in snd [C] [C -> Int] c (fst [C] [C -> Int] c)",
+      package
+    ));
+  }
+
+  #[test]
+  fn products_and_sums() {
+    assert_erasure_agrees("(1, (λx: Int. x) 2).2");
+    assert_erasure_agrees("(λp: Int × (Int -> Int). p.2 p.1) (4, λx. x)");
+    let either = "λs: Int + Int × Int. case s of inl x => x | inr p => p.2";
+    assert_erasure_agrees(&format!("({}) (inl 1 as Int + Int × Int)", either));
+    assert_erasure_agrees(&format!("({}) (inr (1, 2) as Int + Int × Int)", either));
+    assert_erasure_agrees("(ΛX: *. λx: X. inr (x, x) as Int + X × X) [Int] 3");
+  }
+
+  #[test]
+  fn records_and_variants() {
+    assert_erasure_agrees("{x = (λx: Int. x) 1, y = 2}.x");
+    assert_erasure_agrees("(λp: {y: Int, x: Int}. p.y) {x = 1, y = 2}");
+    let option = "<some: Int, none: Unit>";
+    let get = format!(
+      "λo: {}. case o of {{ <none = u> => 0 | <some = n> => n }}",
+      option
+    );
+    assert_erasure_agrees(&format!("({}) (<some = 3> as {})", get, option));
+    assert_erasure_agrees(&format!("({}) (<none = ()> as {})", get, option));
+  }
+
+  #[test]
+  fn sequencing() {
+    assert_erasure_agrees("(); 1");
+    assert_erasure_agrees("(λu: Unit. u; u) ()");
+    assert_erasure_agrees("(λf: Int -> Unit. f 1; f 2; 3) (λx. ())");
+  }
+
+  #[test]
+  fn recursion() {
+    // Doubles a Church numeral. The branches are delayed behind a Unit
+    // argument so that only the one taken unfolds the recursion again.
+    let double = format!(
+      "let rec double: {nat} -> {nat} = λn. isZero n [Unit -> {nat}] (λu. zero) (λu. succ (succ (double (pred n)))) () in double",
+      nat = format!("({})", prelude::NAT)
+    );
+    assert_erasure_agrees(&format!("({}) (succ (succ zero))", double));
+  }
+
+  #[test]
+  fn recursive_types() {
+    let list = "μL. <nil: Unit, cons: Int × L>";
+    let unfolded = format!("<nil: Unit, cons: Int × ({})>", list);
+    let nil = format!("fold [{}] (<nil = ()> as {})", list, unfolded);
+    let cons = |head: &str, tail: &str| {
+      format!(
+        "fold [{}] (<cons = ({}, {})> as {})",
+        list, head, tail, unfolded
+      )
+    };
+    let last = format!(
+      "let rec last: ({l}) -> Int = λxs. case unfold [{l}] xs of {{ <nil = u> => 0 | <cons = c> => case unfold [{l}] c.2 of {{ <nil = u> => c.1 | <cons = d> => last c.2 }} }} in last",
+      l = list
+    );
+    let xs = cons("1", &cons("2", &cons("3", &nil)));
+    assert_erasure_agrees(&format!("({}) ({})", last, xs));
+  }
+
+  #[test]
+  fn let_bindings() {
+    assert_erasure_agrees("let id = ΛX: *. λx: X. x in id [Int] (id 1)");
+    assert_erasure_agrees("let f = λx: Int. (x, x) in let x = 2 in f x");
+    assert_erasure_agrees("let x = (1, ()) in x.1");
+  }
+
+  #[test]
+  fn type_aliases() {
+    assert_erasure_agrees("let P = Int × Int in let swap = λp: P. (p.2, p.1) in swap (1, 2)");
+  }
+
+  #[test]
+  fn type_declarations() {
+    let nat = format!("type Nat = {};", prelude::NAT);
+    assert_erasure_agrees(&format!(
+      "{} (λn: Nat. n [Int] (λx: Int. x) 0) (ΛY: *. λf: Y -> Y. λx: Y. f x)",
+      nat
+    ));
+  }
+
+  #[test]
+  fn divergence_is_cut_off() {
+    assert_eq!(
+      erase(&typed("fix (λx: Int. x)")).eval_with_limit(100),
+      Err(EvalError::StepLimitExceeded(100))
+    );
+  }
+
+  #[test]
+  fn values_under_type_abstractions() {
+    assert_erasure_agrees("ΛX: *. λx: X. x");
+    assert_erasure_agrees("(λx: Int. ΛX: *. λy: X. x) 1");
+    // A type abstraction delays its body, and so does its erasure.
+    assert_erasure_agrees("ΛX: *. (λx: Int. x) 1");
+    assert_erasure_agrees("ΛX: *. fix (λx: Int. x)");
+    assert_erasure_agrees("(ΛX: *. (λx: Int. x) 1) [Unit]");
+    assert_erasure_agrees("λy: Int. ΛX: *. (λx: Int. x) y");
+  }
+}
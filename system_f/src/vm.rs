@@ -0,0 +1,499 @@
+use crate::ast;
+use crate::bytecode::{self, Instruction, Program, Slot};
+use crate::typechecker::TypecheckerError;
+use crate::untyped::{self, Term};
+use thiserror::Error;
+
+// A stack machine for the bytecode of `bytecode.rs`.
+//
+// Each call pushes a frame that remembers where to return to, and the
+// locals of all the frames are kept on one stack of their own. Objects live
+// on a heap with room for a fixed number of them. When it is full, the
+// machine collects the objects that are not reachable from its stacks, and
+// fails if that does not free any room.
+
+pub const INSTRUCTION_LIMIT: usize = 100_000_000;
+pub const FRAME_LIMIT: usize = 100_000;
+pub const HEAP_SIZE: usize = 1 << 20;
+
+#[derive(Debug, PartialEq, Error)]
+pub enum VmError {
+  #[error(transparent)]
+  Typechecker(#[from] TypecheckerError),
+  #[error("the program did not finish within {0} instructions")]
+  InstructionLimitExceeded(usize),
+  #[error("the program nested more than {0} calls")]
+  FrameLimitExceeded(usize),
+  #[error("the heap is full with {0} live objects")]
+  HeapExhausted(usize),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Value {
+  Int(i32),
+  Unit,
+  Ref(usize),
+}
+
+#[derive(Debug)]
+enum Object {
+  Closure(u32, Vec<Value>),
+  // The closure that `fix` was applied to.
+  Rec(Value),
+  Pair(Value, Value),
+  Inl(Value),
+  Inr(Value),
+  Record(u32, Vec<Value>),
+  Tag(u32, Value),
+}
+
+impl Object {
+  fn children(&self) -> Vec<Value> {
+    match self {
+      Object::Closure(_, values) | Object::Record(_, values) => values.clone(),
+      Object::Rec(value) | Object::Inl(value) | Object::Inr(value) | Object::Tag(_, value) => {
+        vec![*value]
+      }
+      Object::Pair(first, second) => vec![*first, *second],
+    }
+  }
+}
+
+// Where a call returns to: the function, `None` for the main program, the
+// address after the call, the closure, and where the locals of the caller
+// begin.
+#[derive(Debug)]
+struct Frame {
+  function: Option<u32>,
+  pc: usize,
+  closure: Value,
+  locals: usize,
+}
+
+pub struct Vm<'p> {
+  program: &'p Program,
+  heap: Vec<Option<Object>>,
+  free: Vec<usize>,
+  heap_size: usize,
+  stack: Vec<Value>,
+  locals: Vec<Value>,
+  frames: Vec<Frame>,
+  frame: Frame,
+}
+
+impl<'p> Vm<'p> {
+  pub fn new(program: &'p Program, heap_size: usize) -> Self {
+    Vm {
+      program,
+      heap: Vec::new(),
+      free: Vec::new(),
+      heap_size,
+      stack: Vec::new(),
+      locals: Vec::new(),
+      frames: Vec::new(),
+      frame: Frame {
+        function: None,
+        pc: 0,
+        closure: Value::Unit,
+        locals: 0,
+      },
+    }
+  }
+
+  // Runs the program, failing if it takes more than `limit` instructions,
+  // and reads back its result.
+  pub fn run(&mut self, limit: usize) -> Result<Term, VmError> {
+    for _ in 0..limit {
+      let code = match self.frame.function {
+        Some(function) => &self.program.functions[function as usize].code,
+        None => &self.program.main,
+      };
+      let instruction = code[self.frame.pc];
+      self.frame.pc += 1;
+      if let Some(result) = self.execute(instruction)? {
+        return Ok(self.read_back(result));
+      }
+    }
+    Err(VmError::InstructionLimitExceeded(limit))
+  }
+
+  // Executes an instruction, returning the result of the program once the
+  // main program returns.
+  fn execute(&mut self, instruction: Instruction) -> Result<Option<Value>, VmError> {
+    match instruction {
+      Instruction::Int(n) => self.stack.push(Value::Int(n)),
+      Instruction::Unit => self.stack.push(Value::Unit),
+      Instruction::Local(i) => self.load(Slot::Local(i))?,
+      Instruction::Captured(i) => self.load(Slot::Captured(i))?,
+      Instruction::Closure(function) => {
+        self.reserve()?;
+        let captured = self.program.functions[function as usize]
+          .captures
+          .iter()
+          .map(|slot| self.slot(*slot))
+          .collect();
+        let closure = self.alloc(Object::Closure(function, captured));
+        self.stack.push(closure);
+      }
+      Instruction::Apply => {
+        let arg = self.pop();
+        let closure = self.pop();
+        self.call(closure, arg)?;
+      }
+      Instruction::Return => {
+        let result = *self.stack.last().unwrap();
+        match self.frames.pop() {
+          Some(frame) => {
+            self.locals.truncate(self.frame.locals);
+            self.frame = frame;
+          }
+          None => return Ok(Some(result)),
+        }
+      }
+      Instruction::Fix => {
+        self.reserve()?;
+        let closure = self.pop();
+        let rec = self.alloc(Object::Rec(closure));
+        self.call(closure, rec)?;
+      }
+      Instruction::Pair => {
+        self.reserve()?;
+        let second = self.pop();
+        let first = self.pop();
+        let pair = self.alloc(Object::Pair(first, second));
+        self.stack.push(pair);
+      }
+      Instruction::Proj(i) => {
+        let pair = self.pop();
+        let value = match (self.object(pair), i) {
+          (Object::Pair(first, _), 1) => *first,
+          (Object::Pair(_, second), 2) => *second,
+          (object, _) => stuck(object),
+        };
+        self.stack.push(value);
+      }
+      Instruction::Inl | Instruction::Inr => {
+        self.reserve()?;
+        let value = self.pop();
+        let object = if instruction == Instruction::Inl {
+          Object::Inl(value)
+        } else {
+          Object::Inr(value)
+        };
+        let sum = self.alloc(object);
+        self.stack.push(sum);
+      }
+      Instruction::Case(right) => {
+        let sum = self.pop();
+        match self.object(sum) {
+          Object::Inl(value) => self.stack.push(*value),
+          Object::Inr(value) => {
+            self.stack.push(*value);
+            self.frame.pc = right as usize;
+          }
+          object => stuck(object),
+        }
+      }
+      Instruction::Record(shape) => {
+        self.reserve()?;
+        let n = self.program.shapes[shape as usize].len();
+        let fields = self.stack.split_off(self.stack.len() - n);
+        let record = self.alloc(Object::Record(shape, fields));
+        self.stack.push(record);
+      }
+      Instruction::Field(label) => {
+        let record = self.pop();
+        let value = match self.object(record) {
+          Object::Record(shape, fields) => {
+            let shape = &self.program.shapes[*shape as usize];
+            match shape.iter().position(|other| *other == label) {
+              Some(i) => fields[i],
+              None => stuck(self.object(record)),
+            }
+          }
+          object => stuck(object),
+        };
+        self.stack.push(value);
+      }
+      Instruction::Tag(label) => {
+        self.reserve()?;
+        let value = self.pop();
+        let variant = self.alloc(Object::Tag(label, value));
+        self.stack.push(variant);
+      }
+      Instruction::Switch(table) => {
+        let variant = self.pop();
+        match self.object(variant) {
+          Object::Tag(label, value) => {
+            let table = &self.program.tables[table as usize];
+            match table.iter().find(|(other, _)| other == label) {
+              Some((_, address)) => {
+                self.stack.push(*value);
+                self.frame.pc = *address as usize;
+              }
+              None => stuck(self.object(variant)),
+            }
+          }
+          object => stuck(object),
+        }
+      }
+      Instruction::Bind => {
+        let value = self.pop();
+        self.locals.push(value);
+      }
+      Instruction::Unbind(n) => {
+        self.locals.truncate(self.locals.len() - n as usize);
+      }
+      Instruction::Pop => {
+        self.pop();
+      }
+      Instruction::Jump(address) => self.frame.pc = address as usize,
+    }
+    Ok(None)
+  }
+
+  fn pop(&mut self) -> Value {
+    self.stack.pop().expect("the stack is empty")
+  }
+
+  fn slot(&self, slot: Slot) -> Value {
+    match slot {
+      Slot::Local(i) => self.locals[self.frame.locals + i as usize],
+      Slot::Captured(i) => match self.object(self.frame.closure) {
+        Object::Closure(_, captured) => captured[i as usize],
+        object => stuck(object),
+      },
+    }
+  }
+
+  // Pushes the value of a variable. A recursive reference unfolds to a call
+  // of its closure instead, as `fix (λx. t)` does when it is substituted.
+  fn load(&mut self, slot: Slot) -> Result<(), VmError> {
+    let value = self.slot(slot);
+    if let Value::Ref(r) = value {
+      if let Some(Object::Rec(closure)) = &self.heap[r] {
+        return self.call(*closure, value);
+      }
+    }
+    self.stack.push(value);
+    Ok(())
+  }
+
+  fn call(&mut self, closure: Value, arg: Value) -> Result<(), VmError> {
+    let function = match self.object(closure) {
+      Object::Closure(function, _) => *function,
+      object => stuck(object),
+    };
+    if self.frames.len() == FRAME_LIMIT {
+      return Err(VmError::FrameLimitExceeded(FRAME_LIMIT));
+    }
+    let caller = std::mem::replace(
+      &mut self.frame,
+      Frame {
+        function: Some(function),
+        pc: 0,
+        closure,
+        locals: self.locals.len(),
+      },
+    );
+    self.frames.push(caller);
+    self.locals.push(arg);
+    Ok(())
+  }
+
+  fn object(&self, value: Value) -> &Object {
+    match value {
+      Value::Ref(r) => self.heap[r].as_ref().expect("a live object"),
+      _ => panic!("the bytecode machine is stuck on {:?}", value),
+    }
+  }
+
+  // Makes sure that the next allocation has room, collecting garbage if the
+  // heap is full. The operands of the allocation are still on the stack.
+  fn reserve(&mut self) -> Result<(), VmError> {
+    if self.free.is_empty() && self.heap.len() == self.heap_size {
+      self.collect();
+      if self.free.is_empty() {
+        return Err(VmError::HeapExhausted(self.heap_size));
+      }
+    }
+    Ok(())
+  }
+
+  fn alloc(&mut self, object: Object) -> Value {
+    match self.free.pop() {
+      Some(r) => {
+        self.heap[r] = Some(object);
+        Value::Ref(r)
+      }
+      None => {
+        self.heap.push(Some(object));
+        Value::Ref(self.heap.len() - 1)
+      }
+    }
+  }
+
+  // Marks the objects reachable from the stacks and frees the others.
+  fn collect(&mut self) {
+    let mut marked = vec![false; self.heap.len()];
+    let mut pending: Vec<Value> = self
+      .stack
+      .iter()
+      .chain(&self.locals)
+      .copied()
+      .chain(self.frames.iter().map(|frame| frame.closure))
+      .chain(std::iter::once(self.frame.closure))
+      .collect();
+    while let Some(value) = pending.pop() {
+      if let Value::Ref(r) = value {
+        if !marked[r] {
+          marked[r] = true;
+          pending.extend(self.object(value).children());
+        }
+      }
+    }
+    for (r, marked) in marked.into_iter().enumerate() {
+      if !marked && self.heap[r].is_some() {
+        self.heap[r] = None;
+        self.free.push(r);
+      }
+    }
+  }
+
+  // The erased term the value stands for.
+  fn read_back(&self, value: Value) -> Term {
+    let object = match value {
+      Value::Int(n) => return Term::Int(n),
+      Value::Unit => return Term::Unit,
+      Value::Ref(_) => self.object(value),
+    };
+    match object {
+      Object::Closure(function, captured) => {
+        let function = &self.program.functions[*function as usize];
+        let captured: Vec<_> = captured
+          .iter()
+          .map(|value| self.read_back(*value))
+          .collect();
+        let body = function.body.map_vars(1, &|c, i| match i
+          .checked_sub(c)
+          .and_then(|j| function.free.iter().position(|free| *free == j))
+        {
+          Some(slot) => captured[slot].clone(),
+          None => Term::Var(i),
+        });
+        Term::Abs {
+          hint: function.hint.clone(),
+          body: Box::new(body),
+        }
+      }
+      Object::Rec(closure) => Term::Fix(Box::new(self.read_back(*closure))),
+      Object::Pair(first, second) => Term::Pair(
+        Box::new(self.read_back(*first)),
+        Box::new(self.read_back(*second)),
+      ),
+      Object::Inl(value) => Term::Inl(Box::new(self.read_back(*value))),
+      Object::Inr(value) => Term::Inr(Box::new(self.read_back(*value))),
+      Object::Record(shape, fields) => Term::Record(
+        self.program.shapes[*shape as usize]
+          .iter()
+          .zip(fields)
+          .map(|(label, value)| {
+            (
+              self.program.labels[*label as usize].clone(),
+              self.read_back(*value),
+            )
+          })
+          .collect(),
+      ),
+      Object::Tag(label, value) => Term::Tag(
+        self.program.labels[*label as usize].clone(),
+        Box::new(self.read_back(*value)),
+      ),
+    }
+  }
+}
+
+// Only an ill-typed program gets stuck.
+fn stuck(object: &Object) -> ! {
+  panic!("the bytecode machine is stuck on {:?}", object)
+}
+
+// Runs the erased term on the bytecode machine.
+pub fn run_erased(term: &Term) -> Result<Term, VmError> {
+  Vm::new(&bytecode::compile(term), HEAP_SIZE).run(INSTRUCTION_LIMIT)
+}
+
+// Typechecks the term, erases its types and runs it on the bytecode machine.
+pub fn run(term: &ast::Term) -> Result<Term, VmError> {
+  run_erased(&untyped::erase_ast(term)?)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::parse;
+
+  fn compile(input: &str) -> Program {
+    bytecode::compile(&untyped::erase_ast(&parse(input).unwrap()).unwrap())
+  }
+
+  #[test]
+  fn runs_programs() {
+    assert_eq!(
+      run(&parse("(λx: Int. λy: Int. x) 1").unwrap()).map(|term| term.to_string()),
+      Ok(String::from("λy. 1"))
+    );
+    assert_eq!(
+      run(&parse("let rec f: Int -> Int = λn: Int. n in f").unwrap()).map(|term| term.to_string()),
+      Ok(String::from("λn. n"))
+    );
+    assert_eq!(
+      run(&parse("let rec f: Int -> Int = λn: Int. f n in λx: Int. f x").unwrap())
+        .map(|term| term.to_string()),
+      Ok(String::from("λx. (λn. (fix (λf. λn'. f n')) n) x"))
+    );
+  }
+
+  #[test]
+  fn disassembles_programs() {
+    let program = compile("λx: Int. (λy: Int. x) x");
+    assert_eq!(
+      program.to_string(),
+      "main:\n     0  closure 1\n     1  return\n\
+       fn 0 y [local 0]:\n     0  captured 0\n     1  return\n\
+       fn 1 x:\n     0  closure 0\n     1  local 0\n     2  apply\n     3  return\n"
+    );
+    let program = compile("case <b = 1> as <a: Unit, b: Int> of { <a = u> => 0 | <b = n> => n }");
+    assert_eq!(
+      program.to_string(),
+      "main:\n     0  int 1\n     1  tag b\n     2  switch {a => 3, b => 7}\n     3  bind\n     4  int 0\n     5  unbind 1\n     6  jump 11\n     7  bind\n     8  local 0\n     9  unbind 1\n    10  jump 11\n    11  return\n"
+    );
+  }
+
+  #[test]
+  fn limits_resources() {
+    let program = compile("let rec f: Int -> Int = λn: Int. f n in f 3");
+    assert_eq!(
+      Vm::new(&program, HEAP_SIZE).run(1000),
+      Err(VmError::InstructionLimitExceeded(1000))
+    );
+    let program = compile("let rec f: Int -> Int = λn: Int. (λm: Int. m) (f n) in f 3");
+    assert_eq!(
+      Vm::new(&program, HEAP_SIZE).run(INSTRUCTION_LIMIT),
+      Err(VmError::FrameLimitExceeded(FRAME_LIMIT))
+    );
+    // Each call allocates a pair that is garbage once it returns.
+    let program = compile(
+      "let rec count: Int -> Int = λn: Int. (λp: Int × Int. p.1) (n, n) in count (count (count 0))",
+    );
+    assert_eq!(
+      Vm::new(&program, 4).run(INSTRUCTION_LIMIT),
+      Ok(Term::Int(0))
+    );
+    let program = compile("((1, 2), (3, 4))");
+    assert_eq!(
+      Vm::new(&program, 2).run(INSTRUCTION_LIMIT),
+      Err(VmError::HeapExhausted(2))
+    );
+  }
+}
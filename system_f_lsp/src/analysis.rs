@@ -0,0 +1,140 @@
+use lambda_core::{Diagnostic, Span};
+use std::mem::discriminant;
+use system_f::ast::{Term, Type};
+use system_f::derivation::{self, Derivation};
+use system_f::spans::{binders, subterms, SpanTree};
+use system_f::{debruijn, TypecheckerError};
+
+// The language features of the server, on the text of a document and byte
+// offsets into it.
+
+// The problems in a document: its parse error, or else its first type error.
+pub fn diagnostics(text: &str) -> Vec<Diagnostic> {
+  let (term, tree) = match system_f::parse_with_spans(text) {
+    Ok(parsed) => parsed,
+    Err(err) => return vec![Diagnostic::from_parse_error(&err)],
+  };
+  match debruijn::compile(&term) {
+    Ok(_) => Vec::new(),
+    Err(err) => vec![Diagnostic {
+      message: err.to_string(),
+      span: Some(locate(&err, &term, &tree).unwrap_or(tree.span)),
+    }],
+  }
+}
+
+// Where in the source the error was found, when the term it reports can be
+// found among the subterms of the document.
+fn locate(err: &TypecheckerError, term: &Term, tree: &SpanTree) -> Option<Span> {
+  match err {
+    TypecheckerError::UndefinedVariable(x) => find_free(x, term, tree, &mut Vec::new()),
+    TypecheckerError::TypeMismatch { term: culprit, .. }
+    | TypecheckerError::UnexpectedTerm { got: culprit, .. }
+    | TypecheckerError::MissingAnnotation(culprit)
+    | TypecheckerError::AmbiguousTypeArgument { term: culprit, .. } => find(culprit, term, tree),
+    _ => None,
+  }
+}
+
+fn find(culprit: &Term, term: &Term, tree: &SpanTree) -> Option<Span> {
+  if term == culprit {
+    return Some(tree.span);
+  }
+  subterms(term)
+    .into_iter()
+    .zip(&tree.children)
+    .find_map(|(subterm, child)| find(culprit, subterm, child))
+}
+
+// The first occurrence of `x` that is not bound by an enclosing binder.
+fn find_free<'a>(
+  x: &str,
+  term: &'a Term,
+  tree: &SpanTree,
+  bound: &mut Vec<&'a String>,
+) -> Option<Span> {
+  if let Term::Var(y) = term {
+    if y == x && !bound.contains(&y) {
+      return Some(tree.span);
+    }
+  }
+  for (i, (subterm, child)) in subterms(term).into_iter().zip(&tree.children).enumerate() {
+    let names = binders(term, i);
+    let depth = bound.len();
+    bound.extend(names.into_iter().map(|(name, _)| name));
+    let span = find_free(x, subterm, child, bound);
+    bound.truncate(depth);
+    if span.is_some() {
+      return span;
+    }
+  }
+  None
+}
+
+// The type of the innermost subterm at `offset`, together with its span.
+//
+// The type is read off the typing derivation of the document, walking down
+// the derivation of the elaborated term alongside the source.
+pub fn hover(text: &str, offset: usize) -> Option<(Span, Type)> {
+  let (term, tree) = system_f::parse_with_spans(text).ok()?;
+  let derivation = derivation::derive(&term).ok()?;
+  let (mut source, mut target, mut node) = (&term, &derivation, &tree);
+  for i in tree.path_to(offset) {
+    let (aligned_source, aligned_target) = align(source, target)?;
+    source = subterms(aligned_source)[i];
+    target = &aligned_target.premises[i];
+    node = &node.children[i];
+  }
+  match source {
+    Term::Ann(_, typ) => Some((node.span, typ.clone())),
+    _ => Some((node.span, target.typ.clone())),
+  }
+}
+
+// Steps over the nodes that elaboration removes from the source or adds to
+// it, until both are the same kind of node with the same subterms.
+fn align<'a>(
+  mut source: &'a Term,
+  mut target: &'a Derivation,
+) -> Option<(&'a Term, &'a Derivation)> {
+  loop {
+    if discriminant(source) == discriminant(&target.term) {
+      return Some((source, target));
+    }
+    match (source, &target.term) {
+      (Term::Ann(term, _), _) | (Term::TypeLet { body: term, .. }, _) => source = term,
+      (_, Term::UniversalApp(..)) => target = &target.premises[0],
+      _ => return None,
+    }
+  }
+}
+
+// The span of the name that binds the variable at `offset`.
+pub fn definition(text: &str, offset: usize) -> Option<Span> {
+  let (term, tree) = system_f::parse_with_spans(text).ok()?;
+  let mut scope = Vec::new();
+  let (mut term, mut node) = (&term, &tree);
+  for i in tree.path_to(offset) {
+    scope.extend(
+      binders(term, i)
+        .into_iter()
+        .map(|(name, k)| (name, node.names[k])),
+    );
+    term = subterms(term)[i];
+    node = &node.children[i];
+  }
+  match term {
+    Term::Var(x) => scope
+      .iter()
+      .rev()
+      .find(|(name, _)| *name == x)
+      .map(|(_, span)| *span),
+    _ => None,
+  }
+}
+
+// The document printed in canonical form.
+pub fn format(text: &str) -> Option<String> {
+  let term = system_f::parse(text).ok()?;
+  Some(format!("{}\n", term))
+}
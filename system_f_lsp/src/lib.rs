@@ -0,0 +1,8 @@
+// A Language Server Protocol server for System F source files, speaking
+// JSON-RPC over stdio.
+
+pub mod analysis;
+pub mod rpc;
+pub mod server;
+
+pub use server::run;
@@ -0,0 +1,53 @@
+use serde_json::Value;
+use std::io::{self, BufRead, Write};
+
+// JSON-RPC messages as the Language Server Protocol frames them: a
+// `Content-Length` header, a blank line, then that many bytes of JSON.
+
+// The largest body a frame may declare. Larger frames are rejected without
+// reading the body, rather than allocating whatever the client asks for.
+pub const MAX_CONTENT_LENGTH: usize = 64 * 1024 * 1024;
+
+// Reads the next message, or returns `None` at the end of the input.
+//
+// A frame that is not a message fails with `InvalidData` after it has been
+// consumed, so reading can go on with the frame after it. A frame longer
+// than `MAX_CONTENT_LENGTH` only has its headers consumed.
+pub fn read_message(input: &mut impl BufRead) -> io::Result<Option<Value>> {
+  let mut length = None;
+  loop {
+    let mut line = String::new();
+    if input.read_line(&mut line)? == 0 {
+      return Ok(None);
+    }
+    let line = line.trim_end();
+    if line.is_empty() {
+      break;
+    }
+    if let Some(value) = line.strip_prefix("Content-Length:") {
+      length = value.trim().parse::<usize>().ok();
+    }
+  }
+  let length = length.ok_or_else(|| invalid_data("missing Content-Length header"))?;
+  if length > MAX_CONTENT_LENGTH {
+    return Err(invalid_data(&format!(
+      "Content-Length {} is larger than {} bytes",
+      length, MAX_CONTENT_LENGTH
+    )));
+  }
+  let mut body = vec![0; length];
+  input.read_exact(&mut body)?;
+  serde_json::from_slice(&body)
+    .map(Some)
+    .map_err(|err| invalid_data(&err.to_string()))
+}
+
+pub fn write_message(output: &mut impl Write, message: &Value) -> io::Result<()> {
+  let body = message.to_string();
+  write!(output, "Content-Length: {}\r\n\r\n{}", body.len(), body)?;
+  output.flush()
+}
+
+fn invalid_data(message: &str) -> io::Error {
+  io::Error::new(io::ErrorKind::InvalidData, message.to_string())
+}
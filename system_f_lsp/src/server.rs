@@ -0,0 +1,203 @@
+use crate::analysis;
+use crate::rpc::{read_message, write_message};
+use lambda_core::{Diagnostic, Span};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::io::{self, BufRead, Write};
+
+// JSON-RPC error code for frames whose body is not a JSON-RPC message.
+const PARSE_ERROR: i64 = -32700;
+// JSON-RPC error code for requests the server does not implement.
+const METHOD_NOT_FOUND: i64 = -32601;
+
+// The open documents, by URI. Documents are synchronized in full on every
+// change.
+#[derive(Debug, Default)]
+pub struct Server {
+  documents: HashMap<String, String>,
+}
+
+// Serves requests read from `input` until the client sends `exit` or closes
+// the input. Malformed frames are answered with a parse error and skipped.
+pub fn run(mut input: impl BufRead, mut output: impl Write) -> io::Result<()> {
+  let mut server = Server::default();
+  loop {
+    let message = match read_message(&mut input) {
+      Ok(Some(message)) => message,
+      Ok(None) => break,
+      Err(err) if err.kind() == io::ErrorKind::InvalidData => {
+        let reply = json!({
+          "jsonrpc": "2.0",
+          "id": Value::Null,
+          "error": { "code": PARSE_ERROR, "message": err.to_string() },
+        });
+        write_message(&mut output, &reply)?;
+        continue;
+      }
+      Err(err) => return Err(err),
+    };
+    if message["method"] == "exit" {
+      break;
+    }
+    for reply in server.handle(&message) {
+      write_message(&mut output, &reply)?;
+    }
+  }
+  Ok(())
+}
+
+impl Server {
+  // Handles a request or notification, returning the messages to send back.
+  pub fn handle(&mut self, message: &Value) -> Vec<Value> {
+    let params = &message["params"];
+    let uri = params["textDocument"]["uri"].as_str().unwrap_or_default();
+    match message["method"].as_str().unwrap_or_default() {
+      "initialize" => vec![response(
+        message,
+        json!({
+          "capabilities": {
+            "textDocumentSync": { "openClose": true, "change": 1, "save": true },
+            "hoverProvider": true,
+            "definitionProvider": true,
+            "documentFormattingProvider": true,
+          },
+          "serverInfo": { "name": "system_f_lsp" },
+        }),
+      )],
+      "shutdown" => vec![response(message, Value::Null)],
+      "textDocument/didOpen" => {
+        let text = params["textDocument"]["text"].as_str().unwrap_or_default();
+        self.documents.insert(String::from(uri), String::from(text));
+        vec![self.publish_diagnostics(uri)]
+      }
+      "textDocument/didChange" => {
+        if let Some(change) = params["contentChanges"].as_array().and_then(|c| c.last()) {
+          let text = change["text"].as_str().unwrap_or_default();
+          self.documents.insert(String::from(uri), String::from(text));
+        }
+        Vec::new()
+      }
+      "textDocument/didSave" => {
+        if let Some(text) = params["text"].as_str() {
+          self.documents.insert(String::from(uri), String::from(text));
+        }
+        vec![self.publish_diagnostics(uri)]
+      }
+      "textDocument/didClose" => {
+        self.documents.remove(uri);
+        vec![notification(
+          "textDocument/publishDiagnostics",
+          json!({ "uri": uri, "diagnostics": [] }),
+        )]
+      }
+      "textDocument/hover" => {
+        let result = self
+          .at_position(params, analysis::hover)
+          .map(|(text, (span, typ))| {
+            json!({
+              "contents": { "kind": "plaintext", "value": typ.to_string() },
+              "range": range(text, span),
+            })
+          });
+        vec![response(message, result.unwrap_or(Value::Null))]
+      }
+      "textDocument/definition" => {
+        let result = self
+          .at_position(params, analysis::definition)
+          .map(|(text, span)| json!({ "uri": uri, "range": range(text, span) }));
+        vec![response(message, result.unwrap_or(Value::Null))]
+      }
+      "textDocument/formatting" => {
+        let result = self.documents.get(uri).and_then(|text| {
+          let formatted = analysis::format(text)?;
+          let whole = Span::new(0, text.len());
+          Some(json!([{ "range": range(text, whole), "newText": formatted }]))
+        });
+        vec![response(message, result.unwrap_or(Value::Null))]
+      }
+      // Other notifications, such as `initialized`, need no reply.
+      _ if message.get("id").is_none() => Vec::new(),
+      method => vec![json!({
+        "jsonrpc": "2.0",
+        "id": message["id"],
+        "error": {
+          "code": METHOD_NOT_FOUND,
+          "message": format!("method {} is not supported", method),
+        },
+      })],
+    }
+  }
+
+  fn publish_diagnostics(&self, uri: &str) -> Value {
+    let text = self.documents.get(uri).map_or("", String::as_str);
+    let diagnostics: Vec<Value> = analysis::diagnostics(text)
+      .iter()
+      .map(|diagnostic| diagnostic_to_json(text, diagnostic))
+      .collect();
+    notification(
+      "textDocument/publishDiagnostics",
+      json!({ "uri": uri, "diagnostics": diagnostics }),
+    )
+  }
+
+  // Runs `query` on the document and offset a `TextDocumentPositionParams`
+  // refers to.
+  fn at_position<'a, T>(
+    &'a self,
+    params: &Value,
+    query: impl Fn(&str, usize) -> Option<T>,
+  ) -> Option<(&'a str, T)> {
+    let text = self
+      .documents
+      .get(params["textDocument"]["uri"].as_str()?)?;
+    let line = params["position"]["line"].as_u64()? as usize;
+    let character = params["position"]["character"].as_u64()? as usize;
+    Some((text, query(text, offset(text, line, character))?))
+  }
+}
+
+fn response(request: &Value, result: Value) -> Value {
+  json!({ "jsonrpc": "2.0", "id": request["id"], "result": result })
+}
+
+fn notification(method: &str, params: Value) -> Value {
+  json!({ "jsonrpc": "2.0", "method": method, "params": params })
+}
+
+fn diagnostic_to_json(text: &str, diagnostic: &Diagnostic) -> Value {
+  let span = diagnostic.span.unwrap_or_else(|| Span::new(0, text.len()));
+  json!({
+    "range": range(text, span),
+    "severity": 1,
+    "source": "system_f",
+    "message": diagnostic.message,
+  })
+}
+
+// Positions count lines from 0 and characters in UTF-16 code units, as the
+// protocol specifies.
+fn position(text: &str, offset: usize) -> Value {
+  let before = &text[..offset.min(text.len())];
+  let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+  json!({
+    "line": before.matches('\n').count(),
+    "character": before[line_start..].encode_utf16().count(),
+  })
+}
+
+fn range(text: &str, span: Span) -> Value {
+  json!({ "start": position(text, span.start), "end": position(text, span.end) })
+}
+
+// The byte offset of a position, clamped to the end of its line.
+fn offset(text: &str, line: usize, character: usize) -> usize {
+  let line_start: usize = text.split_inclusive('\n').take(line).map(str::len).sum();
+  let mut units = 0;
+  for (i, c) in text[line_start..].char_indices() {
+    if units >= character || c == '\n' {
+      return line_start + i;
+    }
+    units += c.len_utf16();
+  }
+  text.len()
+}
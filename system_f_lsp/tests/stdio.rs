@@ -0,0 +1,232 @@
+use serde_json::{json, Value};
+use std::io::{BufReader, Write};
+use std::process::{ChildStdin, Command, Stdio};
+use system_f_lsp::rpc::{read_message, write_message};
+
+const URI: &str = "file:///test.sf";
+
+// Runs the server binary on a script of messages and returns everything it
+// sends back.
+fn run(script: &[Value]) -> Vec<Value> {
+  run_with(|stdin| {
+    for message in script {
+      write_message(stdin, message).unwrap();
+    }
+  })
+}
+
+// Like `run`, with the script written to the server's input by `write`.
+fn run_with(write: impl FnOnce(&mut ChildStdin)) -> Vec<Value> {
+  let mut child = Command::new(env!("CARGO_BIN_EXE_system_f_lsp"))
+    .stdin(Stdio::piped())
+    .stdout(Stdio::piped())
+    .spawn()
+    .unwrap();
+  let mut stdin = child.stdin.take().unwrap();
+  write_message(&mut stdin, &request(0, "initialize", json!({}))).unwrap();
+  write_message(&mut stdin, &notification("initialized", json!({}))).unwrap();
+  write(&mut stdin);
+  write_message(&mut stdin, &request(99, "shutdown", Value::Null)).unwrap();
+  write_message(&mut stdin, &notification("exit", Value::Null)).unwrap();
+  stdin.flush().unwrap();
+  drop(stdin);
+  let mut stdout = BufReader::new(child.stdout.take().unwrap());
+  let mut messages = Vec::new();
+  while let Some(message) = read_message(&mut stdout).unwrap() {
+    messages.push(message);
+  }
+  assert!(child.wait().unwrap().success());
+  messages
+}
+
+fn request(id: u64, method: &str, params: Value) -> Value {
+  json!({ "jsonrpc": "2.0", "id": id, "method": method, "params": params })
+}
+
+fn notification(method: &str, params: Value) -> Value {
+  json!({ "jsonrpc": "2.0", "method": method, "params": params })
+}
+
+fn open(text: &str) -> Value {
+  notification(
+    "textDocument/didOpen",
+    json!({ "textDocument": { "uri": URI, "languageId": "systemf", "version": 1, "text": text } }),
+  )
+}
+
+fn at(id: u64, method: &str, line: u64, character: u64) -> Value {
+  request(
+    id,
+    method,
+    json!({
+      "textDocument": { "uri": URI },
+      "position": { "line": line, "character": character },
+    }),
+  )
+}
+
+fn result(messages: &[Value], id: u64) -> &Value {
+  &messages.iter().find(|message| message["id"] == id).unwrap()["result"]
+}
+
+fn diagnostics(messages: &[Value]) -> Vec<&Value> {
+  messages
+    .iter()
+    .filter(|message| message["method"] == "textDocument/publishDiagnostics")
+    .map(|message| &message["params"]["diagnostics"])
+    .collect()
+}
+
+fn range(start: (u64, u64), end: (u64, u64)) -> Value {
+  json!({
+    "start": { "line": start.0, "character": start.1 },
+    "end": { "line": end.0, "character": end.1 },
+  })
+}
+
+#[test]
+fn initialize_advertises_capabilities() {
+  let messages = run(&[]);
+  let capabilities = &result(&messages, 0)["capabilities"];
+  assert_eq!(capabilities["hoverProvider"], true);
+  assert_eq!(capabilities["definitionProvider"], true);
+  assert_eq!(capabilities["documentFormattingProvider"], true);
+  assert_eq!(result(&messages, 99), &Value::Null);
+}
+
+#[test]
+fn diagnostics_are_published_on_open_and_save() {
+  let messages = run(&[
+    open("let x = 1 in\nλz: Int. y"),
+    notification(
+      "textDocument/didChange",
+      json!({ "textDocument": { "uri": URI, "version": 2 }, "contentChanges": [{ "text": "λx: Int) x" }] }),
+    ),
+    notification(
+      "textDocument/didSave",
+      json!({ "textDocument": { "uri": URI } }),
+    ),
+    notification(
+      "textDocument/didSave",
+      json!({ "textDocument": { "uri": URI }, "text": "(λx: Int. x) 1" }),
+    ),
+  ]);
+  let published = diagnostics(&messages);
+  assert_eq!(published.len(), 3);
+  assert_eq!(published[0][0]["message"], "variable y is not defined");
+  assert_eq!(published[0][0]["range"], range((1, 9), (1, 10)));
+  assert_eq!(published[1][0]["range"], range((0, 7), (0, 8)));
+  assert!(published[1][0]["message"]
+    .as_str()
+    .unwrap()
+    .starts_with("unexpected token )"));
+  assert_eq!(published[2], &json!([]));
+}
+
+#[test]
+fn hover_shows_the_type_of_the_subterm() {
+  let messages = run(&[
+    open("let f = λx: Int. x in\nlet id = ΛX: *. λy: X. y in id (f 1)"),
+    at(1, "textDocument/hover", 1, 32),
+    at(2, "textDocument/hover", 1, 28),
+    at(3, "textDocument/hover", 0, 17),
+    at(4, "textDocument/hover", 1, 23),
+  ]);
+  // f in `f 1`
+  assert_eq!(result(&messages, 1)["contents"]["value"], "Int -> Int");
+  assert_eq!(result(&messages, 1)["range"], range((1, 32), (1, 33)));
+  // id, at the type it is instantiated to
+  assert_eq!(result(&messages, 2)["contents"]["value"], "Int -> Int");
+  // x in the body of f
+  assert_eq!(result(&messages, 3)["contents"]["value"], "Int");
+  // y, bound under a type abstraction
+  assert_eq!(result(&messages, 4)["contents"]["value"], "X");
+}
+
+#[test]
+fn definition_jumps_to_the_binder() {
+  let messages = run(&[
+    open("let x = 1 in\nlet rec f: Int -> Int = λy. f x in\nλx. f x"),
+    at(1, "textDocument/definition", 2, 6),
+    at(2, "textDocument/definition", 2, 4),
+    at(3, "textDocument/definition", 1, 28),
+    at(4, "textDocument/definition", 1, 30),
+    at(5, "textDocument/definition", 0, 8),
+  ]);
+  assert_eq!(result(&messages, 1)["range"], range((2, 1), (2, 2)));
+  assert_eq!(result(&messages, 2)["range"], range((1, 8), (1, 9)));
+  assert_eq!(result(&messages, 3)["range"], range((1, 8), (1, 9)));
+  assert_eq!(result(&messages, 4)["range"], range((0, 4), (0, 5)));
+  assert_eq!(result(&messages, 4)["uri"], URI);
+  assert_eq!(result(&messages, 5), &Value::Null);
+}
+
+#[test]
+fn formatting_prints_the_document_canonically() {
+  let messages = run(&[
+    open("( λx :Int.x )\n  (1)"),
+    request(
+      1,
+      "textDocument/formatting",
+      json!({ "textDocument": { "uri": URI }, "options": {} }),
+    ),
+    request(2, "textDocument/references", json!({})),
+  ]);
+  assert_eq!(
+    result(&messages, 1),
+    &json!([{ "range": range((0, 0), (1, 5)), "newText": "(λx: Int. x) 1\n" }])
+  );
+  let error = &messages.iter().find(|message| message["id"] == 2).unwrap()["error"];
+  assert_eq!(error["code"], -32601);
+}
+
+#[test]
+fn malformed_frames_are_answered_and_skipped() {
+  let messages = run_with(|stdin| {
+    // A body that is not JSON, then headers without a Content-Length.
+    write!(stdin, "Content-Length: 9\r\n\r\n{{not json").unwrap();
+    write!(stdin, "Content-Type: application/json\r\n\r\n").unwrap();
+    write_message(stdin, &open("λx: Int. x")).unwrap();
+    write_message(stdin, &at(1, "textDocument/hover", 0, 9)).unwrap();
+  });
+  let errors: Vec<_> = messages
+    .iter()
+    .filter(|message| message["error"]["code"] == -32700)
+    .collect();
+  assert_eq!(errors.len(), 2);
+  assert!(errors.iter().all(|error| error["id"].is_null()));
+  assert_eq!(diagnostics(&messages), vec![&json!([])]);
+  // x in the body of the λ
+  assert_eq!(result(&messages, 1)["contents"]["value"], "Int");
+}
+
+#[test]
+fn overflowing_literals_are_reported_and_serving_goes_on() {
+  let messages = run(&[
+    open("(λx: Int. x) 99999999999"),
+    at(1, "textDocument/hover", 0, 1),
+  ]);
+  let published = diagnostics(&messages);
+  assert_eq!(published.len(), 1);
+  assert_eq!(published[0][0]["message"], "integer literal is too large");
+  assert_eq!(result(&messages, 1), &Value::Null);
+  assert_eq!(result(&messages, 99), &Value::Null);
+}
+
+#[test]
+fn oversized_frames_are_rejected_without_reading_them() {
+  let messages = run_with(|stdin| {
+    write!(stdin, "Content-Length: 99999999999999\r\n\r\n").unwrap();
+    write_message(stdin, &at(1, "textDocument/hover", 0, 0)).unwrap();
+  });
+  let errors: Vec<_> = messages
+    .iter()
+    .filter(|message| message["error"]["code"] == -32700)
+    .collect();
+  assert_eq!(errors.len(), 1);
+  assert!(errors[0]["error"]["message"]
+    .as_str()
+    .unwrap()
+    .starts_with("Content-Length 99999999999999 is larger than"));
+  assert_eq!(result(&messages, 1), &Value::Null);
+}
@@ -0,0 +1,5 @@
+extern crate lalrpop;
+
+fn main() {
+  lalrpop::process_root().unwrap();
+}
@@ -0,0 +1,189 @@
+use lambda_core::pretty::{fmt_fields, Precedence};
+use std::fmt;
+
+// Type ::=
+//  | Top                        -- supertype of every type
+//  | Int                        -- base type
+//  | Type -> Type               -- function type
+//  | {Label: Type, ...}         -- record type
+//  | (Type)                     -- grouping
+//  | TypeVar
+//  | ∀ TypeVar <: Type . Type   -- bounded quantification
+//  | ∀ TypeVar . Type           -- sugar for ∀ TypeVar <: Top . Type
+#[derive(Debug, Clone, PartialEq)]
+#[allow(clippy::enum_variant_names)]
+pub enum Type {
+  // Top
+  Top,
+  // Int
+  Int,
+  // Type -> Type
+  Arrow(Box<Type>, Box<Type>),
+  // {Label: Type, ...}
+  Record(Vec<(String, Type)>),
+  // TypeVar
+  TypeVar(String),
+  // ∀ TypeVar <: Type . Type
+  Forall {
+    type_var: String,
+    bound: Box<Type>,
+    typ: Box<Type>,
+  },
+}
+
+// Term ::=
+//  | Int                        -- integer literal
+//  | Var                        -- term variable
+//  | Term Term                  -- term application
+//  | λ Var : Type . Term        -- term abstraction
+//  | (Term : Type)              -- type ascription
+//  | (Term)                     -- grouping
+//  | Λ TypeVar <: Type . Term   -- bounded type abstraction
+//  | Λ TypeVar . Term           -- sugar for Λ TypeVar <: Top . Term
+//  | Term [Type]                -- type application
+//  | {Label = Term, ...}        -- record
+//  | Term.Label                 -- field projection
+#[derive(Debug, Clone, PartialEq)]
+pub enum Term {
+  // Int
+  Int(i32),
+  // Var
+  Var(String),
+  // Term Term
+  App(Box<Term>, Box<Term>),
+  // λ Var : Type . Term
+  Abs {
+    param_name: String,
+    param_type: Type,
+    body: Box<Term>,
+  },
+  // (Term : Type)
+  Ann(Box<Term>, Type),
+  // Λ TypeVar <: Type . Term
+  TypeAbs {
+    type_var: String,
+    bound: Type,
+    body: Box<Term>,
+  },
+  // Term [Type]
+  TypeApp(Box<Term>, Type),
+  // {Label = Term, ...}
+  Record(Vec<(String, Term)>),
+  // Term.Label
+  Field(Box<Term>, String),
+}
+
+// Pretty printing produces syntax that the grammar parses back into the same tree.
+
+// Writes `<: bound` unless the bound is Top, which is left implicit.
+fn fmt_bound(f: &mut fmt::Formatter<'_>, bound: &Type) -> fmt::Result {
+  match bound {
+    Type::Top => Ok(()),
+    _ => write!(f, " <: {}", bound),
+  }
+}
+
+impl Precedence for Type {
+  fn precedence(&self) -> usize {
+    match self {
+      Type::Top | Type::Int | Type::TypeVar(_) | Type::Record(_) => 1,
+      Type::Arrow(..) => 2,
+      Type::Forall { .. } => 3,
+    }
+  }
+}
+
+impl fmt::Display for Type {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Type::Top => write!(f, "Top"),
+      Type::Int => write!(f, "Int"),
+      Type::Arrow(param_type, return_type) => self.fmt_binary(f, "->", param_type, return_type),
+      Type::Record(fields) => {
+        write!(f, "{{")?;
+        fmt_fields(f, fields, ": ")?;
+        write!(f, "}}")
+      }
+      Type::TypeVar(x) => write!(f, "{}", x),
+      Type::Forall {
+        type_var,
+        bound,
+        typ,
+      } => {
+        write!(f, "∀{}", type_var)?;
+        fmt_bound(f, bound)?;
+        write!(f, ". {}", typ)
+      }
+    }
+  }
+}
+
+impl Term {
+  fn is_atom(&self) -> bool {
+    matches!(
+      self,
+      Term::Int(_) | Term::Var(_) | Term::Ann(..) | Term::Record(_) | Term::Field(..)
+    )
+  }
+
+  // Terms that extend as far to the right as possible.
+  fn is_open(&self) -> bool {
+    matches!(self, Term::Abs { .. } | Term::TypeAbs { .. })
+  }
+}
+
+impl fmt::Display for Term {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Term::Int(n) => write!(f, "{}", n),
+      Term::Var(x) => write!(f, "{}", x),
+      Term::App(function, arg) => {
+        if function.is_open() {
+          write!(f, "({})", function)?;
+        } else {
+          write!(f, "{}", function)?;
+        }
+        if arg.is_atom() {
+          write!(f, " {}", arg)
+        } else {
+          write!(f, " ({})", arg)
+        }
+      }
+      Term::Abs {
+        param_name,
+        param_type,
+        body,
+      } => write!(f, "λ{}: {}. {}", param_name, param_type, body),
+      Term::Ann(term, typ) => write!(f, "({} : {})", term, typ),
+      Term::TypeAbs {
+        type_var,
+        bound,
+        body,
+      } => {
+        write!(f, "Λ{}", type_var)?;
+        fmt_bound(f, bound)?;
+        write!(f, ". {}", body)
+      }
+      Term::TypeApp(term, typ) => {
+        if term.is_open() {
+          write!(f, "({})", term)?;
+        } else {
+          write!(f, "{}", term)?;
+        }
+        write!(f, " [{}]", typ)
+      }
+      Term::Record(fields) => {
+        write!(f, "{{")?;
+        fmt_fields(f, fields, " = ")?;
+        write!(f, "}}")
+      }
+      Term::Field(term, label) => {
+        if term.is_atom() {
+          write!(f, "{}.{}", term, label)
+        } else {
+          write!(f, "({}).{}", term, label)
+        }
+      }
+    }
+  }
+}
@@ -0,0 +1,689 @@
+use crate::ast;
+use crate::typechecker::{self, TypecheckerError};
+use lambda_core::names::{fresh_name, lookup, name_of, shift_index};
+
+pub use lambda_core::Hint;
+
+// Locally nameless intermediate representation.
+//
+// Bound variables are replaced by de Bruijn indices: the number of binders of
+// the same sort between the occurrence and the binder it refers to. Term
+// variables and type variables live in separate index spaces, so `λ` only
+// shifts term indices and `Λ` only shifts type indices.
+//
+//   λx: Int. λy: Int. x            ~>   λ: Int. λ: Int. 1
+//   ΛX <: {a: Int}. λx: X. x.a     ~>   Λ <: {a: Int}. λ: 0. 0.a
+//
+// The bound of a type variable is part of the binder, outside of its scope.
+
+// Type ::=
+//  | Top
+//  | Int
+//  | Type -> Type
+//  | {Label: Type, ...}   -- fields sorted by label
+//  | n                    -- type variable
+//  | ∀ <: Type . Type
+#[derive(Debug, Clone, PartialEq)]
+#[allow(clippy::enum_variant_names)]
+pub enum Type {
+  Top,
+  Int,
+  Arrow(Box<Type>, Box<Type>),
+  Record(Vec<(String, Type)>),
+  TypeVar(usize),
+  Forall {
+    hint: Hint,
+    bound: Box<Type>,
+    typ: Box<Type>,
+  },
+}
+
+// Term ::=
+//  | Int
+//  | n                 -- term variable
+//  | Term Term
+//  | λ : Type . Term
+//  | (Term : Type)
+//  | Λ <: Type . Term
+//  | Term [Type]
+//  | {Label = Term, ...}
+//  | Term.Label
+#[derive(Debug, Clone, PartialEq)]
+pub enum Term {
+  Int(i32),
+  Var(usize),
+  App(Box<Term>, Box<Term>),
+  Abs {
+    hint: Hint,
+    param_type: Type,
+    body: Box<Term>,
+  },
+  Ann(Box<Term>, Type),
+  TypeAbs {
+    hint: Hint,
+    bound: Type,
+    body: Box<Term>,
+  },
+  TypeApp(Box<Term>, Type),
+  Record(Vec<(String, Term)>),
+  Field(Box<Term>, String),
+}
+
+// Γ, innermost binding last. Every type stored in `terms` is valid in the
+// type variable context at the current depth, while the bound of a type
+// variable is valid in the context it was introduced in.
+#[derive(Debug, Clone, Default)]
+pub struct Context {
+  terms: Vec<(String, Type)>,
+  types: Vec<(String, Type)>,
+}
+
+// Sorts the fields of a record type by label, so that types that only list
+// their fields in a different order are equal. Fails if a label appears more
+// than once.
+fn sort_fields<T>(mut fields: Vec<(String, T)>) -> Result<Vec<(String, T)>, TypecheckerError> {
+  for (i, (label, _)) in fields.iter().enumerate() {
+    if fields[..i].iter().any(|(other, _)| other == label) {
+      return Err(TypecheckerError::DuplicateLabel(label.clone()));
+    }
+  }
+  fields.sort_by(|(a, _), (b, _)| a.cmp(b));
+  Ok(fields)
+}
+
+pub fn field<'a, T>(fields: &'a [(String, T)], label: &str) -> Option<&'a T> {
+  fields
+    .iter()
+    .find(|(other, _)| other == label)
+    .map(|(_, value)| value)
+}
+
+impl Context {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  pub fn assign(&self, name: String, typ: Type) -> Context {
+    let mut ctx = self.clone();
+    ctx.terms.push((name, typ));
+    ctx
+  }
+
+  pub fn assign_type(&self, name: String, bound: Type) -> Context {
+    let mut ctx = self.clone();
+    for (_, typ) in ctx.terms.iter_mut() {
+      *typ = typ.shift(1, 0);
+    }
+    ctx.types.push((name, bound));
+    ctx
+  }
+
+  pub fn get(&self, i: usize) -> Option<&Type> {
+    let position = self.terms.len().checked_sub(i + 1)?;
+    Some(&self.terms[position].1)
+  }
+
+  // The bound of the type variable `i`, shifted to be valid at the current
+  // depth.
+  pub fn get_bound(&self, i: usize) -> Option<Type> {
+    let position = self.types.len().checked_sub(i + 1)?;
+    Some(self.types[position].1.shift(i as isize + 1, 0))
+  }
+}
+
+impl Type {
+  pub fn from_ast(ctx: &Context, typ: &ast::Type) -> Result<Type, TypecheckerError> {
+    match typ {
+      ast::Type::Top => Ok(Type::Top),
+      ast::Type::Int => Ok(Type::Int),
+      ast::Type::Arrow(param_type, return_type) => Ok(Type::Arrow(
+        Box::new(Type::from_ast(ctx, param_type)?),
+        Box::new(Type::from_ast(ctx, return_type)?),
+      )),
+      ast::Type::Record(fields) => Ok(Type::Record(sort_fields(
+        fields
+          .iter()
+          .map(|(label, typ)| Ok((label.clone(), Type::from_ast(ctx, typ)?)))
+          .collect::<Result<_, TypecheckerError>>()?,
+      )?)),
+      ast::Type::TypeVar(x) => match lookup(&ctx.types, x) {
+        None => Err(TypecheckerError::UndefinedTypeVariable(x.clone())),
+        Some(i) => Ok(Type::TypeVar(i)),
+      },
+      ast::Type::Forall {
+        type_var,
+        bound,
+        typ,
+      } => {
+        let bound = Type::from_ast(ctx, bound)?;
+        let ctx = ctx.assign_type(type_var.clone(), bound.clone());
+        Ok(Type::Forall {
+          hint: Hint(type_var.clone()),
+          bound: Box::new(bound),
+          typ: Box::new(Type::from_ast(&ctx, typ)?),
+        })
+      }
+    }
+  }
+
+  pub fn to_ast(&self, ctx: &Context) -> ast::Type {
+    match self {
+      Type::Top => ast::Type::Top,
+      Type::Int => ast::Type::Int,
+      Type::Arrow(param_type, return_type) => ast::Type::Arrow(
+        Box::new(param_type.to_ast(ctx)),
+        Box::new(return_type.to_ast(ctx)),
+      ),
+      Type::Record(fields) => ast::Type::Record(
+        fields
+          .iter()
+          .map(|(label, typ)| (label.clone(), typ.to_ast(ctx)))
+          .collect(),
+      ),
+      Type::TypeVar(i) => ast::Type::TypeVar(name_of(&ctx.types, *i)),
+      Type::Forall { hint, bound, typ } => {
+        let type_var = fresh_name(&ctx.types, &hint.0);
+        let inner = ctx.assign_type(type_var.clone(), (**bound).clone());
+        ast::Type::Forall {
+          type_var,
+          bound: Box::new(bound.to_ast(ctx)),
+          typ: Box::new(typ.to_ast(&inner)),
+        }
+      }
+    }
+  }
+
+  // Rebuilds the type, replacing every type variable with `on_var(cutoff, index)`,
+  // where `cutoff` is the number of binders crossed so far.
+  fn map_vars(&self, cutoff: usize, on_var: &dyn Fn(usize, usize) -> Type) -> Type {
+    match self {
+      Type::Top => Type::Top,
+      Type::Int => Type::Int,
+      Type::Arrow(param_type, return_type) => Type::Arrow(
+        Box::new(param_type.map_vars(cutoff, on_var)),
+        Box::new(return_type.map_vars(cutoff, on_var)),
+      ),
+      Type::Record(fields) => Type::Record(
+        fields
+          .iter()
+          .map(|(label, typ)| (label.clone(), typ.map_vars(cutoff, on_var)))
+          .collect(),
+      ),
+      Type::TypeVar(i) => on_var(cutoff, *i),
+      Type::Forall { hint, bound, typ } => Type::Forall {
+        hint: hint.clone(),
+        bound: Box::new(bound.map_vars(cutoff, on_var)),
+        typ: Box::new(typ.map_vars(cutoff + 1, on_var)),
+      },
+    }
+  }
+
+  // ↑d,c(T): adds `d` to every type variable that is free above `cutoff`.
+  pub fn shift(&self, d: isize, cutoff: usize) -> Type {
+    self.map_vars(cutoff, &|c, i| {
+      if i >= c {
+        Type::TypeVar(shift_index(i, d))
+      } else {
+        Type::TypeVar(i)
+      }
+    })
+  }
+
+  // T[S/j]
+  pub fn subst(&self, j: usize, s: &Type) -> Type {
+    self.map_vars(0, &|c, i| {
+      if i == j + c {
+        s.shift(c as isize, 0)
+      } else {
+        Type::TypeVar(i)
+      }
+    })
+  }
+
+  // Given the body T of `∀α <: U. T`, computes T[S/α].
+  pub fn instantiate(&self, s: &Type) -> Type {
+    self.subst(0, &s.shift(1, 0)).shift(-1, 0)
+  }
+}
+
+impl Term {
+  pub fn from_ast(ctx: &Context, term: &ast::Term) -> Result<Term, TypecheckerError> {
+    match term {
+      ast::Term::Int(n) => Ok(Term::Int(*n)),
+      ast::Term::Var(x) => match lookup(&ctx.terms, x) {
+        None => Err(TypecheckerError::UndefinedVariable(x.clone())),
+        Some(i) => Ok(Term::Var(i)),
+      },
+      ast::Term::App(f, arg) => Ok(Term::App(
+        Box::new(Term::from_ast(ctx, f)?),
+        Box::new(Term::from_ast(ctx, arg)?),
+      )),
+      ast::Term::Abs {
+        param_name,
+        param_type,
+        body,
+      } => {
+        let param_type = Type::from_ast(ctx, param_type)?;
+        let ctx = ctx.assign(param_name.clone(), param_type.clone());
+        Ok(Term::Abs {
+          hint: Hint(param_name.clone()),
+          param_type,
+          body: Box::new(Term::from_ast(&ctx, body)?),
+        })
+      }
+      ast::Term::Ann(term, typ) => Ok(Term::Ann(
+        Box::new(Term::from_ast(ctx, term)?),
+        Type::from_ast(ctx, typ)?,
+      )),
+      ast::Term::TypeAbs {
+        type_var,
+        bound,
+        body,
+      } => {
+        let bound = Type::from_ast(ctx, bound)?;
+        let ctx = ctx.assign_type(type_var.clone(), bound.clone());
+        Ok(Term::TypeAbs {
+          hint: Hint(type_var.clone()),
+          bound,
+          body: Box::new(Term::from_ast(&ctx, body)?),
+        })
+      }
+      ast::Term::TypeApp(term, typ) => Ok(Term::TypeApp(
+        Box::new(Term::from_ast(ctx, term)?),
+        Type::from_ast(ctx, typ)?,
+      )),
+      ast::Term::Record(fields) => Ok(Term::Record(
+        fields
+          .iter()
+          .map(|(label, term)| Ok((label.clone(), Term::from_ast(ctx, term)?)))
+          .collect::<Result<_, TypecheckerError>>()?,
+      )),
+      ast::Term::Field(term, label) => Ok(Term::Field(
+        Box::new(Term::from_ast(ctx, term)?),
+        label.clone(),
+      )),
+    }
+  }
+
+  pub fn to_ast(&self, ctx: &Context) -> ast::Term {
+    match self {
+      Term::Int(n) => ast::Term::Int(*n),
+      Term::Var(i) => ast::Term::Var(name_of(&ctx.terms, *i)),
+      Term::App(f, arg) => ast::Term::App(Box::new(f.to_ast(ctx)), Box::new(arg.to_ast(ctx))),
+      Term::Abs {
+        hint,
+        param_type,
+        body,
+      } => {
+        let param_name = fresh_name(&ctx.terms, &hint.0);
+        let inner = ctx.assign(param_name.clone(), param_type.clone());
+        ast::Term::Abs {
+          param_name,
+          param_type: param_type.to_ast(ctx),
+          body: Box::new(body.to_ast(&inner)),
+        }
+      }
+      Term::Ann(term, typ) => ast::Term::Ann(Box::new(term.to_ast(ctx)), typ.to_ast(ctx)),
+      Term::TypeAbs { hint, bound, body } => {
+        let type_var = fresh_name(&ctx.types, &hint.0);
+        let inner = ctx.assign_type(type_var.clone(), bound.clone());
+        ast::Term::TypeAbs {
+          type_var,
+          bound: bound.to_ast(ctx),
+          body: Box::new(body.to_ast(&inner)),
+        }
+      }
+      Term::TypeApp(term, typ) => ast::Term::TypeApp(Box::new(term.to_ast(ctx)), typ.to_ast(ctx)),
+      Term::Record(fields) => ast::Term::Record(
+        fields
+          .iter()
+          .map(|(label, term)| (label.clone(), term.to_ast(ctx)))
+          .collect(),
+      ),
+      Term::Field(term, label) => ast::Term::Field(Box::new(term.to_ast(ctx)), label.clone()),
+    }
+  }
+
+  // Rebuilds the term, replacing every term variable with
+  // `on_var(term_cutoff, type_cutoff, index)` and every type annotation with
+  // `on_type(type_cutoff, type)`, where the cutoffs are the number of binders
+  // of each sort crossed so far.
+  fn map_vars(
+    &self,
+    term_cutoff: usize,
+    type_cutoff: usize,
+    on_var: &dyn Fn(usize, usize, usize) -> Term,
+    on_type: &dyn Fn(usize, &Type) -> Type,
+  ) -> Term {
+    match self {
+      Term::Int(n) => Term::Int(*n),
+      Term::Var(i) => on_var(term_cutoff, type_cutoff, *i),
+      Term::App(f, arg) => Term::App(
+        Box::new(f.map_vars(term_cutoff, type_cutoff, on_var, on_type)),
+        Box::new(arg.map_vars(term_cutoff, type_cutoff, on_var, on_type)),
+      ),
+      Term::Abs {
+        hint,
+        param_type,
+        body,
+      } => Term::Abs {
+        hint: hint.clone(),
+        param_type: on_type(type_cutoff, param_type),
+        body: Box::new(body.map_vars(term_cutoff + 1, type_cutoff, on_var, on_type)),
+      },
+      Term::Ann(term, typ) => Term::Ann(
+        Box::new(term.map_vars(term_cutoff, type_cutoff, on_var, on_type)),
+        on_type(type_cutoff, typ),
+      ),
+      Term::TypeAbs { hint, bound, body } => Term::TypeAbs {
+        hint: hint.clone(),
+        bound: on_type(type_cutoff, bound),
+        body: Box::new(body.map_vars(term_cutoff, type_cutoff + 1, on_var, on_type)),
+      },
+      Term::TypeApp(term, typ) => Term::TypeApp(
+        Box::new(term.map_vars(term_cutoff, type_cutoff, on_var, on_type)),
+        on_type(type_cutoff, typ),
+      ),
+      Term::Record(fields) => Term::Record(
+        fields
+          .iter()
+          .map(|(label, term)| {
+            (
+              label.clone(),
+              term.map_vars(term_cutoff, type_cutoff, on_var, on_type),
+            )
+          })
+          .collect(),
+      ),
+      Term::Field(term, label) => Term::Field(
+        Box::new(term.map_vars(term_cutoff, type_cutoff, on_var, on_type)),
+        label.clone(),
+      ),
+    }
+  }
+
+  // ↑d,c(t) on term variables.
+  pub fn shift(&self, d: isize, cutoff: usize) -> Term {
+    self.map_vars(
+      cutoff,
+      0,
+      &|c, _, i| {
+        if i >= c {
+          Term::Var(shift_index(i, d))
+        } else {
+          Term::Var(i)
+        }
+      },
+      &|_, typ| typ.clone(),
+    )
+  }
+
+  // ↑d,c(t) on the type variables appearing in annotations.
+  pub fn shift_types(&self, d: isize, cutoff: usize) -> Term {
+    self.map_vars(0, cutoff, &|_, _, i| Term::Var(i), &|c, typ| {
+      typ.shift(d, c)
+    })
+  }
+
+  // t[s/j]
+  pub fn subst(&self, j: usize, s: &Term) -> Term {
+    self.map_vars(
+      0,
+      0,
+      &|c, type_c, i| {
+        if i == j + c {
+          s.shift(c as isize, 0).shift_types(type_c as isize, 0)
+        } else {
+          Term::Var(i)
+        }
+      },
+      &|_, typ| typ.clone(),
+    )
+  }
+
+  // t[S/j]
+  pub fn subst_type(&self, j: usize, s: &Type) -> Term {
+    self.map_vars(0, 0, &|_, _, i| Term::Var(i), &|c, typ| {
+      typ.subst(j + c, &s.shift(c as isize, 0))
+    })
+  }
+
+  // Given the body t of `λx. t`, computes t[v/x].
+  pub fn instantiate(&self, v: &Term) -> Term {
+    self.subst(0, &v.shift(1, 0)).shift(-1, 0)
+  }
+
+  // Given the body t of `Λα <: U. t`, computes t[σ/α].
+  pub fn instantiate_type(&self, s: &Type) -> Term {
+    self.subst_type(0, &s.shift(1, 0)).shift_types(-1, 0)
+  }
+
+  pub fn type_of(&self, ctx: &Context) -> Result<Type, TypecheckerError> {
+    match self {
+      Term::Int(_) => Ok(Type::Int),
+      // Γ(x) = τ
+      // --------
+      // Γ ⊢ x: τ
+      Term::Var(i) => match ctx.get(*i) {
+        None => Err(TypecheckerError::UndefinedVariable(name_of(&ctx.terms, *i))),
+        Some(typ) => Ok(typ.clone()),
+      },
+      // Γ ⊢ t1: σ1 -> τ    Γ ⊢ t2: σ2    Γ ⊢ σ2 <: σ1
+      // ---------------------------------------------
+      //               Γ ⊢ (t1 t2): τ
+      Term::App(f, arg) => match typechecker::expose(ctx, &f.type_of(ctx)?) {
+        Type::Arrow(param_type, return_type) => {
+          let arg_type = arg.type_of(ctx)?;
+
+          if !typechecker::subtype(ctx, &arg_type, &param_type) {
+            return Err(TypecheckerError::NotASubtype {
+              term: arg.to_ast(ctx),
+              expected: param_type.to_ast(ctx),
+              got: arg_type.to_ast(ctx),
+            });
+          }
+
+          Ok(*return_type)
+        }
+        _ => Err(TypecheckerError::UnexpectedTerm {
+          expected: String::from("abstraction"),
+          got: f.to_ast(ctx),
+        }),
+      },
+      //    Γ, x: σ ⊢ t: τ
+      // ----------------------
+      // Γ ⊢ (λx: σ. t): σ -> τ
+      Term::Abs {
+        hint,
+        param_type,
+        body,
+      } => {
+        let ctx = ctx.assign(fresh_name(&ctx.terms, &hint.0), param_type.clone());
+        let body_typ = body.type_of(&ctx)?;
+        Ok(Type::Arrow(
+          Box::new(param_type.clone()),
+          Box::new(body_typ),
+        ))
+      }
+      // Γ ⊢ t: σ    Γ ⊢ σ <: τ
+      // ----------------------
+      //    Γ ⊢ (t : τ): τ
+      Term::Ann(term, typ) => {
+        let got = term.type_of(ctx)?;
+
+        if !typechecker::subtype(ctx, &got, typ) {
+          return Err(TypecheckerError::NotASubtype {
+            term: term.to_ast(ctx),
+            expected: typ.to_ast(ctx),
+            got: got.to_ast(ctx),
+          });
+        }
+
+        Ok(typ.clone())
+      }
+      //       Γ, α <: υ ⊢ t : τ
+      // ------------------------------
+      // Γ ⊢ (Λα <: υ. t) : ∀α <: υ. τ
+      Term::TypeAbs { hint, bound, body } => {
+        let ctx = ctx.assign_type(fresh_name(&ctx.types, &hint.0), bound.clone());
+        Ok(Type::Forall {
+          hint: hint.clone(),
+          bound: Box::new(bound.clone()),
+          typ: Box::new(body.type_of(&ctx)?),
+        })
+      }
+      // Γ ⊢ t : ∀α <: υ. τ    Γ ⊢ σ <: υ
+      // --------------------------------
+      //       Γ ⊢ t [σ] : τ[σ/α]
+      Term::TypeApp(term, arg_typ) => match typechecker::expose(ctx, &term.type_of(ctx)?) {
+        Type::Forall { bound, typ, .. } => {
+          if !typechecker::subtype(ctx, arg_typ, &bound) {
+            return Err(TypecheckerError::UnsatisfiedBound {
+              typ: arg_typ.to_ast(ctx),
+              bound: bound.to_ast(ctx),
+            });
+          }
+
+          Ok(typ.instantiate(arg_typ))
+        }
+        _ => Err(TypecheckerError::UnexpectedTerm {
+          expected: String::from("type abstraction"),
+          got: term.to_ast(ctx),
+        }),
+      },
+      // Γ ⊢ ti : τi for each i    the labels li are distinct
+      // ---------------------------------------------------
+      //   Γ ⊢ {l1 = t1, ..., ln = tn} : {l1: τ1, ..., ln: τn}
+      Term::Record(fields) => Ok(Type::Record(sort_fields(
+        fields
+          .iter()
+          .map(|(label, term)| Ok((label.clone(), term.type_of(ctx)?)))
+          .collect::<Result<_, TypecheckerError>>()?,
+      )?)),
+      // Γ ⊢ t : {l1: τ1, ..., ln: τn}
+      // -----------------------------
+      //        Γ ⊢ t.li : τi
+      Term::Field(term, label) => match typechecker::expose(ctx, &term.type_of(ctx)?) {
+        Type::Record(fields) => match field(&fields, label) {
+          Some(typ) => Ok(typ.clone()),
+          None => Err(TypecheckerError::MissingLabel {
+            label: label.clone(),
+            typ: Type::Record(fields).to_ast(ctx),
+          }),
+        },
+        typ => Err(TypecheckerError::UnexpectedType {
+          expected: String::from("record type"),
+          got: typ.to_ast(ctx),
+        }),
+      },
+    }
+  }
+
+  pub fn is_value(&self) -> bool {
+    match self {
+      Term::Int(_) | Term::Abs { .. } | Term::TypeAbs { .. } => true,
+      Term::Record(fields) => fields.iter().all(|(_, term)| term.is_value()),
+      _ => false,
+    }
+  }
+
+  // Performs a single call-by-value reduction step, or returns `None` when
+  // the term is a value or stuck.
+  pub fn step(&self) -> Option<Term> {
+    match self {
+      Term::App(f, arg) => {
+        if !f.is_value() {
+          //    t1 |> t1'
+          // ---------------
+          // t1 t2 |> t1' t2
+          Some(Term::App(Box::new(f.step()?), arg.clone()))
+        } else if !arg.is_value() {
+          //   t2 |> t2'
+          // ------------
+          // v t2 |> v t2'
+          Some(Term::App(f.clone(), Box::new(arg.step()?)))
+        } else {
+          match &**f {
+            // ------------------------
+            // (λx: τ. t) v |> t[v/x]
+            Term::Abs { body, .. } => Some(body.instantiate(arg)),
+            _ => None,
+          }
+        }
+      }
+      Term::Ann(term, typ) => {
+        if !term.is_value() {
+          //        t |> t'
+          // ---------------------
+          // (t : τ) |> (t' : τ)
+          Some(Term::Ann(Box::new(term.step()?), typ.clone()))
+        } else {
+          // -----------
+          // (v : τ) |> v
+          Some((**term).clone())
+        }
+      }
+      Term::TypeApp(term, typ) => {
+        if !term.is_value() {
+          //      t |> t'
+          // -----------------
+          // t [σ] |> t' [σ]
+          Some(Term::TypeApp(Box::new(term.step()?), typ.clone()))
+        } else {
+          match &**term {
+            // ----------------------------
+            // (Λα <: υ. t) [σ] |> t[σ/α]
+            Term::TypeAbs { body, .. } => Some(body.instantiate_type(typ)),
+            _ => None,
+          }
+        }
+      }
+      //                        ti |> ti'
+      // --------------------------------------------------------
+      // {l1 = v1, ..., li = ti, ...} |> {l1 = v1, ..., li = ti', ...}
+      Term::Record(fields) => {
+        let i = fields.iter().position(|(_, term)| !term.is_value())?;
+        let mut fields = fields.clone();
+        fields[i].1 = fields[i].1.step()?;
+        Some(Term::Record(fields))
+      }
+      Term::Field(term, label) => {
+        if !term.is_value() {
+          //    t |> t'
+          // -------------
+          // t.l |> t'.l
+          Some(Term::Field(Box::new(term.step()?), label.clone()))
+        } else {
+          match &**term {
+            // ------------------------------
+            // {l1 = v1, ..., ln = vn}.li |> vi
+            Term::Record(fields) => field(fields, label).cloned(),
+            _ => None,
+          }
+        }
+      }
+      _ => None,
+    }
+  }
+
+  // Every well typed term of F<: terminates, so evaluation needs no step limit.
+  pub fn eval(&self) -> Term {
+    let mut term = self.clone();
+    while let Some(next) = term.step() {
+      term = next;
+    }
+    term
+  }
+}
+
+// Typechecks `term` and converts it to a closed term.
+pub fn compile(term: &ast::Term) -> Result<Term, TypecheckerError> {
+  let ctx = Context::new();
+  let term = Term::from_ast(&ctx, term)?;
+  term.type_of(&ctx)?;
+  Ok(term)
+}
+
+pub fn eval(term: &ast::Term) -> Result<ast::Term, TypecheckerError> {
+  Ok(compile(term)?.eval().to_ast(&Context::new()))
+}
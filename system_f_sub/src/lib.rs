@@ -0,0 +1,37 @@
+#![allow(clippy::result_large_err)]
+
+pub mod ast;
+pub mod debruijn;
+mod grammar;
+pub mod typechecker;
+
+pub use ast::{Term, Type};
+pub use debruijn::Context;
+pub use typechecker::{infer, infer_in, TypecheckerError};
+
+pub type ParseError<'input> =
+  lalrpop_util::ParseError<usize, lalrpop_util::lexer::Token<'input>, &'static str>;
+
+pub fn parse(input: &str) -> Result<Term, ParseError<'_>> {
+  grammar::TermParser::new().parse(input)
+}
+
+pub fn parse_type(input: &str) -> Result<Type, ParseError<'_>> {
+  grammar::TypeParser::new().parse(input)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn parses_integer_literals_that_fit() {
+    assert_eq!(parse("2147483647"), Ok(Term::Int(i32::MAX)));
+    assert_eq!(
+      parse("99999999999"),
+      Err(ParseError::User {
+        error: "integer literal is too large"
+      })
+    );
+  }
+}
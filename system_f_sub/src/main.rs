@@ -0,0 +1,18 @@
+use lambda_core::Diagnostic;
+use system_f_sub::{debruijn, typechecker, Term};
+
+fn parse(input: &str) -> Term {
+  match system_f_sub::parse(input) {
+    Ok(term) => term,
+    Err(err) => panic!("{}", Diagnostic::from_parse_error(&err).render(input)),
+  }
+}
+
+fn main() {
+  let e = parse("(ΛX <: {a: Int}. λx: X. {orig = x, a = x.a}) [{a: Int, b: Int}] {a = 1, b = 2}");
+  println!("{}", e);
+  match (typechecker::infer(&e), debruijn::eval(&e)) {
+    (Ok(typ), Ok(value)) => println!("{} : {}", value, typ),
+    (Err(err), _) | (_, Err(err)) => println!("{}", err),
+  }
+}
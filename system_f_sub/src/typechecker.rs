@@ -0,0 +1,274 @@
+use crate::ast::{Term, Type};
+use crate::debruijn::{self, Context};
+use thiserror::Error;
+
+// System F<: extends System F with subtyping. Type variables are introduced
+// with an upper bound, and a term of type σ can be used wherever a term of a
+// supertype τ of σ is expected. `∀α. τ` and `Λα. t` are short for a bound of
+// Top.
+//
+// Γ ⊢ σ <: τ is read "σ is a subtype of τ under Γ". The rules below are the
+// algorithmic form of the kernel F<: rules: reflexivity and transitivity are
+// derivable from them, so they are not rules of their own.
+//
+//
+// -----------
+// Γ ⊢ σ <: Top
+//
+//
+// ----------
+// Γ ⊢ α <: α
+//
+//
+// α <: υ ∈ Γ    Γ ⊢ υ <: τ
+// ------------------------
+//        Γ ⊢ α <: τ
+//
+//
+// Γ ⊢ τ1 <: σ1    Γ ⊢ σ2 <: τ2
+// ----------------------------
+//   Γ ⊢ σ1 -> σ2 <: τ1 -> τ2
+//
+// Functions are contravariant in their parameter: a function that accepts
+// any σ1 can stand in for one that only has to accept the smaller τ1.
+//
+//
+// every label lj of the second record is some li    Γ ⊢ σi <: τj
+// --------------------------------------------------------------
+//   Γ ⊢ {l1: σ1, ..., ln: σn} <: {k1: τ1, ..., km: τm}
+//
+// A record may have more fields than needed (width), fields with smaller
+// types (depth), and its fields in any order (permutation).
+//
+//
+//          Γ, α <: υ ⊢ σ <: τ
+// -------------------------------------
+// Γ ⊢ (∀α <: υ. σ) <: (∀α <: υ. τ)
+//
+// Only the bodies are compared; both bounds have to be the same type. This
+// is the kernel rule, which keeps subtyping decidable.
+//
+//
+// Application and type application use subsumption: the argument only has to
+// be a subtype of what is expected.
+//
+// Γ ⊢ t1: σ1 -> τ    Γ ⊢ t2: σ2    Γ ⊢ σ2 <: σ1
+// ---------------------------------------------
+//               Γ ⊢ (t1 t2): τ
+//
+//
+//       Γ, α <: υ ⊢ t : τ
+// ------------------------------
+// Γ ⊢ (Λα <: υ. t) : ∀α <: υ. τ
+//
+//
+// Γ ⊢ t : ∀α <: υ. τ    Γ ⊢ σ <: υ
+// --------------------------------
+//       Γ ⊢ t [σ] : τ[σ/α]
+//
+//
+// Γ ⊢ t: σ    Γ ⊢ σ <: τ
+// ----------------------
+//    Γ ⊢ (t : τ): τ
+//
+// An ascription forgets what it does not mention, which is the only way to
+// give a term a larger type than the one it is inferred to have.
+//
+//
+// When a term whose type is a type variable is applied, projected or
+// instantiated, the variable is replaced with its bound until the type has
+// the expected shape.
+
+#[derive(Debug, PartialEq, Error)]
+pub enum TypecheckerError {
+  #[error("variable {0} is not defined")]
+  UndefinedVariable(String),
+  #[error("type variable {0} is not defined")]
+  UndefinedTypeVariable(String),
+  #[error("expected term {term} to have a subtype of {expected} but it has type {got}")]
+  NotASubtype {
+    term: Term,
+    expected: Type,
+    got: Type,
+  },
+  #[error("type {typ} is not a subtype of the bound {bound}")]
+  UnsatisfiedBound { typ: Type, bound: Type },
+  #[error("expected {expected} but got {got}")]
+  UnexpectedTerm { expected: String, got: Term },
+  #[error("expected {expected} but got {got}")]
+  UnexpectedType { expected: String, got: Type },
+  #[error("type {typ} has no label {label}")]
+  MissingLabel { label: String, typ: Type },
+  #[error("label {0} appears more than once")]
+  DuplicateLabel(String),
+}
+
+// Γ ⊢ σ <: τ
+pub fn subtype(ctx: &Context, s: &debruijn::Type, t: &debruijn::Type) -> bool {
+  use debruijn::Type::*;
+  match (s, t) {
+    (_, Top) => true,
+    (Int, Int) => true,
+    (TypeVar(i), TypeVar(j)) if i == j => true,
+    (TypeVar(i), _) => match ctx.get_bound(*i) {
+      Some(bound) => subtype(ctx, &bound, t),
+      None => false,
+    },
+    (Arrow(s1, s2), Arrow(t1, t2)) => subtype(ctx, t1, s1) && subtype(ctx, s2, t2),
+    (Record(s_fields), Record(t_fields)) => t_fields
+      .iter()
+      .all(|(label, t)| debruijn::field(s_fields, label).is_some_and(|s| subtype(ctx, s, t))),
+    (
+      Forall {
+        bound: s_bound,
+        typ: s,
+        ..
+      },
+      Forall {
+        hint,
+        bound: t_bound,
+        typ: t,
+      },
+    ) => s_bound == t_bound && subtype(&ctx.assign_type(hint.0.clone(), (**t_bound).clone()), s, t),
+    _ => false,
+  }
+}
+
+// Replaces a type variable with its bound until the type is not a variable,
+// giving the smallest type of a known shape that the type is a subtype of.
+pub fn expose(ctx: &Context, typ: &debruijn::Type) -> debruijn::Type {
+  let mut typ = typ.clone();
+  while let debruijn::Type::TypeVar(i) = typ {
+    match ctx.get_bound(i) {
+      Some(bound) => typ = bound,
+      None => break,
+    }
+  }
+  typ
+}
+
+pub fn infer(term: &Term) -> Result<Type, TypecheckerError> {
+  infer_in(&Context::new(), term)
+}
+
+// Like `infer`, for a term whose free variables and type variables are bound
+// by `ctx`.
+pub fn infer_in(ctx: &Context, term: &Term) -> Result<Type, TypecheckerError> {
+  let typ = debruijn::Term::from_ast(ctx, term)?.type_of(ctx)?;
+  Ok(typ.to_ast(ctx))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::grammar::{TermParser, TypeParser};
+
+  fn parse(input: &str) -> Term {
+    TermParser::new().parse(input).unwrap()
+  }
+
+  fn is_subtype(s: &str, t: &str) -> bool {
+    let ctx = Context::new();
+    let typ =
+      |input| debruijn::Type::from_ast(&ctx, &TypeParser::new().parse(input).unwrap()).unwrap();
+    subtype(&ctx, &typ(s), &typ(t))
+  }
+
+  fn assert_type(input: &str, expected: &str) {
+    assert_eq!(
+      infer(&parse(input)),
+      Ok(TypeParser::new().parse(expected).unwrap()),
+      "type of {}",
+      input
+    );
+  }
+
+  fn assert_eval(input: &str, expected: &str) {
+    assert_eq!(
+      debruijn::eval(&parse(input)).unwrap().to_string(),
+      expected,
+      "value of {}",
+      input
+    );
+  }
+
+  #[test]
+  fn subtyping() {
+    assert!(is_subtype("Int", "Top"));
+    assert!(is_subtype("Int -> Int", "Top"));
+    assert!(is_subtype("{a: Int, b: Int}", "{a: Int}"));
+    assert!(is_subtype("{b: Int, a: Int}", "{a: Int, b: Int}"));
+    assert!(is_subtype("{a: {b: Int, c: Int}}", "{a: {b: Int}}"));
+    assert!(!is_subtype("{a: Int}", "{a: Int, b: Int}"));
+    assert!(!is_subtype("Top", "Int"));
+    // Contravariant in the parameter, covariant in the result.
+    assert!(is_subtype(
+      "{a: Int} -> {a: Int, b: Int}",
+      "{a: Int, b: Int} -> {a: Int}"
+    ));
+    assert!(!is_subtype("{a: Int, b: Int} -> Int", "{a: Int} -> Int"));
+    assert!(is_subtype("∀X. X -> {a: Int, b: Int}", "∀X. X -> {a: Int}"));
+    assert!(is_subtype("∀X <: {a: Int}. X", "∀X <: {a: Int}. {a: Int}"));
+    // The kernel rule only compares quantifiers with the same bound.
+    assert!(!is_subtype("∀X. X -> X", "∀X <: Int. X -> X"));
+    assert!(is_subtype("∀X. ∀Y <: X. Y", "∀X. ∀Y <: X. X"));
+  }
+
+  #[test]
+  fn subsumption() {
+    assert_type("(λr: {a: Int}. r.a) {a = 1, b = 2}", "Int");
+    assert_type("({a = 1, b = 2} : {a: Int})", "{a: Int}");
+    assert_type(
+      "(λf: {a: Int, b: Int} -> Top. f) (λr: {a: Int}. r)",
+      "{a: Int, b: Int} -> Top",
+    );
+    assert!(matches!(
+      infer(&parse("(λr: {a: Int, b: Int}. r.a) {a = 1}")),
+      Err(TypecheckerError::NotASubtype { .. })
+    ));
+    assert!(matches!(
+      infer(&parse("({a = 1} : {a: Int, b: Int})")),
+      Err(TypecheckerError::NotASubtype { .. })
+    ));
+  }
+
+  #[test]
+  fn bounded_quantification() {
+    let f = "ΛX <: {a: Int}. λx: X. {orig = x, a = x.a}";
+    assert_type(f, "∀X <: {a: Int}. X -> {a: Int, orig: X}");
+    // The caller gets back the type it passed in, not just the bound.
+    assert_type(
+      &format!("({}) [{{a: Int, b: Int}}] {{a = 1, b = 2}}", f),
+      "{a: Int, orig: {a: Int, b: Int}}",
+    );
+    assert_eval(
+      &format!("(({}) [{{a: Int, b: Int}}] {{a = 1, b = 2}}).orig.b", f),
+      "2",
+    );
+    assert!(matches!(
+      infer(&parse(&format!("({}) [{{b: Int}}]", f))),
+      Err(TypecheckerError::UnsatisfiedBound { .. })
+    ));
+    assert_type("ΛX. ΛY <: X. λy: Y. (y : X)", "∀X. ∀Y <: X. Y -> X");
+    assert_type("ΛX <: Int -> Int. λf: X. f 1", "∀X <: Int -> Int. X -> Int");
+    assert!(matches!(
+      infer(&parse("ΛX. λx: X. x.a")),
+      Err(TypecheckerError::UnexpectedType { .. })
+    ));
+  }
+
+  #[test]
+  fn printing_round_trips() {
+    for input in &[
+      "∀X <: {a: Int}. X -> X",
+      "(∀X. X) -> Int",
+      "Int -> (∀X <: Top -> Int. X)",
+    ] {
+      let typ = TypeParser::new().parse(input).unwrap();
+      assert_eq!(TypeParser::new().parse(&typ.to_string()), Ok(typ));
+    }
+    let term = parse("(ΛX <: {a: Int}. λx: X. x.a) [{a: Int}] {a = 1}");
+    assert_eq!(parse(&term.to_string()), term);
+    assert_eq!(parse("ΛX. λx: X. x").to_string(), "ΛX. λx: X. x");
+  }
+}